@@ -0,0 +1,33 @@
+//! Rejects the `new-range` feature on toolchains which predate the `core::range` types.
+
+use std::env;
+use std::process::Command;
+
+/// The first Rust minor version with stable `core::range` types.
+const NEW_RANGE_MINOR: u32 = 96;
+
+fn main() {
+    println!("cargo::rerun-if-changed=build.rs");
+    if env::var_os("CARGO_FEATURE_NEW_RANGE").is_none() {
+        return;
+    }
+
+    let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let version = Command::new(rustc)
+        .arg("--version")
+        .output()
+        .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
+        .unwrap_or_default();
+    // eg `rustc 1.95.0 (59807616e 2026-04-14)`
+    let minor = version
+        .split_whitespace()
+        .nth(1)
+        .and_then(|semver| semver.split('.').nth(1))
+        .and_then(|minor| minor.parse::<u32>().ok());
+    if minor.is_some_and(|minor| minor < NEW_RANGE_MINOR) {
+        println!(
+            "cargo::error=the `new-range` feature requires Rust 1.{NEW_RANGE_MINOR} or newer, but found {}",
+            version.trim(),
+        );
+    }
+}
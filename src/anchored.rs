@@ -0,0 +1,400 @@
+//! Indices and ranges resolved against a particular sequence length.
+
+use std::fmt;
+use std::ops::*;
+
+use crate::{SeqIndex, SeqRange, SeqRangeError};
+
+/// A [`SeqRange`] resolved against the length of a sequence.
+///
+/// Created by [`SeqRange::anchor`]. Unlike a native [`Range`], an `AnchoredRange`
+/// remembers the `SeqRange` it came from, so it can be re-anchored when the
+/// length of the sequence changes.
+///
+/// Like [`Range`], an `AnchoredRange` is an [`Iterator`] over its indices.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::idx;
+/// let mut vec = vec![8, 6, 7, 5, 3, 0, 9];
+/// let anchored = idx!(2..^2).anchor(vec.len()).unwrap();
+/// assert_eq!(anchored.range(), 2..5);
+/// assert_eq!(&vec[anchored.clone()], &[7, 5, 3]);
+///
+/// vec.push(1);
+/// let anchored = anchored.reanchor(vec.len()).unwrap();
+/// assert_eq!(&vec[anchored], &[7, 5, 3, 0]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AnchoredRange {
+    range: Range<usize>,
+    origin: SeqRange<usize>,
+}
+
+impl SeqRange<usize> {
+    /// Resolve this range against a sequence of the given `len`, remembering its origin.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the range does not fit a sequence of length `len`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::idx;
+    /// let anchored = idx!(^3..^1).anchor(10).unwrap();
+    /// assert_eq!(anchored.collect::<Vec<_>>(), [7, 8]);
+    /// assert!(idx!(^3..^1).anchor(2).is_err());
+    /// ```
+    pub fn anchor(&self, len: usize) -> Result<AnchoredRange, SeqRangeError> {
+        let range = self.checked_for_seq_len(len)?;
+        Ok(AnchoredRange { range, origin: *self })
+    }
+}
+
+impl AnchoredRange {
+    /// The resolved range.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// The [`SeqRange`] this range was resolved from.
+    pub fn origin(&self) -> SeqRange<usize> {
+        self.origin
+    }
+
+    /// Resolve the originating [`SeqRange`] again, against a new `len`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the range does not fit a sequence of length `len`.
+    pub fn reanchor(&self, len: usize) -> Result<AnchoredRange, SeqRangeError> {
+        self.origin.anchor(len)
+    }
+}
+
+impl RangeBounds<usize> for AnchoredRange {
+    fn start_bound(&self) -> Bound<&usize> {
+        Bound::Included(&self.range.start)
+    }
+
+    fn end_bound(&self) -> Bound<&usize> {
+        Bound::Excluded(&self.range.end)
+    }
+}
+
+impl Iterator for AnchoredRange {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        self.range.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for AnchoredRange {
+    fn next_back(&mut self) -> Option<usize> {
+        self.range.next_back()
+    }
+}
+
+impl ExactSizeIterator for AnchoredRange {}
+
+impl From<AnchoredRange> for Range<usize> {
+    fn from(anchored: AnchoredRange) -> Range<usize> {
+        anchored.range
+    }
+}
+
+// ===
+// Anchored indices
+
+/// A [`SeqIndex`] resolved against a sequence, which remembers the sequence it was resolved against.
+///
+/// Created by [`SeqIndex::anchor`], which captures the length of the sequence,
+/// or by [`Generational::anchor`], which also captures the sequence's generation.
+/// Using the index against a sequence which has since changed is an error,
+/// rather than silently addressing a different element.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::idx;
+/// let mut vec = vec![8, 6, 7, 5, 3, 0, 9];
+/// let anchored = idx!(^2).anchor(vec.len()).unwrap();
+/// assert_eq!(anchored.resolve(vec.len()), Ok(5));
+///
+/// vec.push(1);
+/// assert!(anchored.resolve(vec.len()).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AnchoredIndex {
+    index: usize,
+    origin: SeqIndex<usize>,
+    len: usize,
+    generation: Option<u64>,
+}
+
+/// The error type for using an [`AnchoredIndex`] against a sequence which has changed since it was anchored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StaleIndex;
+
+impl fmt::Display for StaleIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "index was anchored to a sequence which has since changed")
+    }
+}
+
+impl std::error::Error for StaleIndex {}
+
+impl SeqIndex<usize> {
+    /// Resolve this index against a sequence of the given `len`, remembering the length.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index does not address an element of a sequence of length `len`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::idx;
+    /// assert_eq!(idx!(^3).anchor(10).unwrap().index(), 7);
+    /// assert!(idx!(^0).anchor(10).is_err());
+    /// ```
+    pub fn anchor(&self, len: usize) -> Result<AnchoredIndex, SeqRangeError> {
+        self.anchor_generation(len, None)
+    }
+
+    fn anchor_generation(&self, len: usize, generation: Option<u64>) -> Result<AnchoredIndex, SeqRangeError> {
+        match self.checked_for_seq_len(len)? {
+            index if index < len => Ok(AnchoredIndex { index, origin: *self, len, generation }),
+            _ => Err(SeqRangeError::OutOfBounds { index: *self, len }),
+        }
+    }
+}
+
+impl AnchoredIndex {
+    /// The resolved index, as of when it was anchored.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The [`SeqIndex`] this index was resolved from.
+    pub fn origin(&self) -> SeqIndex<usize> {
+        self.origin
+    }
+
+    /// The resolved index, if the sequence still has the length it was anchored against.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `len` differs from the anchored length.
+    pub fn resolve(&self, len: usize) -> Result<usize, StaleIndex> {
+        (len == self.len).then_some(self.index).ok_or(StaleIndex)
+    }
+
+    /// Resolve the originating [`SeqIndex`] again, against a new `len`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index does not address an element of a sequence of length `len`.
+    pub fn reanchor(&self, len: usize) -> Result<AnchoredIndex, SeqRangeError> {
+        self.origin.anchor(len)
+    }
+}
+
+/// A sequence paired with a generation counter, which advances whenever the sequence is mutably borrowed.
+///
+/// Indices anchored via [`Generational::anchor`] are only usable while the generation is unchanged,
+/// which catches mutations that leave the length as it was, such as a pop followed by a push.
+/// Mutable access to individual elements through [`Generational::get_mut`] does not advance the generation.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, Generational};
+/// let mut vec = Generational::new(vec![8, 6, 7, 5, 3, 0, 9]);
+/// let last = vec.anchor(idx!(^1)).unwrap();
+/// *vec.get_mut(&last).unwrap() += 1;
+/// assert_eq!(vec.get(&last), Ok(&10));
+///
+/// vec.pop();
+/// vec.push(1);
+/// assert!(vec.get(&last).is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Generational<C> {
+    inner: C,
+    generation: u64,
+}
+
+impl<C> Generational<C> {
+    /// Wrap a sequence, starting at generation zero.
+    pub fn new(inner: C) -> Self {
+        Generational { inner, generation: 0 }
+    }
+
+    /// The current generation of the sequence.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Unwrap the sequence.
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+
+    /// The resolved index, if it was anchored to this sequence at its current generation.
+    fn check<T>(&self, anchored: &AnchoredIndex) -> Result<usize, StaleIndex>
+    where C: AsRef<[T]> {
+        if anchored.generation == Some(self.generation) {
+            anchored.resolve(self.inner.as_ref().len())
+        } else {
+            Err(StaleIndex)
+        }
+    }
+
+    /// Resolve the index against the sequence, remembering its length and current generation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index does not address an element of the sequence.
+    pub fn anchor<T>(&self, index: SeqIndex<usize>) -> Result<AnchoredIndex, SeqRangeError>
+    where C: AsRef<[T]> {
+        index.anchor_generation(self.inner.as_ref().len(), Some(self.generation))
+    }
+
+    /// The element at the anchored index.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sequence has changed since the index was anchored,
+    /// or the index was not anchored via [`Generational::anchor`].
+    pub fn get<T>(&self, anchored: &AnchoredIndex) -> Result<&T, StaleIndex>
+    where C: AsRef<[T]> {
+        let index = self.check(anchored)?;
+        Ok(&self.inner.as_ref()[index])
+    }
+
+    /// The element at the anchored index, mutably.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sequence has changed since the index was anchored,
+    /// or the index was not anchored via [`Generational::anchor`].
+    pub fn get_mut<T>(&mut self, anchored: &AnchoredIndex) -> Result<&mut T, StaleIndex>
+    where C: AsRef<[T]> + AsMut<[T]> {
+        let index = self.check(anchored)?;
+        Ok(&mut self.inner.as_mut()[index])
+    }
+}
+
+impl<C> Deref for Generational<C> {
+    type Target = C;
+
+    fn deref(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C> DerefMut for Generational<C> {
+    fn deref_mut(&mut self) -> &mut C {
+        self.generation = self.generation.wrapping_add(1);
+        &mut self.inner
+    }
+}
+
+// ===
+// Index impls
+
+impl<T> Index<AnchoredRange> for Vec<T> {
+    type Output = [T];
+
+    fn index(&self, rng: AnchoredRange) -> &[T] {
+        &self[rng.range]
+    }
+}
+
+impl<T> Index<AnchoredRange> for [T] {
+    type Output = [T];
+
+    fn index(&self, rng: AnchoredRange) -> &[T] {
+        &self[rng.range]
+    }
+}
+
+impl Index<AnchoredRange> for str {
+    type Output = str;
+
+    fn index(&self, rng: AnchoredRange) -> &str {
+        &self[rng.range]
+    }
+}
+
+impl Index<AnchoredRange> for String {
+    type Output = str;
+
+    fn index(&self, rng: AnchoredRange) -> &str {
+        &self[rng.range]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::RangeBounds;
+
+    use super::*;
+    use crate::idx;
+
+    #[test]
+    fn test_anchor() {
+        let anchored = idx!(2..^3).anchor(10).unwrap();
+        assert_eq!(anchored.range(), 2..7);
+        assert_eq!(anchored.origin(), idx!(2..^3));
+        assert!(anchored.contains(&6));
+        assert!(!anchored.contains(&7));
+        assert_eq!(anchored.len(), 5);
+        assert_eq!(anchored.clone().rev().collect::<Vec<_>>(), [6, 5, 4, 3, 2]);
+
+        let reanchored = anchored.reanchor(5).unwrap();
+        assert_eq!(reanchored.range(), 2..2);
+        assert!(anchored.reanchor(4).is_err());
+    }
+
+    #[test]
+    fn test_anchor_index() {
+        let anchored = idx!(^3).anchor(10).unwrap();
+        assert_eq!(anchored.index(), 7);
+        assert_eq!(anchored.origin(), idx!(^3));
+        assert_eq!(anchored.resolve(10), Ok(7));
+        assert_eq!(anchored.resolve(9), Err(StaleIndex));
+        assert_eq!(anchored.reanchor(9).unwrap().index(), 6);
+        assert!(idx!(10).anchor(10).is_err());
+        assert!(idx!(^11).anchor(10).is_err());
+    }
+
+    #[test]
+    fn test_generational() {
+        let mut vec = Generational::new(vec![1, 2, 3]);
+        let first = vec.anchor(idx!(0)).unwrap();
+        assert_eq!(vec.get(&first), Ok(&1));
+        assert_eq!(vec.get(&idx!(0).anchor(3).unwrap()), Err(StaleIndex));
+        vec[0] = 4;
+        assert_eq!(vec.generation(), 1);
+        assert_eq!(vec.get(&first), Err(StaleIndex));
+        assert!(vec.anchor(idx!(^4)).is_err());
+        assert_eq!(vec.into_inner(), [4, 2, 3]);
+    }
+
+    #[test]
+    fn test_index_anchored() {
+        let s = "ranges".to_string();
+        let anchored = idx!(1..^2).anchor(s.len()).unwrap();
+        assert_eq!(&s[anchored.clone()], "ang");
+        assert_eq!(&s.as_bytes()[anchored], b"ang");
+    }
+}
@@ -0,0 +1,308 @@
+//! Slicing terminal strings by their visible characters, preserving ANSI escape sequences.
+//!
+//! Escape sequences occupy no cells, so ranges here count only visible characters.
+//! A slice reopens any SGR (color and style) sequences which were in effect at its start,
+//! and resets them at its end if any are still in effect, so slices can be printed in
+//! isolation without their styling bleeding into the surrounding output.
+//! Other escape sequences are kept only if they fall within the slice.
+//!
+//! Each char counts as one cell, or with the `width` feature, as many cells as its Unicode width.
+//! Then a range bound which falls between the two cells of a wide char does not resolve,
+//! and zero-width chars are kept with the char before them.
+
+use crate::{SeqIndex, SeqRange};
+
+const ESC: char = '\x1b';
+const RESET: &str = "\x1b[0m";
+
+/// A token of a terminal string: either a whole escape sequence, or a single visible char.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token<'a> {
+    Escape(&'a str),
+    Visible(&'a str),
+}
+
+/// The byte length of the escape sequence at the front of `s`, which starts with `ESC`.
+fn escape_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let end = match bytes.get(1) {
+        // CSI: parameter and intermediate bytes, then a final byte
+        Some(b'[') => bytes[2..].iter().position(|b| (0x40..=0x7e).contains(b)).map(|pos| pos + 3),
+        // OSC: terminated by BEL or ST, or left unterminated by any other escape
+        Some(b']') => s[2..].find(['\x07', ESC]).map(|pos| match bytes[pos + 2..] {
+            [0x07, ..] => pos + 3,
+            [0x1b, b'\\', ..] => pos + 4,
+            _ => pos + 2,
+        }),
+        Some(_) => Some(1 + s[1..].chars().next().map_or(0, char::len_utf8)),
+        None => Some(1),
+    };
+    end.unwrap_or(s.len()).min(s.len())
+}
+
+/// Split a terminal string into escape sequences and visible chars.
+fn tokens(s: &str) -> impl Iterator<Item = Token<'_>> {
+    let mut rest = s;
+    std::iter::from_fn(move || {
+        let c = rest.chars().next()?;
+        let len = if c == ESC { escape_len(rest) } else { c.len_utf8() };
+        let (token, tail) = rest.split_at(len);
+        rest = tail;
+        Some(if c == ESC { Token::Escape(token) } else { Token::Visible(token) })
+    })
+}
+
+/// How an SGR (graphic rendition) escape sequence changes the styles in effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Sgr {
+    /// Resets every style.
+    Reset,
+    /// Resets every style, then sets some, as in `ESC[0;31m`.
+    ResetThenSet,
+    /// Sets some styles on top of those in effect.
+    Set,
+}
+
+/// How the escape sequence changes graphic rendition, if it is an SGR sequence.
+fn sgr(escape: &str) -> Option<Sgr> {
+    let mut params = escape.strip_prefix("\x1b[")?.strip_suffix('m')?.split(';');
+    let (mut reset, mut set) = (false, false);
+    while let Some(param) = params.next() {
+        match param {
+            // an empty parameter defaults to 0
+            "" | "0" | "00" => (reset, set) = (true, false),
+            // extended colors take their color as further parameters, which may be 0
+            "38" | "48" | "58" => {
+                let skip = match params.next() {
+                    Some("5") => 1,
+                    Some("2") => 3,
+                    _ => 0,
+                };
+                params.by_ref().take(skip).for_each(drop);
+                set = true;
+            }
+            _ => set = true,
+        }
+    }
+    Some(match (reset, set) {
+        (true, false) => Sgr::Reset,
+        (true, true) => Sgr::ResetThenSet,
+        (false, _) => Sgr::Set,
+    })
+}
+
+/// The number of cells a visible char occupies.
+#[cfg(feature = "width")]
+fn cell_width(c: &str) -> usize {
+    c.chars().next().and_then(unicode_width::UnicodeWidthChar::width).unwrap_or(0)
+}
+
+/// The number of cells a visible char occupies.
+#[cfg(not(feature = "width"))]
+fn cell_width(_c: &str) -> usize {
+    1
+}
+
+/// The number of cells the visible chars of a terminal string occupy.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::ansi::visible_len;
+/// assert_eq!(visible_len("\x1b[1;31merror\x1b[0m: oops"), 11);
+/// ```
+pub fn visible_len(s: &str) -> usize {
+    tokens(s)
+        .map(|token| match token {
+            Token::Escape(_) => 0,
+            Token::Visible(c) => cell_width(c),
+        })
+        .sum()
+}
+
+/// Slice a terminal string by a range of the cells of its visible chars,
+/// or `None` if the range does not fit.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::idx;
+/// # use from_back::ansi::slice_visible;
+/// let s = "\x1b[31mred\x1b[0m plain \x1b[32mgreen\x1b[0m";
+/// assert_eq!(slice_visible(s, idx!(1..^8)).unwrap(), "\x1b[31med\x1b[0m pla");
+/// assert_eq!(slice_visible(s, idx!(^3..)).unwrap(), "\x1b[32meen\x1b[0m");
+/// assert_eq!(slice_visible(s, idx!(^16..)), None);
+/// ```
+pub fn slice_visible(s: &str, range: impl Into<SeqRange<usize>>) -> Option<String> {
+    let range = range.into().checked_for_seq_len(visible_len(s)).ok()?;
+    let mut active = Vec::new();
+    let mut open = false;
+    let mut out = String::new();
+    let mut cells = 0;
+    // whether the last char of nonzero width was kept, along with any zero-width chars after it
+    let mut kept = false;
+    for token in tokens(s) {
+        match token {
+            Token::Escape(escape) => {
+                match sgr(escape) {
+                    Some(Sgr::Reset) => active.clear(),
+                    Some(Sgr::ResetThenSet) => {
+                        active.clear();
+                        active.push(escape);
+                    }
+                    Some(Sgr::Set) => active.push(escape),
+                    None => {}
+                }
+                // escapes at either edge are superseded by the reopened and closing SGRs
+                if range.start < cells && cells < range.end {
+                    out.push_str(escape);
+                }
+            }
+            Token::Visible(c) => {
+                let width = cell_width(c);
+                if width > 0 {
+                    let cell_range = cells + 1..cells + width;
+                    if cell_range.contains(&range.start) || cell_range.contains(&range.end) {
+                        return None;
+                    }
+                    if cells == range.start && !range.is_empty() {
+                        out.extend(active.iter().copied());
+                    }
+                    kept = range.start <= cells && cells + width <= range.end;
+                }
+                if kept {
+                    out.push_str(c);
+                    open = !active.is_empty();
+                }
+                cells += width;
+            }
+        }
+    }
+    if open {
+        out.push_str(RESET);
+    }
+    Some(out)
+}
+
+/// The last `n` cells of a terminal string, or the whole string if it has fewer.
+///
+/// See [`slice_visible`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::ansi::keep_last_visible;
+/// let status = "\x1b[1m~/src/from-back\x1b[0m (main)";
+/// assert_eq!(keep_last_visible(status, 11), "\x1b[1mback\x1b[0m (main)");
+/// ```
+pub fn keep_last_visible(s: &str, n: usize) -> String {
+    let start = visible_len(s).saturating_sub(n);
+    slice_visible(s, SeqRange { start: SeqIndex::FromFront(start), end: SeqIndex::FromBack(0) }).unwrap_or_default()
+}
+
+/// The first `n` cells of a terminal string, or the whole string if it has fewer.
+///
+/// See [`slice_visible`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::ansi::keep_first_visible;
+/// assert_eq!(keep_first_visible("\x1b[4mlink\x1b[0m text", 2), "\x1b[4mli\x1b[0m");
+/// ```
+pub fn keep_first_visible(s: &str, n: usize) -> String {
+    let end = visible_len(s).min(n);
+    slice_visible(s, SeqRange { start: SeqIndex::FromFront(0), end: SeqIndex::FromFront(end) }).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idx;
+
+    #[test]
+    fn test_tokens() {
+        let s = "a\x1b[1;31mb\x1b]0;title\x07c\x1b]8;;url\x1b\\d\x1b7e\x1b[";
+        let tokens: Vec<_> = tokens(s).collect();
+        assert_eq!(
+            tokens,
+            [
+                Token::Visible("a"),
+                Token::Escape("\x1b[1;31m"),
+                Token::Visible("b"),
+                Token::Escape("\x1b]0;title\x07"),
+                Token::Visible("c"),
+                Token::Escape("\x1b]8;;url\x1b\\"),
+                Token::Visible("d"),
+                Token::Escape("\x1b7"),
+                Token::Visible("e"),
+                Token::Escape("\x1b["),
+            ],
+        );
+        assert_eq!(visible_len("\x1b"), 0);
+        assert_eq!(visible_len("€\x1b[0m€"), 2);
+    }
+
+    #[test]
+    fn test_tokens_unterminated_osc() {
+        let s = "\x1b]0;t\x1bé\x1b]0;t\x1b[1mb";
+        let tokens: Vec<_> = tokens(s).collect();
+        assert_eq!(
+            tokens,
+            [
+                Token::Escape("\x1b]0;t"),
+                Token::Escape("\x1bé"),
+                Token::Escape("\x1b]0;t"),
+                Token::Escape("\x1b[1m"),
+                Token::Visible("b"),
+            ],
+        );
+        assert_eq!(visible_len("\x1b]0;t\x1bé"), 0);
+        assert_eq!(slice_visible("\x1b]0;t\x1béab", idx!(^1..)).unwrap(), "b");
+        assert_eq!(keep_last_visible("a\x1b]0;t\x1bé", 1), "a");
+    }
+
+    #[test]
+    fn test_slice_visible() {
+        let s = "\x1b[1mbo\x1b[31mld red\x1b[m plain";
+        assert_eq!(slice_visible(s, idx!(0..0)).unwrap(), "");
+        assert_eq!(slice_visible(s, idx!(1..3)).unwrap(), "\x1b[1mo\x1b[31ml\x1b[0m");
+        assert_eq!(slice_visible(s, idx!(3..^6)).unwrap(), "\x1b[1m\x1b[31md red\x1b[0m");
+        assert_eq!(slice_visible(s, idx!(^5..)).unwrap(), "plain");
+        assert_eq!(slice_visible(s, idx!(^6..)).unwrap(), " plain");
+        assert_eq!(slice_visible(s, idx!(0..^0)).unwrap(), s);
+        assert_eq!(slice_visible(s, idx!(^15..)), None);
+        assert_eq!(keep_last_visible(s, 20), s);
+        assert_eq!(keep_first_visible(s, 0), "");
+    }
+
+    #[test]
+    fn test_sgr_params() {
+        assert_eq!(sgr("\x1b[m"), Some(Sgr::Reset));
+        assert_eq!(sgr("\x1b[0;0m"), Some(Sgr::Reset));
+        assert_eq!(sgr("\x1b[0;31m"), Some(Sgr::ResetThenSet));
+        assert_eq!(sgr("\x1b[1;0m"), Some(Sgr::Reset));
+        assert_eq!(sgr("\x1b[38;5;0m"), Some(Sgr::Set));
+        assert_eq!(sgr("\x1b[48;2;0;0;0;1m"), Some(Sgr::Set));
+        assert_eq!(sgr("\x1b[2J"), None);
+
+        // the reset drops the bold, so only the red is reopened
+        let s = "\x1b[1mbold\x1b[0;31mred\x1b[0m";
+        assert_eq!(slice_visible(s, idx!(^2..)).unwrap(), "\x1b[0;31med\x1b[0m");
+        let s = "\x1b[1mbold\x1b[1;0mplain";
+        assert_eq!(slice_visible(s, idx!(^2..)).unwrap(), "in");
+    }
+
+    #[test]
+    #[cfg(feature = "width")]
+    fn test_slice_visible_width() {
+        // '日' and '本' are two cells each, and U+301 is a zero-width combining accent
+        let s = "\x1b[32m日本\x1b[0me\u{301}x";
+        assert_eq!(visible_len(s), 6);
+        assert_eq!(slice_visible(s, idx!(2..4)).unwrap(), "\x1b[32m本\x1b[0m");
+        assert_eq!(slice_visible(s, idx!(^2..)).unwrap(), "e\u{301}x");
+        assert_eq!(slice_visible(s, idx!(1..)), None);
+        assert_eq!(slice_visible(s, idx!(..^3)), None);
+        assert_eq!(keep_last_visible(s, 4), "\x1b[32m本\x1b[0me\u{301}x");
+    }
+}
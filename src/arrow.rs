@@ -0,0 +1,87 @@
+//! Zero-copy slicing of [Apache Arrow](arrow_array) arrays and buffers.
+//!
+//! Slices share the underlying memory of the original, and sliced arrays keep
+//! their validity (null) buffers aligned with their values.
+
+use arrow_array::{Array, ArrayRef};
+use arrow_buffer::Buffer;
+
+use crate::SeqRange;
+
+/// Extension methods for Arrow arrays and buffers which accept the library's structs.
+pub trait ArrowSeqExt: Sized {
+    /// Zero-copy slice of the elements within the range.
+    ///
+    /// Arrays are indexed by row, and [`Buffer`]s by byte.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{Array, ArrayRef, Int32Array};
+    /// # use from_back::idx;
+    /// # use from_back::arrow::ArrowSeqExt;
+    /// let array: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3), Some(4)]));
+    /// let last_rows = array.slice_seq(idx!(^3..));
+    /// assert_eq!(last_rows.len(), 3);
+    /// assert!(last_rows.is_null(0));
+    /// ```
+    #[cfg_attr(feature = "no-panic", deprecated(note = "panics on an index which does not fit"))]
+    fn slice_seq<R>(&self, range: R) -> Self
+    where R: Into<SeqRange<usize>>;
+}
+
+impl ArrowSeqExt for ArrayRef {
+    fn slice_seq<R>(&self, range: R) -> Self
+    where R: Into<SeqRange<usize>> {
+        let range = range.into().for_seq_len(self.len());
+        self.slice(range.start, range.len())
+    }
+}
+
+impl ArrowSeqExt for Buffer {
+    fn slice_seq<R>(&self, range: R) -> Self
+    where R: Into<SeqRange<usize>> {
+        let range = range.into().for_seq_len(self.len());
+        self.slice_with_length(range.start, range.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::{Int32Array, StringArray};
+
+    use super::*;
+    use crate::idx;
+
+    #[test]
+    fn test_array_slice_seq() {
+        let array: ArrayRef = Arc::new(StringArray::from(vec![Some("a"), None, Some("c"), None, Some("e")]));
+        let sliced = array.slice_seq(idx!(1..^1));
+        let sliced = sliced.as_any().downcast_ref::<StringArray>().unwrap();
+        let values: Vec<_> = sliced.iter().collect();
+        assert_eq!(values, [None, Some("c"), None]);
+        assert_eq!(sliced.null_count(), 2);
+        assert!(array.slice_seq(idx!(^0..)).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_array_slice_seq_out_of_bounds() {
+        let array: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        array.slice_seq(idx!(^4..));
+    }
+
+    #[test]
+    fn test_buffer_slice_seq() {
+        let buffer = Buffer::from(b"header:body:trailer");
+        assert_eq!(buffer.slice_seq(idx!(7..^8)).as_slice(), b"body");
+        assert_eq!(buffer.slice_seq(idx!(^7..)).as_slice(), b"trailer");
+    }
+}
@@ -0,0 +1,133 @@
+//! Minimal newtypes for from-back indexing without the [`idx!`](crate::idx) macro.
+
+use std::ops::Neg;
+use std::ops::{Index, IndexMut};
+
+use crate::SeqIndex;
+
+/// An index counting from the back of a sequence, equivalent to `idx!(^n)`.
+///
+/// For code which only ever needs single back-relative element access,
+/// without the [`idx!`](crate::idx) macro or the [`SeqIndex`] enum.
+/// As with `idx!(^1)`, `BackOffset(1)` is the last element, and indexing panics with
+/// the same message as `idx!(^n)` when the offset does not refer to an element.
+///
+/// It is not named `FromBack`, so that it does not collide with [`SeqIndex::FromBack`]
+/// wherever the enum's variants are imported alongside it.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::BackOffset;
+/// let mut vec = vec![8, 6, 7, 5, 3, 0, 9];
+/// assert_eq!(vec[BackOffset(2)], 0);
+/// vec[BackOffset(1)] = 1;
+/// assert_eq!(vec, [8, 6, 7, 5, 3, 0, 1]);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BackOffset(pub usize);
+
+impl From<BackOffset> for SeqIndex<usize> {
+    fn from(BackOffset(idx): BackOffset) -> Self {
+        SeqIndex::FromBack(idx)
+    }
+}
+
+impl<T> Index<BackOffset> for [T] {
+    type Output = T;
+
+    #[track_caller]
+    fn index(&self, BackOffset(idx): BackOffset) -> &T {
+        &self[SeqIndex::FromBack(idx)]
+    }
+}
+
+impl<T> IndexMut<BackOffset> for [T] {
+    #[track_caller]
+    fn index_mut(&mut self, BackOffset(idx): BackOffset) -> &mut T {
+        &mut self[SeqIndex::FromBack(idx)]
+    }
+}
+
+impl<T> Index<BackOffset> for Vec<T> {
+    type Output = T;
+
+    #[track_caller]
+    fn index(&self, index: BackOffset) -> &T {
+        &self.as_slice()[index]
+    }
+}
+
+impl<T> IndexMut<BackOffset> for Vec<T> {
+    #[track_caller]
+    fn index_mut(&mut self, index: BackOffset) -> &mut T {
+        &mut self.as_mut_slice()[index]
+    }
+}
+
+/// A plain index, which negates to an index counting from the back.
+///
+/// This allows Python-like construction without the [`idx!`](crate::idx) macro:
+/// `-At(2)` is `idx!(^2)`, and `At(2)` converts to `idx!(2)`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, At, SeqIndex};
+/// let vec = vec![8, 6, 7, 5, 3, 0, 9];
+/// assert_eq!(vec[-At(1)], 9);
+/// assert_eq!(SeqIndex::from(At(1)), idx!(1));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct At<Idx>(pub Idx);
+
+impl<Idx> Neg for At<Idx> {
+    type Output = SeqIndex<Idx>;
+
+    fn neg(self) -> SeqIndex<Idx> {
+        SeqIndex::FromBack(self.0)
+    }
+}
+
+impl<Idx> From<At<Idx>> for SeqIndex<Idx> {
+    fn from(At(idx): At<Idx>) -> Self {
+        SeqIndex::FromFront(idx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idx;
+
+    #[test]
+    fn test_index() {
+        let mut arr = [1, 2, 3];
+        assert_eq!(arr[..][BackOffset(3)], 1);
+        arr[..][BackOffset(3)] = 0;
+        assert_eq!(arr, [0, 2, 3]);
+        assert_eq!(SeqIndex::from(BackOffset(3)), idx!(^3));
+    }
+
+    #[test]
+    fn test_at() {
+        assert_eq!(-At(0), idx!(^0));
+        assert_eq!(-At(3), SeqIndex::from(BackOffset(3)));
+        let index: SeqIndex<_> = At(3).into();
+        assert_eq!(index, idx!(3));
+    }
+
+    #[test]
+    #[should_panic(expected = "index ^0 out of range for sequence of length 3")]
+    fn test_index_zero() {
+        let vec = Vec::from([1, 2, 3]);
+        let _ = vec[BackOffset(0)];
+    }
+
+    #[test]
+    #[should_panic(expected = "index ^4 out of range for sequence of length 3")]
+    fn test_index_past_front() {
+        let vec = Vec::from([1, 2, 3]);
+        let _ = vec[BackOffset(4)];
+    }
+}
@@ -0,0 +1,192 @@
+//! Indexing the bits of primitive integers.
+
+use std::ops::Range;
+
+use crate::{SeqIndex, SeqRange};
+
+/// Extension methods for reading and writing the bits of primitive integers by
+/// the library's structs.
+///
+/// Bits are numbered from the least significant end, so `idx!(0)` is the least significant bit
+/// and `idx!(^1)` is the most significant bit. Wrap an integer in [`Msb0`] to number them from the
+/// most significant end instead.
+///
+/// Multi-bit fields are read and written right-aligned, ie shifted down to the least significant end.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, BitSeqExt};
+/// let reg: u8 = 0b101_0110_0;
+/// // the top 3 bits
+/// assert_eq!(reg.bits_seq(idx!(^3..^0)), Some(0b101));
+/// assert_eq!(reg.bits_seq(idx!(1..^3)), Some(0b0110));
+/// assert_eq!(reg.bit_seq(idx!(^1)), Some(true));
+/// assert_eq!(reg.with_bits_seq(idx!(^3..^0), 0b011), Some(0b011_0110_0));
+/// ```
+pub trait BitSeqExt: Copy {
+    /// The integer type of a multi-bit field.
+    type Bits;
+
+    /// The bit at the given index, or `None` if it is out of range.
+    fn bit_seq(self, index: SeqIndex<usize>) -> Option<bool>;
+
+    /// This value with the bit at the given index set to `bit`, or `None` if it is out of range.
+    fn with_bit_seq(self, index: SeqIndex<usize>, bit: bool) -> Option<Self>;
+
+    /// The bits within the given range, right-aligned, or `None` if the range does not fit.
+    fn bits_seq(self, range: SeqRange<usize>) -> Option<Self::Bits>;
+
+    /// This value with the bits within the given range replaced by `field`, right-aligned,
+    /// or `None` if the range does not fit or `field` is too wide for it.
+    fn with_bits_seq(self, range: SeqRange<usize>, field: Self::Bits) -> Option<Self>;
+}
+
+/// An integer whose bits are numbered from the most significant end.
+///
+/// So `idx!(0)` is the most significant bit, and `idx!(^1)` is the least significant bit.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, BitSeqExt, Msb0};
+/// let reg = Msb0(0b101_0110_0u8);
+/// assert_eq!(reg.bits_seq(idx!(..3)), Some(0b101));
+/// assert_eq!(reg.bit_seq(idx!(^1)), Some(false));
+/// assert_eq!(reg.with_bit_seq(idx!(^1), true), Some(Msb0(0b101_0110_1)));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Msb0<T>(pub T);
+
+/// Resolve a single bit to a range of bits.
+fn bit_range(index: SeqIndex<usize>, bits: usize) -> Option<Range<usize>> {
+    let start = index.checked_for_seq_len(bits).ok()?;
+    (start < bits).then_some(start..start + 1)
+}
+
+/// Renumber a range of bits from the opposite end.
+fn flip(range: Range<usize>, bits: usize) -> Range<usize> {
+    bits - range.end..bits - range.start
+}
+
+macro_rules! impl_bit_seq {
+    ($($int:ty),*) => { $(
+        impl BitSeqExt for $int {
+            type Bits = $int;
+
+            fn bit_seq(self, index: SeqIndex<usize>) -> Option<bool> {
+                let range = bit_range(index, <$int>::BITS as usize)?;
+                Some(self >> range.start & 1 == 1)
+            }
+
+            fn with_bit_seq(self, index: SeqIndex<usize>, bit: bool) -> Option<Self> {
+                let range = bit_range(index, <$int>::BITS as usize)?;
+                Some(self & !(1 << range.start) | <$int>::from(bit) << range.start)
+            }
+
+            fn bits_seq(self, range: SeqRange<usize>) -> Option<$int> {
+                let range = range.checked_for_seq_len(<$int>::BITS as usize).ok()?;
+                Some(get_bits!($int, self, range))
+            }
+
+            fn with_bits_seq(self, range: SeqRange<usize>, field: $int) -> Option<Self> {
+                let range = range.checked_for_seq_len(<$int>::BITS as usize).ok()?;
+                set_bits!($int, self, range, field)
+            }
+        }
+
+        impl BitSeqExt for Msb0<$int> {
+            type Bits = $int;
+
+            fn bit_seq(self, index: SeqIndex<usize>) -> Option<bool> {
+                let range = flip(bit_range(index, <$int>::BITS as usize)?, <$int>::BITS as usize);
+                Some(self.0 >> range.start & 1 == 1)
+            }
+
+            fn with_bit_seq(self, index: SeqIndex<usize>, bit: bool) -> Option<Self> {
+                let range = flip(bit_range(index, <$int>::BITS as usize)?, <$int>::BITS as usize);
+                Some(Msb0(self.0 & !(1 << range.start) | <$int>::from(bit) << range.start))
+            }
+
+            fn bits_seq(self, range: SeqRange<usize>) -> Option<$int> {
+                let range = range.checked_for_seq_len(<$int>::BITS as usize).ok()?;
+                Some(get_bits!($int, self.0, flip(range, <$int>::BITS as usize)))
+            }
+
+            fn with_bits_seq(self, range: SeqRange<usize>, field: $int) -> Option<Self> {
+                let range = range.checked_for_seq_len(<$int>::BITS as usize).ok()?;
+                set_bits!($int, self.0, flip(range, <$int>::BITS as usize), field).map(Msb0)
+            }
+        }
+    )* };
+}
+
+/// A mask of the low `width` bits.
+macro_rules! mask {
+    ($int:ty, $width:expr) => {
+        <$int>::MAX.checked_shr(<$int>::BITS - $width as u32).unwrap_or(0)
+    };
+}
+
+/// The bits of `value` within `range`, right-aligned.
+macro_rules! get_bits {
+    ($int:ty, $value:expr, $range:expr) => {{
+        let range = $range;
+        $value.checked_shr(range.start as u32).unwrap_or(0) & mask!($int, range.len())
+    }};
+}
+
+/// `value` with the bits within `range` replaced by `field`, if it fits.
+macro_rules! set_bits {
+    ($int:ty, $value:expr, $range:expr, $field:expr) => {{
+        let (range, field) = ($range, $field);
+        let mask = mask!($int, range.len());
+        if field & !mask != 0 {
+            None
+        } else {
+            let shift = |bits: $int| bits.checked_shl(range.start as u32).unwrap_or(0);
+            Some($value & !shift(mask) | shift(field))
+        }
+    }};
+}
+
+impl_bit_seq!(u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idx;
+
+    #[test]
+    fn test_bits() {
+        let value = 0x8001_u16;
+        assert_eq!(value.bit_seq(idx!(0)), Some(true));
+        assert_eq!(value.bit_seq(idx!(1)), Some(false));
+        assert_eq!(value.bit_seq(idx!(^1)), Some(true));
+        assert_eq!(value.bit_seq(idx!(16)), None);
+        assert_eq!(value.bit_seq(idx!(^0)), None);
+        assert_eq!(value.with_bit_seq(idx!(^1), false), Some(1));
+        assert_eq!(value.bits_seq(idx!(0..^0)), Some(value));
+        assert_eq!(value.bits_seq(idx!(^0..^0)), Some(0));
+        assert_eq!(value.bits_seq(idx!(^4..^0)), Some(0x8));
+        assert_eq!(value.bits_seq(idx!(0..17)), None);
+        assert_eq!(value.with_bits_seq(idx!(4..^4), 0xff), Some(0x8ff1));
+        assert_eq!(value.with_bits_seq(idx!(4..^4), 0x100), None);
+        assert_eq!(value.with_bits_seq(idx!(0..^0), 7), Some(7));
+        assert_eq!(value.with_bits_seq(idx!(^0..^0), 0), Some(value));
+        assert_eq!(u128::MAX.bits_seq(idx!(^1..^0)), Some(1));
+    }
+
+    #[test]
+    fn test_msb0() {
+        let value = Msb0(0x8001_u16);
+        assert_eq!(value.bit_seq(idx!(0)), Some(true));
+        assert_eq!(value.bit_seq(idx!(^1)), Some(true));
+        assert_eq!(value.bit_seq(idx!(^2)), Some(false));
+        assert_eq!(value.bits_seq(idx!(..4)), Some(0x8));
+        assert_eq!(value.bits_seq(idx!(^4..^0)), Some(0x1));
+        assert_eq!(value.bits_seq(idx!(0..0)), Some(0));
+        assert_eq!(value.with_bits_seq(idx!(4..^4), 0xff), Some(Msb0(0x8ff1)));
+        assert_eq!(value.with_bit_seq(idx!(16), true), None);
+    }
+}
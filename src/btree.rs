@@ -0,0 +1,156 @@
+//! Rank-addressed access to ordered maps and sets.
+//!
+//! The rank of an entry is its position in sorted order, so `idx!(^1)` is the largest key.
+//! Locating a rank walks the tree from whichever end is nearer to it.
+
+use std::collections::{btree_map, btree_set, BTreeMap, BTreeSet};
+use std::iter::Take;
+use std::ops::Bound;
+
+use crate::{SeqIndex, SeqRange};
+
+/// Walk an iterator to the element at position `rank`, from whichever end is nearer.
+fn nth_nearer<I>(mut iter: I, rank: usize) -> Option<I::Item>
+where I: DoubleEndedIterator + ExactSizeIterator {
+    let len = iter.len();
+    if rank < len - rank {
+        iter.nth(rank)
+    } else {
+        iter.nth_back(len.checked_sub(rank)?.checked_sub(1)?)
+    }
+}
+
+/// Resolve a rank to a position of an element, if there is one.
+fn resolve_rank(index: SeqIndex<usize>, len: usize) -> Option<usize> {
+    index.checked_for_seq_len(len).ok().filter(|&rank| rank < len)
+}
+
+/// Extension methods for [`BTreeMap`] which address entries by rank.
+pub trait BTreeMapSeqExt<K, V> {
+    /// The entry at the given rank, or `None` if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::collections::BTreeMap;
+    /// # use from_back::{idx, BTreeMapSeqExt};
+    /// let scores = BTreeMap::from([(90, "ada"), (75, "bob"), (82, "cy")]);
+    /// assert_eq!(scores.get_rank_seq(idx!(^1)), Some((&90, &"ada")));
+    /// assert_eq!(scores.get_rank_seq(idx!(0)), Some((&75, &"bob")));
+    /// assert_eq!(scores.get_rank_seq(idx!(^4)), None);
+    /// ```
+    fn get_rank_seq(&self, index: SeqIndex<usize>) -> Option<(&K, &V)>;
+
+    /// Iterate in sorted order over the entries whose ranks fall within the range,
+    /// or `None` if the range does not fit the map.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::collections::BTreeMap;
+    /// # use from_back::{idx, BTreeMapSeqExt};
+    /// let scores = BTreeMap::from([(90, "ada"), (75, "bob"), (82, "cy"), (60, "di")]);
+    /// let top: Vec<_> = scores.iter_rank_seq(idx!(^2..)).unwrap().map(|(_, name)| *name).collect();
+    /// assert_eq!(top, ["cy", "ada"]);
+    /// assert!(scores.iter_rank_seq(idx!(^5..)).is_none());
+    /// ```
+    fn iter_rank_seq(&self, range: impl Into<SeqRange<usize>>) -> Option<Take<btree_map::Range<'_, K, V>>>;
+}
+
+impl<K, V> BTreeMapSeqExt<K, V> for BTreeMap<K, V>
+where K: Ord {
+    fn get_rank_seq(&self, index: SeqIndex<usize>) -> Option<(&K, &V)> {
+        nth_nearer(self.iter(), resolve_rank(index, self.len())?)
+    }
+
+    fn iter_rank_seq(&self, range: impl Into<SeqRange<usize>>) -> Option<Take<btree_map::Range<'_, K, V>>> {
+        let range = range.into().checked_for_seq_len(self.len()).ok()?;
+        let start = match nth_nearer(self.keys(), range.start) {
+            Some(key) => Bound::Included(key),
+            // the range is empty
+            None => Bound::Unbounded,
+        };
+        Some(self.range((start, Bound::Unbounded)).take(range.len()))
+    }
+}
+
+/// Extension methods for [`BTreeSet`] which address elements by rank.
+pub trait BTreeSetSeqExt<T> {
+    /// The element at the given rank, or `None` if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::collections::BTreeSet;
+    /// # use from_back::{idx, BTreeSetSeqExt};
+    /// let scores = BTreeSet::from([90, 75, 82]);
+    /// assert_eq!(scores.get_rank_seq(idx!(^2)), Some(&82));
+    /// ```
+    fn get_rank_seq(&self, index: SeqIndex<usize>) -> Option<&T>;
+
+    /// Iterate in sorted order over the elements whose ranks fall within the range,
+    /// or `None` if the range does not fit the set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::collections::BTreeSet;
+    /// # use from_back::{idx, BTreeSetSeqExt};
+    /// let scores = BTreeSet::from([90, 75, 82, 60]);
+    /// let middle: Vec<_> = scores.iter_rank_seq(idx!(1..^1)).unwrap().collect();
+    /// assert_eq!(middle, [&75, &82]);
+    /// ```
+    fn iter_rank_seq(&self, range: impl Into<SeqRange<usize>>) -> Option<Take<btree_set::Range<'_, T>>>;
+}
+
+impl<T> BTreeSetSeqExt<T> for BTreeSet<T>
+where T: Ord {
+    fn get_rank_seq(&self, index: SeqIndex<usize>) -> Option<&T> {
+        nth_nearer(self.iter(), resolve_rank(index, self.len())?)
+    }
+
+    fn iter_rank_seq(&self, range: impl Into<SeqRange<usize>>) -> Option<Take<btree_set::Range<'_, T>>> {
+        let range = range.into().checked_for_seq_len(self.len()).ok()?;
+        let start = match nth_nearer(self.iter(), range.start) {
+            Some(elem) => Bound::Included(elem),
+            // the range is empty
+            None => Bound::Unbounded,
+        };
+        Some(self.range((start, Bound::Unbounded)).take(range.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idx;
+
+    #[test]
+    fn test_map_rank() {
+        let map: BTreeMap<_, _> = (0..10).map(|n| (n * 10, n)).collect();
+        for rank in 0..10 {
+            assert_eq!(map.get_rank_seq(idx!(rank)), Some((&(rank * 10), &rank)));
+            assert_eq!(map.get_rank_seq(idx!(^10 - rank)), Some((&(rank * 10), &rank)));
+        }
+        assert_eq!(map.get_rank_seq(idx!(10)), None);
+        assert_eq!(map.get_rank_seq(idx!(^0)), None);
+        let ranked = |range: SeqRange<usize>| map.iter_rank_seq(range).map(|iter| iter.map(|(_, &n)| n).collect::<Vec<_>>());
+        assert_eq!(ranked(idx!(^3..^0)), Some(vec![7, 8, 9]));
+        assert_eq!(ranked(idx!(2..5)), Some(vec![2, 3, 4]));
+        assert_eq!(ranked(idx!(^0..^0)), Some(vec![]));
+        assert_eq!(ranked(idx!(5..5)), Some(vec![]));
+        assert_eq!(ranked(idx!(5..4)), None);
+        assert_eq!(ranked(idx!(0..11)), None);
+        assert_eq!(BTreeMap::<i32, i32>::new().iter_rank_seq(idx!(0..0)).map(Iterator::count), Some(0));
+    }
+
+    #[test]
+    fn test_set_rank() {
+        let set: BTreeSet<_> = (0..10).collect();
+        assert_eq!(set.get_rank_seq(idx!(^1)), Some(&9));
+        assert_eq!(set.get_rank_seq(idx!(^11)), None);
+        assert_eq!(set.iter_rank_seq(idx!(^2..)).unwrap().collect::<Vec<_>>(), [&8, &9]);
+        assert_eq!(set.iter_rank_seq(idx!(^0..)).unwrap().count(), 0);
+        assert!(BTreeSet::<i32>::new().get_rank_seq(idx!(0)).is_none());
+    }
+}
@@ -0,0 +1,115 @@
+//! Decoding fixed-width integers from byte slices.
+
+use crate::{SeqIndex, SliceSeqExt};
+
+macro_rules! byte_seq_ext {
+    ($($int:ident: $le:ident, $checked_le:ident, $be:ident, $checked_be:ident;)*) => {
+        /// Extension methods for decoding fixed-width integers at an index of a byte slice.
+        ///
+        /// Each integer is read from the bytes starting at the given index, in either
+        /// little-endian (`_le`) or big-endian (`_be`) byte order.
+        /// The `checked_` variants return `None` instead of panicking if the bytes do not fit the slice.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// # use from_back::{idx, ByteSeqExt};
+        /// // a payload, followed by a 2 byte length and a 4 byte checksum
+        /// let frame = [b'h', b'i', 2, 0, 0xef, 0xbe, 0xad, 0xde];
+        /// assert_eq!(frame.read_u16_le_seq(idx!(^6)), 2);
+        /// assert_eq!(frame.read_u32_le_seq(idx!(^4)), 0xdeadbeef);
+        /// assert_eq!(frame.checked_read_u32_be_seq(idx!(^4)), Some(0xefbeadde));
+        /// assert_eq!(frame.checked_read_u32_le_seq(idx!(^3)), None);
+        /// ```
+        pub trait ByteSeqExt {
+            $(
+                #[doc = concat!("Decode a little-endian `", stringify!($int), "` starting at the given index.")]
+                ///
+                /// # Panics
+                ///
+                /// Panics if the bytes do not fit the slice.
+                #[cfg_attr(feature = "no-panic", deprecated(note = "panics on an index which does not fit"))]
+                fn $le(&self, index: SeqIndex<usize>) -> $int;
+
+                #[doc = concat!("Decode a little-endian `", stringify!($int), "` starting at the given index,")]
+                /// or `None` if the bytes do not fit the slice.
+                fn $checked_le(&self, index: SeqIndex<usize>) -> Option<$int>;
+
+                #[doc = concat!("Decode a big-endian `", stringify!($int), "` starting at the given index.")]
+                ///
+                /// # Panics
+                ///
+                /// Panics if the bytes do not fit the slice.
+                #[cfg_attr(feature = "no-panic", deprecated(note = "panics on an index which does not fit"))]
+                fn $be(&self, index: SeqIndex<usize>) -> $int;
+
+                #[doc = concat!("Decode a big-endian `", stringify!($int), "` starting at the given index,")]
+                /// or `None` if the bytes do not fit the slice.
+                fn $checked_be(&self, index: SeqIndex<usize>) -> Option<$int>;
+            )*
+        }
+
+        impl ByteSeqExt for [u8] {
+            $(
+                fn $le(&self, index: SeqIndex<usize>) -> $int {
+                    self.$checked_le(index).unwrap_or_else(|| out_of_bounds::<$int>(index, self.len()))
+                }
+
+                fn $checked_le(&self, index: SeqIndex<usize>) -> Option<$int> {
+                    self.array_seq(index).copied().map(<$int>::from_le_bytes)
+                }
+
+                fn $be(&self, index: SeqIndex<usize>) -> $int {
+                    self.$checked_be(index).unwrap_or_else(|| out_of_bounds::<$int>(index, self.len()))
+                }
+
+                fn $checked_be(&self, index: SeqIndex<usize>) -> Option<$int> {
+                    self.array_seq(index).copied().map(<$int>::from_be_bytes)
+                }
+            )*
+        }
+    };
+}
+
+#[cold]
+fn out_of_bounds<T>(index: SeqIndex<usize>, len: usize) -> ! {
+    panic!(
+        "{} bytes at index {index} out of range for slice of length {len}",
+        std::mem::size_of::<T>(),
+    )
+}
+
+byte_seq_ext! {
+    u16: read_u16_le_seq, checked_read_u16_le_seq, read_u16_be_seq, checked_read_u16_be_seq;
+    u32: read_u32_le_seq, checked_read_u32_le_seq, read_u32_be_seq, checked_read_u32_be_seq;
+    u64: read_u64_le_seq, checked_read_u64_le_seq, read_u64_be_seq, checked_read_u64_be_seq;
+    i16: read_i16_le_seq, checked_read_i16_le_seq, read_i16_be_seq, checked_read_i16_be_seq;
+    i32: read_i32_le_seq, checked_read_i32_le_seq, read_i32_be_seq, checked_read_i32_be_seq;
+    i64: read_i64_le_seq, checked_read_i64_le_seq, read_i64_be_seq, checked_read_i64_be_seq;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idx;
+
+    #[test]
+    fn test_read_seq() {
+        let buf = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+        assert_eq!(buf.checked_read_u16_le_seq(idx!(0)), Some(0x0201));
+        assert_eq!(buf.checked_read_u16_be_seq(idx!(^2)), Some(0x0708));
+        assert_eq!(buf.checked_read_u64_be_seq(idx!(^8)), Some(0x0102030405060708));
+        assert_eq!(buf.checked_read_u64_le_seq(idx!(1)), None);
+        assert_eq!(buf.checked_read_u32_le_seq(idx!(^9)), None);
+        assert_eq!(buf.checked_read_u16_le_seq(idx!(^0)), None);
+        assert_eq!([0xff, 0xfe].checked_read_i16_be_seq(idx!(^2)), Some(-2));
+        assert_eq!([0xfe, 0xff, 0xff, 0xff].checked_read_i32_le_seq(idx!(0)), Some(-2));
+        assert_eq!(buf[..0].checked_read_i64_le_seq(idx!(0)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "4 bytes at index ^3 out of range for slice of length 8")]
+    fn test_read_seq_out_of_bounds() {
+        [0; 8].read_u32_be_seq(idx!(^3));
+    }
+}
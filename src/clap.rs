@@ -0,0 +1,43 @@
+//! [`clap`](::clap) value parsers for command line arguments in [`idx!`](crate::idx) syntax.
+//!
+//! The library's structs implement [`FromStr`](std::str::FromStr), so `clap::value_parser!`
+//! also accepts them; these functions are shorthands which name the parsed type.
+
+use ::clap::builder::TypedValueParser;
+
+use crate::{SeqIndex, SeqRange};
+
+/// A value parser for arguments like `^2` or `3`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use clap::{Arg, Command};
+/// # use from_back::{idx, SeqIndex};
+/// # use from_back::clap::seq_index_parser;
+/// let cmd = Command::new("pick").arg(Arg::new("index").value_parser(seq_index_parser()));
+/// let matches = cmd.try_get_matches_from(["pick", "^2"]).unwrap();
+/// assert_eq!(matches.get_one::<SeqIndex<usize>>("index"), Some(&idx!(^2)));
+/// ```
+pub fn seq_index_parser() -> impl TypedValueParser<Value = SeqIndex<usize>> {
+    |s: &str| s.parse::<SeqIndex<usize>>()
+}
+
+/// A value parser for arguments like `2..^3`, where either bound may be omitted.
+///
+/// # Examples
+///
+/// ```rust
+/// # use clap::{Arg, Command};
+/// # use from_back::{idx, SeqRange};
+/// # use from_back::clap::seq_range_parser;
+/// let cmd = || Command::new("cut").arg(Arg::new("range").value_parser(seq_range_parser()));
+/// let matches = cmd().try_get_matches_from(["cut", "2..^3"]).unwrap();
+/// assert_eq!(matches.get_one::<SeqRange<usize>>("range"), Some(&idx!(2..^3)));
+///
+/// let err = cmd().try_get_matches_from(["cut", "2..x"]).unwrap_err();
+/// assert!(err.to_string().contains("invalid index `x`"));
+/// ```
+pub fn seq_range_parser() -> impl TypedValueParser<Value = SeqRange<usize>> {
+    |s: &str| s.parse::<SeqRange<usize>>()
+}
@@ -0,0 +1,349 @@
+//! A stable, compact binary encoding of the library's structs.
+//!
+//! # Format
+//!
+//! An index is encoded as a single unsigned LEB128 varint of the word
+//! `(magnitude << 1) | from_back`, where `from_back` is `1` for from-back indices.
+//! So `idx!(3)` encodes as `[0x06]`, `idx!(^3)` as `[0x07]`, and `idx!(^0)` as `[0x01]`.
+//! Ranges are encoded as their start index followed by their end index.
+//!
+//! The word is a `u64` regardless of platform, so magnitudes up to `2^63 - 1` can be encoded.
+//! Each value has exactly one encoding: decoding rejects overlong varints,
+//! which pad the word with trailing zero groups, as well as words wider than 64 bits.
+//! The format does not depend on the layout of [`SeqIndex`], and will not change.
+//! Since postcard and varint-configured bincode encode a `u64` as the same varint,
+//! serializing [`SeqIndex::to_compact`] with them produces identical bytes.
+//!
+//! Encoding and decoding work on caller-provided buffers, and never allocate.
+//!
+//! With the `serde` feature, the [`serde`](self::serde) helpers serialize a field
+//! in this encoding.
+
+use crate::{SeqIndex, SeqRange, SeqRangeFrom, SeqRangeInclusive};
+
+/// The most bytes an encoded index can occupy.
+pub const MAX_INDEX_LEN: usize = 10;
+
+/// Types with a compact binary encoding.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::idx;
+/// # use from_back::compact::Compact;
+/// let mut buf = [0; 4];
+/// let written = idx!(2..^300).encode(&mut buf).unwrap();
+/// assert_eq!(&buf[..written], &[0x04, 0xd9, 0x04]);
+/// assert_eq!(Compact::decode(&buf), Some((idx!(2..^300), written)));
+/// ```
+pub trait Compact: Sized {
+    /// The number of indices encoded, each as one varint.
+    const INDICES: usize;
+
+    /// Encode into the front of `out`, returning the number of bytes written.
+    ///
+    /// Returns `None` if `out` is too short, or if an index exceeds `2^63 - 1`.
+    fn encode(&self, out: &mut [u8]) -> Option<usize>;
+
+    /// Decode from the front of `input`, returning the value and the number of bytes read.
+    ///
+    /// Returns `None` if `input` is truncated, malformed or overlong,
+    /// or if an index does not fit in a [`usize`] on this platform.
+    fn decode(input: &[u8]) -> Option<(Self, usize)>;
+}
+
+impl SeqIndex<usize> {
+    /// The word which this index's compact encoding stores as a varint.
+    ///
+    /// Returns `None` if the index exceeds `2^63 - 1`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, SeqIndex};
+    /// assert_eq!(idx!(^3).to_compact(), Some(7));
+    /// assert_eq!(SeqIndex::from_compact(7), Some(idx!(^3)));
+    /// ```
+    pub fn to_compact(&self) -> Option<u64> {
+        let (idx, tag) = match *self {
+            SeqIndex::FromFront(idx) => (idx, 0),
+            SeqIndex::FromBack(idx) => (idx, 1),
+        };
+        let idx = u64::try_from(idx).ok()?;
+        (idx >> 63 == 0).then_some(idx << 1 | tag)
+    }
+
+    /// Unpack the word stored by an index's compact encoding.
+    ///
+    /// Returns `None` if the index does not fit in a [`usize`] on this platform.
+    pub fn from_compact(word: u64) -> Option<Self> {
+        let idx = usize::try_from(word >> 1).ok()?;
+        Some(match word & 1 {
+            0 => SeqIndex::FromFront(idx),
+            _ => SeqIndex::FromBack(idx),
+        })
+    }
+}
+
+impl Compact for SeqIndex<usize> {
+    const INDICES: usize = 1;
+
+    fn encode(&self, out: &mut [u8]) -> Option<usize> {
+        let mut word = self.to_compact()?;
+        let mut written = 0;
+        loop {
+            let byte = out.get_mut(written)?;
+            written += 1;
+            if word < 0x80 {
+                *byte = word as u8;
+                return Some(written);
+            }
+            *byte = (word & 0x7f) as u8 | 0x80;
+            word >>= 7;
+        }
+    }
+
+    fn decode(input: &[u8]) -> Option<(Self, usize)> {
+        let mut word = 0u64;
+        for (read, &byte) in input.iter().take(MAX_INDEX_LEN).enumerate() {
+            let bits = u64::from(byte & 0x7f);
+            let shift = 7 * read as u32;
+            // reject bits which would be shifted out of the word
+            if bits.checked_shl(shift)? >> shift != bits {
+                return None;
+            }
+            word |= bits << shift;
+            // reject a trailing zero group, which a shorter encoding would omit
+            if byte == 0 && read > 0 {
+                return None;
+            }
+            if byte & 0x80 == 0 {
+                return Some((SeqIndex::from_compact(word)?, read + 1));
+            }
+        }
+        None
+    }
+}
+
+/// Encode two indices back to back.
+fn encode_pair(a: SeqIndex<usize>, b: SeqIndex<usize>, out: &mut [u8]) -> Option<usize> {
+    let written = a.encode(out)?;
+    Some(written + b.encode(&mut out[written..])?)
+}
+
+/// Decode two indices back to back.
+fn decode_pair(input: &[u8]) -> Option<(SeqIndex<usize>, SeqIndex<usize>, usize)> {
+    let (a, read) = SeqIndex::decode(input)?;
+    let (b, read_b) = SeqIndex::decode(&input[read..])?;
+    Some((a, b, read + read_b))
+}
+
+impl Compact for SeqRange<usize> {
+    const INDICES: usize = 2;
+
+    fn encode(&self, out: &mut [u8]) -> Option<usize> {
+        encode_pair(self.start, self.end, out)
+    }
+
+    fn decode(input: &[u8]) -> Option<(Self, usize)> {
+        let (start, end, read) = decode_pair(input)?;
+        Some((SeqRange { start, end }, read))
+    }
+}
+
+impl Compact for SeqRangeFrom<usize> {
+    const INDICES: usize = 1;
+
+    fn encode(&self, out: &mut [u8]) -> Option<usize> {
+        self.start.encode(out)
+    }
+
+    fn decode(input: &[u8]) -> Option<(Self, usize)> {
+        let (start, read) = SeqIndex::decode(input)?;
+        Some((SeqRangeFrom { start }, read))
+    }
+}
+
+impl Compact for SeqRangeInclusive<usize> {
+    const INDICES: usize = 2;
+
+    fn encode(&self, out: &mut [u8]) -> Option<usize> {
+        encode_pair(self.start, self.end, out)
+    }
+
+    fn decode(input: &[u8]) -> Option<(Self, usize)> {
+        let (start, end, read) = decode_pair(input)?;
+        Some((SeqRangeInclusive { start, end }, read))
+    }
+}
+
+/// Helpers which serialize one of the library's structs in its compact encoding,
+/// for use as `#[serde(with = "from_back::compact::serde")]`.
+///
+/// Each index is serialized as its [`SeqIndex::to_compact`] word, and a range as a tuple
+/// of its words, so postcard and varint-configured bincode write exactly the bytes of
+/// [`Compact::encode`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, SeqRange};
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Selection {
+///     #[serde(with = "from_back::compact::serde")]
+///     range: SeqRange<usize>,
+/// }
+///
+/// let json = serde_json::to_string(&Selection { range: idx!(2..^300) }).unwrap();
+/// assert_eq!(json, r#"{"range":[4,601]}"#);
+/// ```
+#[cfg(feature = "serde")]
+pub mod serde {
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    use ::serde::de::{self, Deserializer, SeqAccess, Visitor};
+    use ::serde::ser::{self, SerializeTuple, Serializer};
+
+    use super::{Compact, MAX_INDEX_LEN};
+    use crate::SeqIndex;
+
+    /// The most bytes any of the library's structs can occupy when encoded.
+    const MAX_LEN: usize = 2 * MAX_INDEX_LEN;
+
+    /// Serialize `value` as a tuple of its indices' compact words.
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Compact,
+        S: Serializer,
+    {
+        let mut buf = [0; MAX_LEN];
+        let overflow = || ser::Error::custom("index exceeds 2^63 - 1");
+        let written = value.encode(&mut buf).ok_or_else(overflow)?;
+        let mut tuple = serializer.serialize_tuple(T::INDICES)?;
+        let mut rest = &buf[..written];
+        while let Some((index, read)) = SeqIndex::decode(rest) {
+            tuple.serialize_element(&index.to_compact().ok_or_else(overflow)?)?;
+            rest = &rest[read..];
+        }
+        tuple.end()
+    }
+
+    /// Deserialize a value from a tuple of its indices' compact words.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: Compact,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(T::INDICES, CompactVisitor(PhantomData))
+    }
+
+    struct CompactVisitor<T>(PhantomData<T>);
+
+    impl<'de, T> Visitor<'de> for CompactVisitor<T>
+    where T: Compact {
+        type Value = T;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "a tuple of {} compact index words", T::INDICES)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<T, A::Error>
+        where A: SeqAccess<'de> {
+            let mut buf = [0; MAX_LEN];
+            let mut written = 0;
+            for position in 0..T::INDICES {
+                let word: u64 = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(position, &self))?;
+                let index = SeqIndex::from_compact(word)
+                    .ok_or_else(|| de::Error::custom("index does not fit in a usize"))?;
+                written += index.encode(&mut buf[written..]).ok_or_else(|| de::Error::custom("index exceeds 2^63 - 1"))?;
+            }
+            T::decode(&buf[..written])
+                .map(|(value, _)| value)
+                .ok_or_else(|| de::Error::custom("malformed compact encoding"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idx;
+
+    fn round_trip<T>(value: T) -> usize
+    where T: Compact + PartialEq + std::fmt::Debug {
+        let mut buf = [0; 2 * MAX_INDEX_LEN];
+        let written = value.encode(&mut buf).unwrap();
+        assert_eq!(T::decode(&buf[..written]), Some((value, written)));
+        assert_eq!(T::decode(&buf[..written - 1]), None);
+        written
+    }
+
+    #[test]
+    fn test_round_trip() {
+        assert_eq!(round_trip(idx!(0)), 1);
+        assert_eq!(round_trip(idx!(^63)), 1);
+        assert_eq!(round_trip(idx!(64)), 2);
+        let max = (u64::MAX >> 1) as usize;
+        assert_eq!(round_trip(idx!(^max)), 10);
+        assert_eq!(round_trip(idx!(max..^max)), 20);
+        assert_eq!(round_trip(idx!(^2..)), 1);
+        assert_eq!(round_trip(idx!(^2..=4)), 2);
+    }
+
+    #[test]
+    fn test_format() {
+        let mut buf = [0; MAX_INDEX_LEN];
+        assert_eq!(idx!(^0).encode(&mut buf), Some(1));
+        assert_eq!(buf[0], 0x01);
+        assert_eq!(idx!(300).encode(&mut buf), Some(2));
+        assert_eq!(buf[..2], [0xd8, 0x04]);
+        assert_eq!(idx!(300).encode(&mut buf[..1]), None);
+        assert_eq!(idx!(usize::MAX).encode(&mut buf), None);
+    }
+
+    #[test]
+    fn test_decode_malformed() {
+        // overlong: bits beyond the 64th
+        assert_eq!(SeqIndex::decode(&[0xff; 9].iter().chain(&[0x7f]).copied().collect::<Vec<_>>()), None);
+        assert_eq!(SeqIndex::decode(&[0x80; 11]), None);
+        assert_eq!(SeqIndex::decode(&[]), None);
+        assert_eq!(SeqIndex::decode(&[0x06, 0xff]), Some((idx!(3), 1)));
+    }
+
+    #[test]
+    fn test_decode_non_canonical() {
+        // `idx!(3)` padded with zero groups
+        assert_eq!(SeqIndex::decode(&[0x86, 0x00]), None);
+        assert_eq!(SeqIndex::decode(&[0x86, 0x80, 0x00]), None);
+        assert_eq!(SeqRange::decode(&[0x06, 0x81, 0x00]), None);
+        // a lone zero byte is `idx!(0)`, and a zero group may precede a nonzero one
+        assert_eq!(SeqIndex::decode(&[0x00]), Some((idx!(0), 1)));
+        assert_eq!(SeqIndex::decode(&[0x80, 0x01]), Some((idx!(64), 2)));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_helpers() {
+        #[derive(::serde::Serialize, ::serde::Deserialize, Debug, PartialEq)]
+        struct Selection {
+            #[serde(with = "self::serde")]
+            index: SeqIndex<usize>,
+            #[serde(with = "self::serde")]
+            range: SeqRangeInclusive<usize>,
+        }
+
+        let selection = Selection { index: idx!(^3), range: idx!(2..=^300) };
+        let bytes = postcard::to_allocvec(&selection).unwrap();
+        let mut expected = [0; 3 * MAX_INDEX_LEN];
+        let written = selection.index.encode(&mut expected).unwrap();
+        let written = written + selection.range.encode(&mut expected[written..]).unwrap();
+        assert_eq!(bytes, expected[..written]);
+        assert_eq!(postcard::from_bytes::<Selection>(&bytes).unwrap(), selection);
+
+        let json = serde_json::to_string(&selection).unwrap();
+        assert_eq!(json, r#"{"index":[7],"range":[4,601]}"#);
+        assert_eq!(serde_json::from_str::<Selection>(&json).unwrap(), selection);
+        assert!(serde_json::from_str::<Selection>(r#"{"index":[7],"range":[4]}"#).is_err());
+    }
+}
@@ -0,0 +1,123 @@
+//! Reading ranges of containers which may not store their elements contiguously.
+
+use std::borrow::Cow;
+use std::collections::VecDeque;
+
+use crate::{SeqRange, SeqRangeError};
+
+/// Containers whose elements can be read by range, whether or not they are stored contiguously.
+///
+/// A container yields the elements of a range as a sequence of contiguous chunks:
+/// one for a slice or [`Vec`], and up to two for a [`VecDeque`] whose contents wrap around.
+/// Implement [`seq_chunks`](SeqSlice::seq_chunks) for other chunked containers, such as ropes,
+/// to get the rest of the trait.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::borrow::Cow;
+/// # use std::collections::VecDeque;
+/// # use from_back::{idx, SeqSlice};
+/// let mut deque: VecDeque<_> = (1..5).collect();
+/// deque.push_front(0);
+/// // a range within one half of the deque is borrowed
+/// assert!(matches!(deque.as_contiguous(idx!(^3..)), Ok(Cow::Borrowed([2, 3, 4]))));
+/// // a range spanning both halves is copied
+/// assert!(matches!(deque.as_contiguous(idx!(..^3)), Ok(Cow::Owned(_))));
+/// ```
+pub trait SeqSlice<T> {
+    /// The contiguous chunks of elements within the range, in order.
+    ///
+    /// Chunks may be empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the range does not fit the container.
+    fn seq_chunks<'a>(&'a self, range: impl Into<SeqRange<usize>>) -> Result<impl Iterator<Item = &'a [T]>, SeqRangeError>
+    where T: 'a;
+
+    /// The elements within the range as a single slice,
+    /// borrowed if they are stored contiguously and copied otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the range does not fit the container.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, SeqSlice};
+    /// fn checksum<S: SeqSlice<u8>>(packet: &S) -> u8 {
+    ///     packet.as_contiguous(idx!(..^1)).unwrap().iter().fold(0, |acc, byte| acc ^ byte)
+    /// }
+    /// assert_eq!(checksum(&vec![1, 2, 4, 0]), 7);
+    /// ```
+    fn as_contiguous<'a>(&'a self, range: impl Into<SeqRange<usize>>) -> Result<Cow<'a, [T]>, SeqRangeError>
+    where T: Clone + 'a {
+        let mut chunks = self.seq_chunks(range)?.filter(|chunk| !chunk.is_empty());
+        let Some(first) = chunks.next() else {
+            return Ok(Cow::Borrowed(&[]));
+        };
+        let Some(second) = chunks.next() else {
+            return Ok(Cow::Borrowed(first));
+        };
+        let mut elements = first.to_vec();
+        elements.extend_from_slice(second);
+        chunks.for_each(|chunk| elements.extend_from_slice(chunk));
+        Ok(Cow::Owned(elements))
+    }
+}
+
+impl<T> SeqSlice<T> for [T] {
+    fn seq_chunks<'a>(&'a self, range: impl Into<SeqRange<usize>>) -> Result<impl Iterator<Item = &'a [T]>, SeqRangeError>
+    where T: 'a {
+        let range = range.into().checked_for_seq_len(self.len())?;
+        Ok(std::iter::once(&self[range]))
+    }
+}
+
+impl<T> SeqSlice<T> for Vec<T> {
+    fn seq_chunks<'a>(&'a self, range: impl Into<SeqRange<usize>>) -> Result<impl Iterator<Item = &'a [T]>, SeqRangeError>
+    where T: 'a {
+        self.as_slice().seq_chunks(range)
+    }
+}
+
+impl<T> SeqSlice<T> for VecDeque<T> {
+    fn seq_chunks<'a>(&'a self, range: impl Into<SeqRange<usize>>) -> Result<impl Iterator<Item = &'a [T]>, SeqRangeError>
+    where T: 'a {
+        let range = range.into().checked_for_seq_len(self.len())?;
+        let (front, back) = self.as_slices();
+        let split = front.len();
+        let front = &front[range.start.min(split)..range.end.min(split)];
+        let back = &back[range.start.saturating_sub(split)..range.end.saturating_sub(split)];
+        Ok([front, back].into_iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idx;
+
+    #[test]
+    fn test_as_contiguous() {
+        let mut deque: VecDeque<_> = (2..6).collect();
+        deque.push_front(1);
+        deque.push_front(0);
+        assert_eq!(deque.as_slices(), (&[0, 1][..], &[2, 3, 4, 5][..]));
+        for start in 0..=6 {
+            for end in start..=6 {
+                let expected: Vec<_> = (start..end).collect();
+                let contiguous = deque.as_contiguous(idx!(start..end)).unwrap();
+                assert_eq!(*contiguous, expected);
+                let spans = start < 2 && end > 2;
+                assert_eq!(matches!(contiguous, Cow::Owned(_)), spans, "{start}..{end}");
+            }
+        }
+        assert_eq!(deque.as_contiguous(idx!(^7..)), Err(SeqRangeError::OutOfBounds { index: idx!(^7), len: 6 }));
+        let vec = Vec::from(deque);
+        assert!(matches!(vec.as_contiguous(idx!(1..^1)), Ok(Cow::Borrowed([1, 2, 3, 4]))));
+        assert!(vec.seq_chunks(idx!(^1..1)).is_err());
+    }
+}
@@ -0,0 +1,64 @@
+//! [`defmt::Format`](::defmt::Format) implementations, for logging on embedded targets.
+//!
+//! Indices and ranges are printed as they would be written in [`idx!`](crate::idx),
+//! eg `^3` or `2..^1`.
+
+use ::defmt::{write, Format, Formatter};
+
+use crate::{SeqIndex, SeqRange, SeqRangeError, SeqRangeFrom, SeqRangeInclusive, Span};
+
+impl<Idx> Format for SeqIndex<Idx>
+where Idx: Format {
+    fn format(&self, f: Formatter<'_>) {
+        match self {
+            SeqIndex::FromFront(idx) => write!(f, "{}", idx),
+            SeqIndex::FromBack(idx) => write!(f, "^{}", idx),
+        }
+    }
+}
+
+impl<Idx> Format for SeqRange<Idx>
+where Idx: Format {
+    fn format(&self, f: Formatter<'_>) {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+impl<Idx> Format for SeqRangeFrom<Idx>
+where Idx: Format {
+    fn format(&self, f: Formatter<'_>) {
+        write!(f, "{}..", self.start)
+    }
+}
+
+impl<Idx> Format for SeqRangeInclusive<Idx>
+where Idx: Format {
+    fn format(&self, f: Formatter<'_>) {
+        write!(f, "{}..={}", self.start, self.end)
+    }
+}
+
+impl Format for SeqRangeError {
+    fn format(&self, f: Formatter<'_>) {
+        match *self {
+            SeqRangeError::OutOfBounds { index, len } => {
+                write!(f, "index {} out of range for sequence of length {}", index, len)
+            }
+            SeqRangeError::Inverted { start, end } => {
+                write!(f, "range starts at index {} but ends at index {}", start, end)
+            }
+            SeqRangeError::Overlapping { index } => {
+                write!(f, "ranges overlap at index {}", index)
+            }
+            SeqRangeError::Overflow { index } => {
+                write!(f, "index {} does not fit the target integer type", index)
+            }
+        }
+    }
+}
+
+impl Format for Span {
+    fn format(&self, f: Formatter<'_>) {
+        self.range.format(f)
+    }
+}
@@ -0,0 +1,275 @@
+//! Extension methods for [`VecDeque`].
+
+use std::collections::vec_deque::Drain;
+use std::collections::VecDeque;
+
+use crate::SeqIndex;
+use crate::SeqRange;
+
+/// Extension methods for [`VecDeque`] which accept the library's structs
+/// in place of native indices and ranges.
+pub trait DequeSeqExt<T> {
+    /// Remove and return the element at the given index, or `None` if it is out of bounds.
+    ///
+    /// See [`VecDeque::remove`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::collections::VecDeque;
+    /// # use from_back::{idx, DequeSeqExt};
+    /// let mut deque: VecDeque<_> = (0..5).collect();
+    /// assert_eq!(deque.remove_seq(idx!(^2)), Some(3));
+    /// assert_eq!(deque, [0, 1, 2, 4]);
+    /// assert_eq!(deque.remove_seq(idx!(^5)), None);
+    /// ```
+    fn remove_seq(&mut self, index: SeqIndex<usize>) -> Option<T>;
+
+    /// Insert an element at the given index, shifting the elements after it toward the back.
+    ///
+    /// `idx!(^0)` inserts at the very back of the deque.
+    /// See [`VecDeque::insert`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index resolves beyond the length of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::collections::VecDeque;
+    /// # use from_back::{idx, DequeSeqExt};
+    /// let mut deque: VecDeque<_> = (0..3).collect();
+    /// deque.insert_seq(idx!(^1), 9);
+    /// deque.insert_seq(idx!(^0), 8);
+    /// assert_eq!(deque, [0, 1, 9, 2, 8]);
+    /// ```
+    #[cfg_attr(feature = "no-panic", deprecated(note = "panics on an index which does not fit"))]
+    fn insert_seq(&mut self, index: SeqIndex<usize>, value: T);
+
+    /// Swap the elements at the given indices.
+    ///
+    /// See [`VecDeque::swap`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::collections::VecDeque;
+    /// # use from_back::{idx, DequeSeqExt};
+    /// let mut deque: VecDeque<_> = (0..5).collect();
+    /// deque.swap_seq(idx!(0), idx!(^1));
+    /// assert_eq!(deque, [4, 1, 2, 3, 0]);
+    /// ```
+    #[cfg_attr(feature = "no-panic", deprecated(note = "panics on an index which does not fit"))]
+    fn swap_seq(&mut self, a: SeqIndex<usize>, b: SeqIndex<usize>);
+
+    /// Rotate the deque so that the element at the given index becomes the front.
+    ///
+    /// The deque is rotated with [`VecDeque::rotate_left`] or [`VecDeque::rotate_right`],
+    /// whichever moves fewer elements.
+    /// `idx!(^0)` and `idx!(0)` both leave the deque unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index resolves beyond the length of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::collections::VecDeque;
+    /// # use from_back::{idx, DequeSeqExt};
+    /// let mut deque: VecDeque<_> = (0..5).collect();
+    /// deque.rotate_to_seq(idx!(^2));
+    /// assert_eq!(deque, [3, 4, 0, 1, 2]);
+    /// ```
+    #[cfg_attr(feature = "no-panic", deprecated(note = "panics on an index which does not fit"))]
+    fn rotate_to_seq(&mut self, index: SeqIndex<usize>);
+
+    /// Shorten the deque so that it ends at the given index.
+    ///
+    /// `idx!(^n)` removes the last `n` elements, or every element if there are fewer than `n`.
+    /// `idx!(n)` keeps the first `n` elements, as [`VecDeque::truncate`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::collections::VecDeque;
+    /// # use from_back::{idx, DequeSeqExt};
+    /// let mut deque: VecDeque<_> = (0..5).collect();
+    /// deque.truncate_seq(idx!(^2));
+    /// assert_eq!(deque, [0, 1, 2]);
+    /// ```
+    fn truncate_seq(&mut self, end: SeqIndex<usize>);
+
+    /// Remove the given range from the deque, returning the removed elements as an iterator.
+    ///
+    /// See [`VecDeque::drain`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range does not fit the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::collections::VecDeque;
+    /// # use from_back::{idx, DequeSeqExt};
+    /// let mut deque: VecDeque<_> = (0..5).collect();
+    /// let drained: Vec<_> = deque.drain_seq(idx!(1..^1)).collect();
+    /// assert_eq!(drained, [1, 2, 3]);
+    /// assert_eq!(deque, [0, 4]);
+    /// ```
+    #[cfg_attr(feature = "no-panic", deprecated(note = "panics on an index which does not fit"))]
+    fn drain_seq(&mut self, range: SeqRange<usize>) -> Drain<'_, T>;
+
+    /// Resize the deque to `new_len` elements, adding or removing elements at the front,
+    /// so that each element keeps its back-relative index.
+    ///
+    /// New elements are clones of `value`. See [`VecDeque::resize`], which resizes at the back.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::collections::VecDeque;
+    /// # use from_back::DequeSeqExt;
+    /// let mut deque: VecDeque<_> = (1..4).collect();
+    /// deque.resize_front(5, 0);
+    /// assert_eq!(deque, [0, 0, 1, 2, 3]);
+    /// deque.resize_front(2, 0);
+    /// assert_eq!(deque, [2, 3]);
+    /// ```
+    fn resize_front(&mut self, new_len: usize, value: T)
+    where T: Clone;
+}
+
+impl<T> DequeSeqExt<T> for VecDeque<T> {
+    fn remove_seq(&mut self, index: SeqIndex<usize>) -> Option<T> {
+        match index {
+            SeqIndex::FromFront(0) => self.pop_front(),
+            SeqIndex::FromBack(1) => self.pop_back(),
+            _ => self.remove(index.checked_for_seq_len(self.len()).ok()?),
+        }
+    }
+
+    fn insert_seq(&mut self, index: SeqIndex<usize>, value: T) {
+        match index {
+            SeqIndex::FromFront(0) => self.push_front(value),
+            SeqIndex::FromBack(0) => self.push_back(value),
+            _ => self.insert(index.for_seq_len(self.len()), value),
+        }
+    }
+
+    fn swap_seq(&mut self, a: SeqIndex<usize>, b: SeqIndex<usize>) {
+        let len = self.len();
+        self.swap(a.for_seq_len(len), b.for_seq_len(len));
+    }
+
+    fn rotate_to_seq(&mut self, index: SeqIndex<usize>) {
+        let len = self.len();
+        let mid = index.for_seq_len(len);
+        if mid <= len - mid {
+            self.rotate_left(mid);
+        } else {
+            self.rotate_right(len - mid);
+        }
+    }
+
+    fn truncate_seq(&mut self, end: SeqIndex<usize>) {
+        let len = match end {
+            SeqIndex::FromFront(idx) => idx,
+            SeqIndex::FromBack(idx) => self.len().saturating_sub(idx),
+        };
+        self.truncate(len);
+    }
+
+    fn drain_seq(&mut self, range: SeqRange<usize>) -> Drain<'_, T> {
+        let range = range.for_seq_len(self.len());
+        self.drain(range)
+    }
+
+    fn resize_front(&mut self, new_len: usize, value: T)
+    where T: Clone {
+        let len = self.len();
+        if new_len <= len {
+            self.drain(..len - new_len);
+        } else {
+            self.reserve(new_len - len);
+            for _ in len..new_len {
+                self.push_front(value.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idx;
+
+    #[test]
+    fn test_remove_seq() {
+        let mut deque: VecDeque<_> = (0..5).collect();
+        assert_eq!(deque.remove_seq(idx!(^1)), Some(4));
+        assert_eq!(deque.remove_seq(idx!(0)), Some(0));
+        assert_eq!(deque.remove_seq(idx!(^0)), None);
+        assert_eq!(deque.remove_seq(idx!(3)), None);
+        assert_eq!(deque.remove_seq(idx!(^3)), Some(1));
+        assert_eq!(deque, [2, 3]);
+    }
+
+    #[test]
+    fn test_truncate_seq() {
+        let mut deque: VecDeque<_> = (0..5).collect();
+        deque.truncate_seq(idx!(^0));
+        assert_eq!(deque.len(), 5);
+        deque.truncate_seq(idx!(10));
+        assert_eq!(deque.len(), 5);
+        deque.truncate_seq(idx!(4));
+        assert_eq!(deque, [0, 1, 2, 3]);
+        deque.truncate_seq(idx!(^10));
+        assert!(deque.is_empty());
+    }
+
+    #[test]
+    fn test_resize_front() {
+        let mut deque: VecDeque<_> = (0..3).collect();
+        deque.resize_front(4, 9);
+        assert_eq!(deque, [9, 0, 1, 2]);
+        deque.resize_front(4, 9);
+        assert_eq!(deque, [9, 0, 1, 2]);
+        deque.resize_front(1, 9);
+        assert_eq!(deque, [2]);
+    }
+
+    #[test]
+    fn test_rotate_to_seq() {
+        let rotated = |index| {
+            let mut deque: VecDeque<_> = (0..5).collect();
+            deque.rotate_to_seq(index);
+            deque
+        };
+        assert_eq!(rotated(idx!(1)), [1, 2, 3, 4, 0]);
+        assert_eq!(rotated(idx!(^1)), [4, 0, 1, 2, 3]);
+        assert_eq!(rotated(idx!(^5)), [0, 1, 2, 3, 4]);
+        assert_eq!(rotated(idx!(^0)), [0, 1, 2, 3, 4]);
+        assert_eq!(rotated(idx!(5)), [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rotate_to_seq_out_of_bounds() {
+        let mut deque: VecDeque<_> = (0..5).collect();
+        deque.rotate_to_seq(idx!(^6));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_insert_seq_out_of_bounds() {
+        let mut deque: VecDeque<_> = (0..5).collect();
+        deque.insert_seq(idx!(^6), 0);
+    }
+}
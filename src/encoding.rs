@@ -0,0 +1,170 @@
+//! Slicing byte buffers in legacy text encodings without splitting their characters.
+//!
+//! Ranges here count bytes, as native indexing does, but each bound is snapped inward
+//! to a character boundary of the buffer's [`Encoding`], so a slice holds only whole characters.
+//! Many legacy encodings, such as Shift_JIS and GBK, are not self-synchronizing,
+//! so boundaries are found by decoding the buffer from the front.
+//! Malformed sequences count as characters of their own.
+//!
+//! Stateful encodings such as ISO-2022-JP can be sliced at character boundaries,
+//! but a slice may depend on shift state set before its start,
+//! and decoding for boundaries restarts in the initial state after a malformed sequence.
+
+use std::ops::Range;
+
+use encoding_rs::{DecoderResult, Encoding};
+
+use crate::SeqRange;
+
+/// The byte offsets of the character boundaries of `bytes` in the given encoding,
+/// in ascending order, including `0` and the length of `bytes`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use encoding_rs::SHIFT_JIS;
+/// # use from_back::encoding::char_boundaries;
+/// let (bytes, _, _) = SHIFT_JIS.encode("日本a");
+/// assert_eq!(char_boundaries(&bytes, SHIFT_JIS), [0, 2, 4, 5]);
+/// ```
+pub fn char_boundaries(bytes: &[u8], encoding: &'static Encoding) -> Vec<usize> {
+    let mut boundaries: Vec<_> = decoded_boundaries(bytes, encoding).collect();
+    if boundaries.last() != Some(&bytes.len()) {
+        boundaries.push(bytes.len());
+    }
+    boundaries
+}
+
+/// The byte offsets after each character which decodes completely within `bytes`, starting with `0`.
+///
+/// Decoding only looks back, so these are boundaries of any buffer which `bytes` is a prefix of.
+fn decoded_boundaries<'a>(bytes: &'a [u8], encoding: &'static Encoding) -> impl Iterator<Item = usize> + 'a {
+    let mut decoder = encoding.new_decoder_without_bom_handling();
+    // one byte completes at most two chars, for a few Big5 sequences
+    let mut out = [0; 8];
+    let mut pos = 0;
+    let ends = std::iter::from_fn(move || {
+        while pos < bytes.len() {
+            let (result, read, written) = decoder.decode_to_utf8_without_replacement(&bytes[pos..=pos], &mut out, false);
+            pos += read;
+            if let DecoderResult::Malformed(_, extra) = result {
+                // A byte which reveals a malformed sequence may be left unread, to be fed again.
+                // Bytes read past the sequence are held back by the decoder, so decode them afresh.
+                let end = pos - usize::from(extra);
+                if extra > 0 {
+                    decoder = encoding.new_decoder_without_bom_handling();
+                    pos = end;
+                }
+                return Some(end);
+            }
+            if written > 0 {
+                return Some(pos);
+            }
+        }
+        None
+    });
+    std::iter::once(0).chain(ends)
+}
+
+/// Resolve a byte range against `bytes`, snapping its start forward and its end back
+/// to character boundaries in the given encoding, or `None` if the range does not fit.
+///
+/// Only the bytes before the end of the range are decoded.
+/// A range which holds no whole character snaps to an empty range.
+///
+/// # Examples
+///
+/// ```rust
+/// # use encoding_rs::SHIFT_JIS;
+/// # use from_back::idx;
+/// # use from_back::encoding::snap_range;
+/// let (bytes, _, _) = SHIFT_JIS.encode("ログ: 完了");
+/// // the last 3 bytes start partway through "完"
+/// assert_eq!(snap_range(&bytes, SHIFT_JIS, idx!(^3..)), Some(bytes.len() - 2..bytes.len()));
+/// ```
+pub fn snap_range(bytes: &[u8], encoding: &'static Encoding, range: impl Into<SeqRange<usize>>) -> Option<Range<usize>> {
+    let range = range.into().checked_for_seq_len(bytes.len()).ok()?;
+    let (mut start, mut end) = (None, 0);
+    for boundary in decoded_boundaries(&bytes[..range.end], encoding) {
+        if boundary >= range.start {
+            start.get_or_insert(boundary);
+        }
+        end = boundary;
+    }
+    // the end of the buffer is a boundary, even after an incomplete character
+    if range.end == bytes.len() {
+        end = range.end;
+        start.get_or_insert(end);
+    }
+    let start = start.filter(|&start| start <= end).unwrap_or(end);
+    Some(start..end)
+}
+
+/// Slice `bytes` by a byte range, snapped to character boundaries as by [`snap_range`],
+/// or `None` if the range does not fit.
+///
+/// # Examples
+///
+/// ```rust
+/// # use encoding_rs::GBK;
+/// # use from_back::idx;
+/// # use from_back::encoding::slice_snapped;
+/// let (bytes, _, _) = GBK.encode("错误：文件");
+/// let tail = slice_snapped(&bytes, GBK, idx!(^5..)).unwrap();
+/// assert_eq!(GBK.decode_without_bom_handling(tail).0, "文件");
+/// ```
+pub fn slice_snapped<'a>(bytes: &'a [u8], encoding: &'static Encoding, range: impl Into<SeqRange<usize>>) -> Option<&'a [u8]> {
+    Some(&bytes[snap_range(bytes, encoding, range)?])
+}
+
+#[cfg(test)]
+mod tests {
+    use encoding_rs::{BIG5, GB18030, SHIFT_JIS, UTF_16LE, UTF_8, WINDOWS_1252};
+
+    use super::*;
+    use crate::idx;
+
+    #[test]
+    fn test_char_boundaries() {
+        // halfwidth katakana is a single byte in Shift_JIS
+        let bytes = SHIFT_JIS.encode("aｱ日").0.into_owned();
+        assert_eq!(char_boundaries(&bytes, SHIFT_JIS), [0, 1, 2, 4]);
+        // a trailing lead byte is incomplete, but still ends the buffer
+        assert_eq!(char_boundaries(&bytes[..3], SHIFT_JIS), [0, 1, 2, 3]);
+        assert_eq!(char_boundaries(b"", SHIFT_JIS), [0]);
+        assert_eq!(char_boundaries(b"ab", WINDOWS_1252), [0, 1, 2]);
+        // a malformed byte is a character of its own
+        assert_eq!(char_boundaries(b"\xa0a", SHIFT_JIS), [0, 1, 2]);
+        assert_eq!(char_boundaries(&[0x61, 0, 0x3d, 0xd8, 0, 0xde], UTF_16LE), [0, 2, 6]);
+        // a Big5 sequence which decodes to two chars
+        assert_eq!(char_boundaries(&[0x88, 0x62, 0x61], BIG5), [0, 2, 3]);
+    }
+
+    #[test]
+    fn test_char_boundaries_after_malformed() {
+        // the byte which reveals the malformed sequence starts a character of its own
+        assert_eq!(char_boundaries(&[0x88, 0x61, 0x62], SHIFT_JIS), [0, 1, 2, 3]);
+        assert_eq!(char_boundaries(&[0xe6, 0x97, 0x61], UTF_8), [0, 2, 3]);
+        // bytes read past the malformed sequence are still split into characters
+        assert_eq!(char_boundaries(&[0x81, 0x30, 0x81, 0x61], GB18030), [0, 1, 2, 4]);
+        assert_eq!(char_boundaries(&[0x3d, 0xd8, 0x61, 0, 0x62, 0], UTF_16LE), [0, 2, 4, 6]);
+        let bytes = [0x88, 0x61, 0x62];
+        assert_eq!(snap_range(&bytes, SHIFT_JIS, idx!(1..)), Some(1..3));
+    }
+
+    #[test]
+    fn test_snap_range() {
+        // each char is 2 bytes in Shift_JIS
+        let (bytes, _, _) = SHIFT_JIS.encode("日本語");
+        let snap = |range: SeqRange<usize>| snap_range(&bytes, SHIFT_JIS, range);
+        assert_eq!(snap(idx!(1..5)), Some(2..4));
+        assert_eq!(snap(idx!(0..^0)), Some(0..6));
+        assert_eq!(snap(idx!(^1..^0)), Some(6..6));
+        assert_eq!(snap(idx!(3..4)), Some(4..4));
+        assert_eq!(snap(idx!(2..3)), Some(2..2));
+        assert_eq!(snap(idx!(^7..^0)), None);
+        let bytes = SHIFT_JIS.encode("a日").0.into_owned();
+        assert_eq!(snap_range(&bytes[..2], SHIFT_JIS, idx!(1..)), Some(1..2));
+        assert_eq!(slice_snapped(&bytes, SHIFT_JIS, idx!(..^1)), Some(&b"a"[..]));
+    }
+}
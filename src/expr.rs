@@ -0,0 +1,191 @@
+//! Range bounds which are arithmetic over the length of the sequence.
+//!
+//! A [`SeqRangeExpr`] describes a range like `len/2..^1` or `^(len/4)..` up front,
+//! and resolves it in one call once the length is known.
+
+use std::ops::{Add, Div, Mul, Range, Sub};
+
+use crate::{SeqIndex, SeqRange, SeqRangeFrom};
+
+/// An arithmetic expression over the length of a sequence.
+///
+/// Expressions are built from [`LEN`] and integers with the `+`, `-`, `*` and `/` operators.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{LenExpr, LEN};
+/// let expr = LEN / 4 + 1;
+/// assert_eq!(expr.eval(10), Some(3));
+/// assert_eq!((LenExpr::from(2) - LEN).eval(10), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LenExpr {
+    /// A constant.
+    Lit(usize),
+    /// The length of the sequence.
+    Len,
+    /// The sum of two expressions.
+    Add(Box<LenExpr>, Box<LenExpr>),
+    /// The difference of two expressions.
+    Sub(Box<LenExpr>, Box<LenExpr>),
+    /// The product of two expressions.
+    Mul(Box<LenExpr>, Box<LenExpr>),
+    /// The quotient of two expressions, rounded down.
+    Div(Box<LenExpr>, Box<LenExpr>),
+}
+
+/// The length of the sequence, as a [`LenExpr`].
+pub const LEN: LenExpr = LenExpr::Len;
+
+impl LenExpr {
+    /// Evaluate the expression for a sequence of length `len`.
+    ///
+    /// Returns `None` if any step overflows, underflows, or divides by zero.
+    pub fn eval(&self, len: usize) -> Option<usize> {
+        match self {
+            LenExpr::Lit(value) => Some(*value),
+            LenExpr::Len => Some(len),
+            LenExpr::Add(lhs, rhs) => lhs.eval(len)?.checked_add(rhs.eval(len)?),
+            LenExpr::Sub(lhs, rhs) => lhs.eval(len)?.checked_sub(rhs.eval(len)?),
+            LenExpr::Mul(lhs, rhs) => lhs.eval(len)?.checked_mul(rhs.eval(len)?),
+            LenExpr::Div(lhs, rhs) => lhs.eval(len)?.checked_div(rhs.eval(len)?),
+        }
+    }
+}
+
+impl From<usize> for LenExpr {
+    fn from(value: usize) -> Self {
+        LenExpr::Lit(value)
+    }
+}
+
+macro_rules! impl_len_expr_op {
+    ($($trait:ident::$method:ident),*) => { $(
+        impl<R> $trait<R> for LenExpr
+        where R: Into<LenExpr> {
+            type Output = LenExpr;
+
+            fn $method(self, rhs: R) -> LenExpr {
+                LenExpr::$trait(Box::new(self), Box::new(rhs.into()))
+            }
+        }
+    )* };
+}
+
+impl_len_expr_op!(Add::add, Sub::sub, Mul::mul, Div::div);
+
+/// Convert a bound of any expression-like type to a [`LenExpr`] bound.
+fn bound<A>(index: SeqIndex<A>) -> SeqIndex<LenExpr>
+where A: Into<LenExpr> {
+    match index {
+        SeqIndex::FromFront(idx) => SeqIndex::FromFront(idx.into()),
+        SeqIndex::FromBack(idx) => SeqIndex::FromBack(idx.into()),
+    }
+}
+
+/// A range whose bounds are [`LenExpr`]s, resolved once the length of the sequence is known.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, SeqRangeExpr, LEN};
+/// // the back half, save for the last element
+/// let range = SeqRangeExpr::new(idx!(LEN / 2), idx!(^1));
+/// assert_eq!(range.resolve(10), Some(5..9));
+///
+/// // the last quarter
+/// let range = SeqRangeExpr::from_start(idx!(^LEN / 4));
+/// assert_eq!(range.resolve(12), Some(9..12));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SeqRangeExpr {
+    /// The lower bound of the range (inclusive).
+    pub start: SeqIndex<LenExpr>,
+    /// The upper bound of the range (exclusive).
+    pub end: SeqIndex<LenExpr>,
+}
+
+impl SeqRangeExpr {
+    /// Create a range from its bounds.
+    pub fn new<A, B>(start: SeqIndex<A>, end: SeqIndex<B>) -> Self
+    where
+        A: Into<LenExpr>,
+        B: Into<LenExpr>,
+    {
+        SeqRangeExpr { start: bound(start), end: bound(end) }
+    }
+
+    /// Create a range from its start, which extends to the back of the sequence.
+    pub fn from_start<A>(start: SeqIndex<A>) -> Self
+    where A: Into<LenExpr> {
+        SeqRangeExpr::new(start, SeqIndex::FromBack(0))
+    }
+
+    /// Evaluate both bounds for a sequence of length `len`, without resolving their anchors.
+    ///
+    /// Returns `None` if either bound fails to evaluate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, SeqRangeExpr, LEN};
+    /// let range = SeqRangeExpr::new(idx!(LEN / 2), idx!(^1));
+    /// assert_eq!(range.eval(10), Some(idx!(5..^1)));
+    /// ```
+    pub fn eval(&self, len: usize) -> Option<SeqRange<usize>> {
+        let eval = |index: &SeqIndex<LenExpr>| match index {
+            SeqIndex::FromFront(expr) => expr.eval(len).map(SeqIndex::FromFront),
+            SeqIndex::FromBack(expr) => expr.eval(len).map(SeqIndex::FromBack),
+        };
+        Some(SeqRange { start: eval(&self.start)?, end: eval(&self.end)? })
+    }
+
+    /// Resolve to a range which fits a sequence of length `len`.
+    ///
+    /// Returns `None` if either bound fails to evaluate, or the evaluated range does not fit.
+    pub fn resolve(&self, len: usize) -> Option<Range<usize>> {
+        self.eval(len)?.checked_for_seq_len(len).ok()
+    }
+}
+
+impl<A> From<SeqRange<A>> for SeqRangeExpr
+where A: Into<LenExpr> {
+    fn from(range: SeqRange<A>) -> Self {
+        SeqRangeExpr::new(range.start, range.end)
+    }
+}
+
+impl<A> From<SeqRangeFrom<A>> for SeqRangeExpr
+where A: Into<LenExpr> {
+    fn from(range: SeqRangeFrom<A>) -> Self {
+        SeqRangeExpr::from_start(range.start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idx;
+
+    #[test]
+    fn test_eval() {
+        assert_eq!((LEN * 3 / 4).eval(10), Some(7));
+        assert_eq!((LEN - 1).eval(0), None);
+        assert_eq!((LEN / 0).eval(10), None);
+        assert_eq!((LEN * usize::MAX).eval(2), None);
+        assert_eq!((LenExpr::from(5) - LEN / 2).eval(6), Some(2));
+    }
+
+    #[test]
+    fn test_resolve() {
+        let middle = SeqRangeExpr::new(idx!(LEN / 4), idx!(^LEN / 4));
+        assert_eq!(middle.resolve(8), Some(2..6));
+        assert_eq!(middle.resolve(0), Some(0..0));
+        assert_eq!(SeqRangeExpr::from(idx!(1..^1)).resolve(4), Some(1..3));
+        assert_eq!(SeqRangeExpr::from(idx!(^3..)).resolve(4), Some(1..4));
+        assert_eq!(SeqRangeExpr::new(idx!(LEN), idx!(1)).resolve(4), None);
+        assert_eq!(SeqRangeExpr::new(idx!(LEN + 1), idx!(^0)).resolve(4), None);
+        assert_eq!(SeqRangeExpr::new(idx!(LEN - 5), idx!(^0)).resolve(4), None);
+    }
+}
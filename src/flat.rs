@@ -0,0 +1,185 @@
+//! Two-dimensional access to a grid stored as one flat buffer.
+
+use std::iter::StepBy;
+use std::ops::{Deref, DerefMut, Range};
+use std::slice;
+
+use crate::{SeqIndex, SeqRange};
+
+/// A grid stored row by row in a flat buffer, such as `&[T]`, `&mut [T]`, or `Vec<T>`.
+///
+/// Rows and columns are addressed by [`SeqIndex`], and regions by a pair of [`SeqRange`]s,
+/// each resolved against the grid's height or width rather than the whole buffer.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, Flat2D};
+/// let pixels = [
+///     1, 2, 3, 4,
+///     5, 6, 7, 8,
+///     9, 10, 11, 12,
+/// ];
+/// let grid = Flat2D::new(&pixels[..], 4).unwrap();
+/// assert_eq!(grid.row_seq(idx!(^1)), Some(&[9, 10, 11, 12][..]));
+/// assert_eq!(grid.col_seq(idx!(0)).unwrap().collect::<Vec<_>>(), [&1, &5, &9]);
+/// let region: Vec<_> = grid.region(idx!(^2..), idx!(1..^1)).unwrap().collect();
+/// assert_eq!(region, [&[6, 7][..], &[10, 11][..]]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Flat2D<S> {
+    data: S,
+    width: usize,
+}
+
+impl<T, S> Flat2D<S>
+where S: Deref<Target = [T]> {
+    /// View `data` as rows of `width` elements.
+    ///
+    /// Returns `None` unless `width` is nonzero and evenly divides the length of `data`.
+    pub fn new(data: S, width: usize) -> Option<Self> {
+        if width != 0 && data.len().is_multiple_of(width) {
+            Some(Flat2D { data, width })
+        } else {
+            None
+        }
+    }
+
+    /// The number of elements in each row.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The number of rows.
+    pub fn height(&self) -> usize {
+        self.data.len() / self.width
+    }
+
+    /// Give back the underlying buffer.
+    pub fn into_inner(self) -> S {
+        self.data
+    }
+
+    /// The row at the given index, or `None` if there is none.
+    pub fn row_seq(&self, row: SeqIndex<usize>) -> Option<&[T]> {
+        let rows = self.row_range(row)?;
+        Some(&self.data[rows])
+    }
+
+    /// Iterate over the column at the given index, or `None` if there is none.
+    pub fn col_seq(&self, col: SeqIndex<usize>) -> Option<StepBy<slice::Iter<'_, T>>> {
+        let col = resolve(col, self.width)?;
+        // an empty grid has columns, but no elements in them
+        Some(self.data.get(col..).unwrap_or_default().iter().step_by(self.width))
+    }
+
+    /// Iterate over the rows within `rows`, each sliced to the columns within `cols`,
+    /// or `None` if either range does not fit the grid.
+    pub fn region<'a>(
+        &'a self,
+        rows: impl Into<SeqRange<usize>>,
+        cols: impl Into<SeqRange<usize>>,
+    ) -> Option<impl Iterator<Item = &'a [T]> + 'a>
+    where T: 'a {
+        let (flat, cols) = self.region_ranges(rows.into(), cols.into())?;
+        Some(self.data[flat].chunks_exact(self.width).map(move |row| &row[cols.clone()]))
+    }
+
+    /// Resolve a row index to its range within the buffer.
+    fn row_range(&self, row: SeqIndex<usize>) -> Option<Range<usize>> {
+        let row = resolve(row, self.height())?;
+        Some(row * self.width..(row + 1) * self.width)
+    }
+
+    /// Resolve a region to the range of whole rows it spans within the buffer,
+    /// and the range of columns within each row.
+    fn region_ranges(&self, rows: SeqRange<usize>, cols: SeqRange<usize>) -> Option<(Range<usize>, Range<usize>)> {
+        let rows = rows.checked_for_seq_len(self.height()).ok()?;
+        let cols = cols.checked_for_seq_len(self.width).ok()?;
+        Some((rows.start * self.width..rows.end * self.width, cols))
+    }
+}
+
+impl<T, S> Flat2D<S>
+where S: DerefMut<Target = [T]> {
+    /// The row at the given index mutably, or `None` if there is none.
+    pub fn row_seq_mut(&mut self, row: SeqIndex<usize>) -> Option<&mut [T]> {
+        let rows = self.row_range(row)?;
+        Some(&mut self.data[rows])
+    }
+
+    /// Iterate mutably over the column at the given index, or `None` if there is none.
+    pub fn col_seq_mut(&mut self, col: SeqIndex<usize>) -> Option<StepBy<slice::IterMut<'_, T>>> {
+        let col = resolve(col, self.width)?;
+        let width = self.width;
+        Some(self.data.get_mut(col..).unwrap_or_default().iter_mut().step_by(width))
+    }
+
+    /// Iterate mutably over the rows within `rows`, each sliced to the columns within `cols`,
+    /// or `None` if either range does not fit the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, Flat2D};
+    /// let mut pixels = vec![0; 12];
+    /// let mut grid = Flat2D::new(&mut pixels[..], 4).unwrap();
+    /// // draw a 2x2 box in the bottom right corner
+    /// grid.region_mut(idx!(^2..), idx!(^2..)).unwrap().for_each(|row| row.fill(1));
+    /// assert_eq!(pixels, [0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 1, 1]);
+    /// ```
+    pub fn region_mut<'a>(
+        &'a mut self,
+        rows: impl Into<SeqRange<usize>>,
+        cols: impl Into<SeqRange<usize>>,
+    ) -> Option<impl Iterator<Item = &'a mut [T]> + 'a>
+    where T: 'a {
+        let (flat, cols) = self.region_ranges(rows.into(), cols.into())?;
+        let width = self.width;
+        Some(self.data[flat].chunks_exact_mut(width).map(move |row| &mut row[cols.clone()]))
+    }
+}
+
+/// Resolve an index to a position within `0..len`, if it refers to an element.
+fn resolve(index: SeqIndex<usize>, len: usize) -> Option<usize> {
+    index.checked_for_seq_len(len).ok().filter(|&index| index < len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idx;
+
+    #[test]
+    fn test_flat2d() {
+        let cells: Vec<_> = (0..12).collect();
+        let grid = Flat2D::new(&cells[..], 3).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+        assert_eq!(grid.row_seq(idx!(^1)), Some(&[9, 10, 11][..]));
+        assert_eq!(grid.row_seq(idx!(4)), None);
+        assert_eq!(grid.row_seq(idx!(^0)), None);
+        assert_eq!(grid.col_seq(idx!(^1)).unwrap().copied().collect::<Vec<_>>(), [2, 5, 8, 11]);
+        assert!(grid.col_seq(idx!(3)).is_none());
+        let region = |rows: SeqRange<usize>, cols: SeqRange<usize>| {
+            grid.region(rows, cols).map(|rows| rows.map(<[_]>::to_vec).collect::<Vec<_>>())
+        };
+        assert_eq!(region(idx!(1..^1), idx!(^2..^0)), Some(vec![vec![4, 5], vec![7, 8]]));
+        assert_eq!(region(idx!(^0..^0), idx!(0..^0)), Some(vec![]));
+        assert_eq!(region(idx!(0..1), idx!(1..1)), Some(vec![vec![]]));
+        assert_eq!(region(idx!(0..5), idx!(0..1)), None);
+        assert_eq!(region(idx!(0..1), idx!(^4..^0)), None);
+        assert_eq!(Flat2D::new(&cells[..], 5), None);
+        assert_eq!(Flat2D::new(&cells[..], 0), None);
+        let empty = Flat2D::new(&cells[..0], 3).unwrap();
+        assert_eq!(empty.height(), 0);
+        assert_eq!(empty.col_seq(idx!(^1)).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_flat2d_mut() {
+        let mut grid = Flat2D::new(vec![0; 6], 2).unwrap();
+        grid.row_seq_mut(idx!(^1)).unwrap().fill(1);
+        grid.col_seq_mut(idx!(^1)).unwrap().for_each(|n| *n += 10);
+        assert_eq!(grid.into_inner(), [0, 10, 0, 10, 1, 11]);
+    }
+}
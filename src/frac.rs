@@ -0,0 +1,524 @@
+//! Indices which address a sequence by proportion of its length.
+
+use std::ops::*;
+
+use crate::{SeqIndex, SeqRange, SeqRangeError, SeqRangeFrom, SeqRangeInclusive};
+
+/// How a [`Frac`] rounds when its proportion falls between two indices.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round to the nearest index, with halfway cases rounding away from the anchor.
+    #[default]
+    Nearest,
+    /// Round toward the anchor.
+    TowardAnchor,
+    /// Round away from the anchor.
+    AwayFromAnchor,
+}
+
+/// A proportion of a sequence's length, for use as the `Idx` of the library's structs.
+///
+/// A `SeqIndex::FromFront(frac)` resolves to `len * frac` elements from the front,
+/// and a `SeqIndex::FromBack(frac)` to `len * frac` elements from the back,
+/// rounded according to the `Frac`'s [`Rounding`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, Frac};
+/// let vec: Vec<_> = (0..10).collect();
+///
+/// // the middle 50% of the sequence
+/// let quarter = Frac::new(0.25);
+/// assert_eq!(&vec[idx!(quarter..^quarter)], &[3, 4, 5, 6]);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Frac {
+    value: f64,
+    rounding: Rounding,
+}
+
+impl Frac {
+    /// Create a new proportion, which rounds to the nearest index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is not within `0.0..=1.0`.
+    #[cfg_attr(feature = "no-panic", deprecated(note = "panics on an index which does not fit"))]
+    pub fn new(value: f64) -> Self {
+        assert!((0.0..=1.0).contains(&value), "fraction {value} is not within 0.0..=1.0");
+        Frac { value, rounding: Rounding::Nearest }
+    }
+
+    /// Create a new proportion, which rounds to the nearest index,
+    /// or `None` if `value` is not within `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::Frac;
+    /// assert!(Frac::checked_new(0.5).is_some());
+    /// assert!(Frac::checked_new(1.5).is_none());
+    /// assert!(Frac::checked_new(f64::NAN).is_none());
+    /// ```
+    pub fn checked_new(value: f64) -> Option<Self> {
+        (0.0..=1.0).contains(&value).then_some(Frac { value, rounding: Rounding::Nearest })
+    }
+
+    /// Set how this proportion rounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, Frac, Rounding};
+    /// let third = Frac::new(1.0 / 3.0);
+    /// assert_eq!(idx!(third).for_seq_len(5), 2);
+    /// assert_eq!(idx!(third.with_rounding(Rounding::TowardAnchor)).for_seq_len(5), 1);
+    /// ```
+    pub fn with_rounding(self, rounding: Rounding) -> Self {
+        Frac { rounding, ..self }
+    }
+
+    /// The proportion of the length this represents.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// How this proportion rounds.
+    pub fn rounding(&self) -> Rounding {
+        self.rounding
+    }
+
+    /// The number of elements this proportion spans, counting from its anchor.
+    fn offset_for_seq_len(&self, len: usize) -> usize {
+        let offset = len as f64 * self.value;
+        let offset = match self.rounding {
+            Rounding::Nearest => offset.round(),
+            Rounding::TowardAnchor => offset.floor(),
+            Rounding::AwayFromAnchor => offset.ceil(),
+        };
+        // `value` is within `0.0..=1.0`, but past 2^53 the `f64` product can round above `len`.
+        (offset as usize).min(len)
+    }
+}
+
+/// A bound which is either a number of elements or a proportion of the length,
+/// so that one range can mix the two.
+///
+/// Both bounds of an [`idx!`](crate::idx) range share one type, so a mixed range
+/// is built from a `SeqIndex<usize>` and a `SeqIndex<Frac>` converted with [`Into`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, Anchor, SeqIndex, SeqRange};
+/// let vec: Vec<_> = (0..10).collect();
+/// // from the midpoint to just before the last element
+/// let range: SeqRange<Anchor> = SeqRange { start: SeqIndex::midpoint().into(), end: idx!(^1).into() };
+/// assert_eq!(&vec[range], &[5, 6, 7, 8]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Anchor {
+    /// A number of elements, counting from the index's anchor.
+    Len(usize),
+    /// A proportion of the length, counting from the index's anchor.
+    Frac(Frac),
+}
+
+impl Anchor {
+    /// The number of elements this bound spans, counting from its anchor.
+    fn offset_for_seq_len(&self, len: usize) -> usize {
+        match *self {
+            Anchor::Len(offset) => offset,
+            Anchor::Frac(frac) => frac.offset_for_seq_len(len),
+        }
+    }
+}
+
+impl From<usize> for Anchor {
+    fn from(offset: usize) -> Self {
+        Anchor::Len(offset)
+    }
+}
+
+impl From<Frac> for Anchor {
+    fn from(frac: Frac) -> Self {
+        Anchor::Frac(frac)
+    }
+}
+
+macro_rules! impl_into_anchor {
+    ($($idx:ty),*) => { $(
+        impl From<SeqIndex<$idx>> for SeqIndex<Anchor> {
+            fn from(index: SeqIndex<$idx>) -> Self {
+                match index {
+                    SeqIndex::FromFront(offset) => SeqIndex::FromFront(offset.into()),
+                    SeqIndex::FromBack(offset) => SeqIndex::FromBack(offset.into()),
+                }
+            }
+        }
+    )* };
+}
+
+impl_into_anchor!(usize, Frac);
+
+impl SeqIndex<Frac> {
+    /// The midpoint of a sequence, `len / 2` elements from the front.
+    ///
+    /// For a sequence of odd length this is the index of the middle element.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{SeqIndex, SeqRangeFrom};
+    /// let vec: Vec<_> = (0..7).collect();
+    /// let mid = SeqIndex::midpoint();
+    /// assert_eq!(mid.for_seq_len(vec.len()), 3);
+    /// assert_eq!(&vec[SeqRangeFrom { start: mid }], &[3, 4, 5, 6]);
+    /// ```
+    pub fn midpoint() -> Self {
+        SeqIndex::FromFront(Frac { value: 0.5, rounding: Rounding::TowardAnchor })
+    }
+
+    /// The first quartile of a sequence, `len / 4` elements from the front.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{SeqIndex, SeqRange};
+    /// let vec: Vec<_> = (0..10).collect();
+    /// let range = SeqRange {
+    ///     start: SeqIndex::first_quartile(),
+    ///     end: SeqIndex::third_quartile(),
+    /// };
+    /// assert_eq!(&vec[range], &[2, 3, 4, 5, 6]);
+    /// ```
+    pub fn first_quartile() -> Self {
+        SeqIndex::FromFront(Frac { value: 0.25, rounding: Rounding::TowardAnchor })
+    }
+
+    /// The third quartile of a sequence, `len * 3 / 4` elements from the front.
+    ///
+    /// See [`SeqIndex::first_quartile`].
+    pub fn third_quartile() -> Self {
+        SeqIndex::FromFront(Frac { value: 0.75, rounding: Rounding::TowardAnchor })
+    }
+
+    /// Convert this container to a native from-front [`usize`] for a sequence of the given `len`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, Frac};
+    /// let tenth = Frac::new(0.1);
+    /// assert_eq!(idx!(tenth).for_seq_len(50), 5);
+    /// assert_eq!(idx!(^tenth).for_seq_len(50), 45);
+    /// ```
+    pub fn for_seq_len(&self, len: usize) -> usize {
+        match *self {
+            SeqIndex::FromFront(frac) => frac.offset_for_seq_len(len),
+            SeqIndex::FromBack(frac) => len - frac.offset_for_seq_len(len),
+        }
+    }
+
+    /// Resolve the fraction to a number of elements, leaving the direction unchanged.
+    fn offsets_for_seq_len(&self, len: usize) -> SeqIndex<usize> {
+        match *self {
+            SeqIndex::FromFront(frac) => SeqIndex::FromFront(frac.offset_for_seq_len(len)),
+            SeqIndex::FromBack(frac) => SeqIndex::FromBack(frac.offset_for_seq_len(len)),
+        }
+    }
+}
+
+impl SeqRange<Frac> {
+    /// Convert this container to a native [`Range`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, Frac};
+    /// let tenth = Frac::new(0.1);
+    /// assert_eq!(idx!(tenth..^tenth).for_seq_len(50), 5..45);
+    /// ```
+    pub fn for_seq_len(&self, len: usize) -> Range<usize> {
+        self.start.for_seq_len(len)..self.end.for_seq_len(len)
+    }
+}
+
+impl SeqRangeFrom<Frac> {
+    /// Convert this container to a native [`RangeFrom`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, Frac};
+    /// let tenth = Frac::new(0.1);
+    /// assert_eq!(idx!(^tenth..).for_seq_len(50), 45..);
+    /// ```
+    pub fn for_seq_len(&self, len: usize) -> RangeFrom<usize> {
+        self.start.for_seq_len(len)..
+    }
+}
+
+impl SeqRangeInclusive<Frac> {
+    /// Convert this container to a native [`RangeInclusive`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the end resolves to `len`, one past the last element, as with `idx!(..=^0)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, Frac};
+    /// let tenth = Frac::new(0.1);
+    /// assert_eq!(idx!(tenth..=^tenth).for_seq_len(50), 5..=45);
+    /// ```
+    #[cfg_attr(feature = "no-panic", deprecated(note = "panics on an index which does not fit"))]
+    #[track_caller]
+    pub fn for_seq_len(&self, len: usize) -> RangeInclusive<usize> {
+        let end = self.end.offsets_for_seq_len(len);
+        if !end.fits_seq_len(len) {
+            panic!("{}", SeqRangeError::OutOfBounds { index: end, len });
+        }
+        self.start.for_seq_len(len)..=end.for_seq_len(len)
+    }
+
+    /// Resolve to an exclusive native [`Range`] for the [`Index`] impls.
+    #[track_caller]
+    fn index_for_seq_len(&self, len: usize) -> Range<usize> {
+        let range = SeqRangeInclusive { start: self.start.offsets_for_seq_len(len), end: self.end.offsets_for_seq_len(len) };
+        range.checked_for_seq_len(len).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+impl SeqIndex<Anchor> {
+    /// Convert this container to a native from-front [`usize`] for a sequence of the given `len`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a from-back number of elements exceeds the given length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, Anchor, Frac, SeqIndex};
+    /// let tenth: SeqIndex<Anchor> = idx!(^Frac::new(0.1)).into();
+    /// assert_eq!(tenth.for_seq_len(50), 45);
+    /// let last: SeqIndex<Anchor> = idx!(^1).into();
+    /// assert_eq!(last.for_seq_len(50), 49);
+    /// ```
+    #[cfg_attr(feature = "no-panic", deprecated(note = "panics on an index which does not fit"))]
+    #[track_caller]
+    pub fn for_seq_len(&self, len: usize) -> usize {
+        self.offsets_for_seq_len(len).for_seq_len(len)
+    }
+
+    /// Resolve each anchor to a number of elements, leaving the direction unchanged.
+    fn offsets_for_seq_len(&self, len: usize) -> SeqIndex<usize> {
+        match *self {
+            SeqIndex::FromFront(anchor) => SeqIndex::FromFront(anchor.offset_for_seq_len(len)),
+            SeqIndex::FromBack(anchor) => SeqIndex::FromBack(anchor.offset_for_seq_len(len)),
+        }
+    }
+}
+
+impl SeqRange<Anchor> {
+    /// Convert this container to a native [`Range`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index's conversion panics.
+    #[cfg_attr(feature = "no-panic", deprecated(note = "panics on an index which does not fit"))]
+    #[track_caller]
+    pub fn for_seq_len(&self, len: usize) -> Range<usize> {
+        let range = SeqRange { start: self.start.offsets_for_seq_len(len), end: self.end.offsets_for_seq_len(len) };
+        range.for_seq_len(len)
+    }
+}
+
+impl SeqRangeFrom<Anchor> {
+    /// Convert this container to a native [`RangeFrom`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start index's conversion panics.
+    #[cfg_attr(feature = "no-panic", deprecated(note = "panics on an index which does not fit"))]
+    #[track_caller]
+    pub fn for_seq_len(&self, len: usize) -> RangeFrom<usize> {
+        SeqRangeFrom { start: self.start.offsets_for_seq_len(len) }.for_seq_len(len)
+    }
+}
+
+impl SeqRangeInclusive<Anchor> {
+    /// Convert this container to a native [`RangeInclusive`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index's conversion panics.
+    #[cfg_attr(feature = "no-panic", deprecated(note = "panics on an index which does not fit"))]
+    #[track_caller]
+    pub fn for_seq_len(&self, len: usize) -> RangeInclusive<usize> {
+        let range = SeqRangeInclusive { start: self.start.offsets_for_seq_len(len), end: self.end.offsets_for_seq_len(len) };
+        range.for_seq_len(len)
+    }
+
+    /// Resolve to an exclusive native [`Range`] for the [`Index`] impls.
+    #[track_caller]
+    fn index_for_seq_len(&self, len: usize) -> Range<usize> {
+        let range = SeqRangeInclusive { start: self.start.offsets_for_seq_len(len), end: self.end.offsets_for_seq_len(len) };
+        range.checked_for_seq_len(len).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+// Inclusive ranges resolve as the `usize` impls do, so an end of `len` panics with
+// a `SeqRangeError` message rather than std's.
+macro_rules! impl_index {
+    ($($idx:ty),*) => { $(
+        impl_index!(
+            @range $idx;
+            SeqRange => for_seq_len,
+            SeqRangeFrom => for_seq_len,
+            SeqRangeInclusive => index_for_seq_len
+        );
+    )* };
+    (@range $idx:ty; $($range:ident => $resolve:ident),*) => { $(
+        impl<T> Index<$range<$idx>> for Vec<T> {
+            type Output = [T];
+
+            #[track_caller]
+            fn index(&self, rng: $range<$idx>) -> &[T] {
+                let range = rng.$resolve(self.len());
+                &self[range]
+            }
+        }
+
+        impl<T> Index<$range<$idx>> for [T] {
+            type Output = [T];
+
+            #[track_caller]
+            fn index(&self, rng: $range<$idx>) -> &[T] {
+                let range = rng.$resolve(self.len());
+                &self[range]
+            }
+        }
+
+        impl Index<$range<$idx>> for str {
+            type Output = str;
+
+            #[track_caller]
+            fn index(&self, rng: $range<$idx>) -> &str {
+                let range = rng.$resolve(self.len());
+                &self[range]
+            }
+        }
+    )* };
+}
+
+impl_index!(Frac, Anchor);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idx;
+
+    #[test]
+    fn test_frac_rounding() {
+        let frac = Frac::new(0.25);
+        assert_eq!(idx!(frac).for_seq_len(10), 3);
+        assert_eq!(idx!(^frac).for_seq_len(10), 7);
+        let frac = frac.with_rounding(Rounding::TowardAnchor);
+        assert_eq!(idx!(frac).for_seq_len(10), 2);
+        assert_eq!(idx!(^frac).for_seq_len(10), 8);
+        let frac = frac.with_rounding(Rounding::AwayFromAnchor);
+        assert_eq!(idx!(frac).for_seq_len(9), 3);
+        assert_eq!(idx!(^frac).for_seq_len(9), 6);
+    }
+
+    #[test]
+    fn test_frac_bounds() {
+        let none = Frac::new(0.0);
+        let all = Frac::new(1.0);
+        let vec: Vec<_> = (0..10).collect();
+        assert_eq!(vec[idx!(none..all)], vec[..]);
+        assert_eq!(vec[idx!(^all..^none)], vec[..]);
+        assert_eq!(vec[idx!(^none..)], [0; 0]);
+        assert_eq!(idx!(all..^none).for_seq_len(0), 0..0);
+    }
+
+    #[test]
+    fn test_frac_inclusive() {
+        let quarter = Frac::new(0.25);
+        let vec: Vec<_> = (0..8).collect();
+        assert_eq!(vec[idx!(quarter..=^quarter)], [2, 3, 4, 5, 6]);
+        assert_eq!(vec.as_slice()[idx!(^quarter..=^quarter)], [6]);
+        let s = "abcdefgh";
+        assert_eq!(&s[idx!(quarter..^quarter)], "cdef");
+        assert_eq!(&s[idx!(^quarter..)], "gh");
+        assert_eq!(&s[idx!(..=quarter)], "abc");
+    }
+
+    #[test]
+    #[should_panic(expected = "index ^0 out of range for sequence of length 5")]
+    fn test_frac_inclusive_end_out_of_range() {
+        let none = Frac::new(0.0);
+        let vec: Vec<_> = (0..5).collect();
+        let _ = &vec[idx!(..=^none)];
+    }
+
+    #[test]
+    #[should_panic(expected = "index ^0 out of range for sequence of length 5")]
+    fn test_frac_inclusive_for_seq_len_out_of_range() {
+        let none = Frac::new(0.0);
+        let _ = idx!(..=^none).for_seq_len(5);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn test_frac_large_len() {
+        // `len as f64` rounds up to 2^54 here
+        let len = (1 << 54) - 1;
+        let all = Frac::new(1.0);
+        assert_eq!(idx!(all).for_seq_len(len), len);
+        assert_eq!(idx!(^all).for_seq_len(len), 0);
+    }
+
+    #[test]
+    fn test_anchors() {
+        for len in 0..20 {
+            assert_eq!(SeqIndex::midpoint().for_seq_len(len), len / 2);
+            assert_eq!(SeqIndex::first_quartile().for_seq_len(len), len / 4);
+            assert_eq!(SeqIndex::third_quartile().for_seq_len(len), len * 3 / 4);
+        }
+    }
+
+    #[test]
+    fn test_anchor() {
+        let vec: Vec<_> = (0..10).collect();
+        let range: SeqRange<Anchor> = SeqRange { start: SeqIndex::midpoint().into(), end: idx!(^1).into() };
+        assert_eq!(vec[range], [5, 6, 7, 8]);
+        let range: SeqRangeInclusive<Anchor> = SeqRangeInclusive { start: idx!(2).into(), end: SeqIndex::third_quartile().into() };
+        assert_eq!(vec[range], [2, 3, 4, 5, 6, 7]);
+        let tail: SeqRangeFrom<Anchor> = SeqRangeFrom { start: idx!(^Frac::new(0.2)).into() };
+        assert_eq!(&"abcdefghij"[tail], "ij");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_anchor_out_of_range() {
+        let vec: Vec<_> = (0..10).collect();
+        let _ = vec[SeqRange::<Anchor> { start: SeqIndex::midpoint().into(), end: idx!(^11).into() }];
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_frac_out_of_range() {
+        Frac::new(1.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_frac_nan() {
+        Frac::new(f64::NAN);
+    }
+}
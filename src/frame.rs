@@ -0,0 +1,120 @@
+//! Splitting messages into a header, body, and footer.
+
+use crate::{SeqIndex, SeqRange, SeqRangeError};
+
+/// A message layout of a fixed-length header, a fixed-length footer anchored to the back,
+/// and a body of whatever lies between.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::FrameSpec;
+/// // a 1 byte tag, a payload, and a 2 byte checksum
+/// const SPEC: FrameSpec = FrameSpec::new(1, 2);
+/// let frame = SPEC.split(&[0x01, b'h', b'i', 0xbe, 0xef]).unwrap();
+/// assert_eq!(frame.header, [0x01]);
+/// assert_eq!(frame.body, b"hi");
+/// assert_eq!(frame.footer, [0xbe, 0xef]);
+/// assert!(SPEC.split(&[0x01, 0xbe]).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct FrameSpec {
+    /// The length of the header.
+    pub header: usize,
+    /// The length of the footer.
+    pub footer: usize,
+}
+
+/// A message split into named parts by a [`FrameSpec`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Frame<'a, T = u8> {
+    /// The first [`FrameSpec::header`] elements.
+    pub header: &'a [T],
+    /// The elements between the header and footer.
+    pub body: &'a [T],
+    /// The last [`FrameSpec::footer`] elements.
+    pub footer: &'a [T],
+}
+
+impl FrameSpec {
+    /// A layout with the given header and footer lengths.
+    pub const fn new(header: usize, footer: usize) -> Self {
+        FrameSpec { header, footer }
+    }
+
+    /// The range of the header (`idx!(..header)`).
+    pub fn header_range(&self) -> SeqRange<usize> {
+        SeqRange { start: SeqIndex::FromFront(0), end: SeqIndex::FromFront(self.header) }
+    }
+
+    /// The range of the body (`idx!(header..^footer)`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, FrameSpec};
+    /// assert_eq!(FrameSpec::new(1, 2).body_range(), idx!(1..^2));
+    /// ```
+    pub fn body_range(&self) -> SeqRange<usize> {
+        SeqRange { start: SeqIndex::FromFront(self.header), end: SeqIndex::FromBack(self.footer) }
+    }
+
+    /// The range of the footer (`idx!(^footer..)`).
+    pub fn footer_range(&self) -> SeqRange<usize> {
+        SeqRange { start: SeqIndex::FromBack(self.footer), end: SeqIndex::FromBack(0) }
+    }
+
+    /// The length of the shortest message, with an empty body.
+    ///
+    /// Returns `None` on overflow.
+    pub fn min_len(&self) -> Option<usize> {
+        self.header.checked_add(self.footer)
+    }
+
+    /// Split a message into its header, body, and footer.
+    ///
+    /// Returns an error if the message is shorter than [`min_len`](FrameSpec::min_len).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{FrameSpec, SeqRangeError};
+    /// let spec = FrameSpec::new(2, 4);
+    /// assert!(spec.split(&[0; 6]).unwrap().body.is_empty());
+    /// assert!(matches!(spec.split(&[0; 5]), Err(SeqRangeError::Inverted { start: 2, end: 1 })));
+    /// ```
+    pub fn split<'a, T>(&self, message: &'a [T]) -> Result<Frame<'a, T>, SeqRangeError> {
+        let body = self.body_range().checked_for_seq_len(message.len())?;
+        let (header, rest) = message.split_at(body.start);
+        let (body, footer) = rest.split_at(body.len());
+        Ok(Frame { header, body, footer })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split() {
+        let spec = FrameSpec::new(2, 1);
+        let frame = spec.split(&[1, 2, 3, 4, 5]).unwrap();
+        assert_eq!(frame, Frame { header: &[1, 2], body: &[3, 4], footer: &[5] });
+        assert!(spec.split(&[1, 2, 3]).unwrap().body.is_empty());
+        assert!(matches!(spec.split(&[1, 2]), Err(SeqRangeError::Inverted { .. })));
+        assert!(matches!(spec.split(&[1]), Err(SeqRangeError::OutOfBounds { .. })));
+        assert_eq!(FrameSpec::default().split(&[1]).unwrap().body, [1]);
+        assert_eq!(FrameSpec::new(usize::MAX, 1).min_len(), None);
+    }
+
+    #[test]
+    fn test_ranges() {
+        let spec = FrameSpec::new(2, 1);
+        let message = [1, 2, 3, 4, 5];
+        let frame = spec.split(&message).unwrap();
+        assert_eq!(&message[spec.header_range().checked_for_seq_len(5).unwrap()], frame.header);
+        assert_eq!(&message[spec.body_range().checked_for_seq_len(5).unwrap()], frame.body);
+        assert_eq!(&message[spec.footer_range().checked_for_seq_len(5).unwrap()], frame.footer);
+        assert_eq!(spec.min_len(), Some(3));
+    }
+}
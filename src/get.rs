@@ -0,0 +1,134 @@
+//! Non-panicking access by the library's structs.
+
+use std::ops::Range;
+
+use crate::{SeqIndex, SeqRange, SeqRangeFrom, SeqRangeInclusive, WithLen};
+
+/// Access by one of the library's indices or ranges which returns `None` rather than panicking,
+/// mirroring [`slice::get`] and [`slice::get_mut`].
+///
+/// Implemented for slices, [`Vec`], [`str`], and [`String`], for the same index types as their
+/// [`Index`](std::ops::Index) impls. As with [`str::get`], slicing a string returns `None`
+/// if either bound does not fall on a char boundary.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, SeqGet};
+/// let mut vec = vec![8, 6, 7, 5, 3, 0, 9];
+/// assert_eq!(vec.seq_get(idx!(^2)), Some(&0));
+/// assert_eq!(vec.seq_get(idx!(^8)), None);
+/// assert_eq!(vec.seq_get(idx!(2..^3)), Some(&[7, 5][..]));
+/// if let Some(last) = vec.seq_get_mut(idx!(^1)) {
+///     *last = 1;
+/// }
+/// assert_eq!(vec[idx!(^1)], 1);
+///
+/// let s = "naïve";
+/// assert_eq!(s.seq_get(idx!(^2..)), Some("ve"));
+/// assert_eq!(s.seq_get(idx!(^3..)), None);
+/// ```
+pub trait SeqGet<I> {
+    /// The element or subsequence the index refers to.
+    type Output: ?Sized;
+
+    /// The element or subsequence at the given index, or `None` if it does not fit.
+    fn seq_get(&self, index: I) -> Option<&Self::Output>;
+
+    /// The element or subsequence at the given index, mutably, or `None` if it does not fit.
+    fn seq_get_mut(&mut self, index: I) -> Option<&mut Self::Output>;
+}
+
+impl<T> SeqGet<SeqIndex<usize>> for [T] {
+    type Output = T;
+
+    fn seq_get(&self, index: SeqIndex<usize>) -> Option<&T> {
+        self.get(usize::try_from(WithLen(index, self.len())).ok()?)
+    }
+
+    fn seq_get_mut(&mut self, index: SeqIndex<usize>) -> Option<&mut T> {
+        let index = usize::try_from(WithLen(index, self.len())).ok()?;
+        self.get_mut(index)
+    }
+}
+
+macro_rules! impl_seq_get_range {
+    ($(<$($gen:ident),*> $ty:ty;)*) => { $(
+        impl_seq_get_range!(@range [$($gen),*] $ty; SeqRange);
+        impl_seq_get_range!(@range [$($gen),*] $ty; SeqRangeFrom);
+        impl_seq_get_range!(@range [$($gen),*] $ty; SeqRangeInclusive);
+    )* };
+    (@range [$($generics:tt)*] $ty:ty; $range:ident) => {
+        impl<$($generics)*> SeqGet<$range<usize>> for $ty {
+            type Output = $ty;
+
+            fn seq_get(&self, range: $range<usize>) -> Option<&$ty> {
+                self.get(Range::try_from(WithLen(range, self.len())).ok()?)
+            }
+
+            fn seq_get_mut(&mut self, range: $range<usize>) -> Option<&mut $ty> {
+                let range = Range::try_from(WithLen(range, self.len())).ok()?;
+                self.get_mut(range)
+            }
+        }
+    };
+}
+
+impl_seq_get_range! {
+    <T> [T];
+    <> str;
+}
+
+impl<T, I> SeqGet<I> for Vec<T>
+where [T]: SeqGet<I> {
+    type Output = <[T] as SeqGet<I>>::Output;
+
+    fn seq_get(&self, index: I) -> Option<&Self::Output> {
+        self.as_slice().seq_get(index)
+    }
+
+    fn seq_get_mut(&mut self, index: I) -> Option<&mut Self::Output> {
+        self.as_mut_slice().seq_get_mut(index)
+    }
+}
+
+impl<I> SeqGet<I> for String
+where str: SeqGet<I> {
+    type Output = <str as SeqGet<I>>::Output;
+
+    fn seq_get(&self, index: I) -> Option<&Self::Output> {
+        self.as_str().seq_get(index)
+    }
+
+    fn seq_get_mut(&mut self, index: I) -> Option<&mut Self::Output> {
+        self.as_mut_str().seq_get_mut(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idx;
+
+    #[test]
+    fn test_seq_get() {
+        let mut vec: Vec<_> = (0..5).collect();
+        assert_eq!(vec.seq_get(idx!(^5)), Some(&0));
+        assert_eq!(vec.seq_get(idx!(^0)), None);
+        assert_eq!(vec.seq_get(idx!(5)), None);
+        assert_eq!(vec.seq_get(idx!(^2..)), Some(&[3, 4][..]));
+        assert_eq!(vec.seq_get(idx!(1..=^1)), Some(&[1, 2, 3, 4][..]));
+        assert_eq!(vec.seq_get(idx!(1..=^0)), None);
+        assert_eq!(vec.seq_get(idx!(^1..1)), None);
+        vec.seq_get_mut(idx!(1..^1)).unwrap().reverse();
+        assert_eq!(vec, [0, 3, 2, 1, 4]);
+        assert!(vec.as_mut_slice().seq_get_mut(idx!(^6..)).is_none());
+
+        let mut s = String::from("héllo");
+        assert_eq!(s.seq_get(idx!(^3..)), Some("llo"));
+        assert_eq!(s.seq_get(idx!(..2)), None);
+        s.seq_get_mut(idx!(..=^4)).unwrap().make_ascii_uppercase();
+        assert_eq!(s, "Héllo");
+        assert_eq!(s.as_str().seq_get(idx!(^7..)), None);
+    }
+}
@@ -0,0 +1,315 @@
+//! Adapters for from-back ranges of streamed input and seekable output.
+
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{self, BufRead, Seek, SeekFrom, Write};
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::{SeqIndex, SeqRange, SeqRangeFrom};
+
+/// Extension methods for [`BufRead`] which accept the library's structs.
+pub trait BufReadSeqExt: BufRead + Sized {
+    /// Iterate over only the lines within a range of line numbers.
+    ///
+    /// From-front bounds are applied as lines stream in. From-back bounds are
+    /// applied with a ring buffer which holds at most as many lines as the bound
+    /// counts from the back, so eg `idx!(^50..)` holds at most 50 lines in memory.
+    ///
+    /// Bounds which fall outside of the input are clamped to it, as `tail` and `sed` do.
+    /// Lines are split as [`BufRead::lines`] splits them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::idx;
+    /// # use from_back::io::BufReadSeqExt;
+    /// let log = "one\ntwo\nthree\nfour\nfive\n".as_bytes();
+    /// // tail -n 2
+    /// let lines: Vec<_> = log.lines_seq(idx!(^2..)).collect::<Result<_, _>>().unwrap();
+    /// assert_eq!(lines, ["four", "five"]);
+    ///
+    /// let log = "one\ntwo\nthree\nfour\nfive\n".as_bytes();
+    /// // everything but the first and last lines
+    /// let lines: Vec<_> = log.lines_seq(idx!(1..^1)).collect::<Result<_, _>>().unwrap();
+    /// assert_eq!(lines, ["two", "three", "four"]);
+    /// ```
+    fn lines_seq<R>(self, range: R) -> SeqLines<Self>
+    where R: Into<SeqRange<usize>> {
+        SeqLines {
+            lines: self.lines(),
+            range: range.into(),
+            line_no: 0,
+            buffer: VecDeque::new(),
+            buffered: false,
+            done: false,
+        }
+    }
+}
+
+impl<B> BufReadSeqExt for B
+where B: BufRead {}
+
+/// An iterator over the lines of a [`BufRead`] within a range of line numbers.
+///
+/// Created by [`BufReadSeqExt::lines_seq`].
+/// The iterator ends after yielding an error, rather than retrying the read.
+#[derive(Debug)]
+pub struct SeqLines<B> {
+    lines: io::Lines<B>,
+    range: SeqRange<usize>,
+    line_no: usize,
+    buffer: VecDeque<String>,
+    buffered: bool,
+    done: bool,
+}
+
+impl<B> SeqLines<B>
+where B: BufRead {
+    /// Read the next line, tracking its line number.
+    fn read_line(&mut self) -> Option<io::Result<(usize, String)>> {
+        let line = self.lines.next()?;
+        let line_no = self.line_no;
+        self.line_no += 1;
+        Some(line.map(|line| (line_no, line)))
+    }
+
+    /// Stream lines for a range with a from-front start.
+    fn next_streamed(&mut self, start: usize) -> Option<io::Result<String>> {
+        loop {
+            let (line_no, line) = match self.read_line()? {
+                Ok(line) => line,
+                Err(err) => return Some(Err(err)),
+            };
+            if line_no < start {
+                continue;
+            }
+            match self.range.end {
+                SeqIndex::FromFront(end) => return (line_no < end).then_some(Ok(line)),
+                SeqIndex::FromBack(end) => {
+                    self.buffer.push_back(line);
+                    if self.buffer.len() > end {
+                        return self.buffer.pop_front().map(Ok);
+                    }
+                }
+            }
+        }
+    }
+
+    /// The next line in range, ignoring whether the iterator is done.
+    fn next_in_range(&mut self) -> Option<io::Result<String>> {
+        match self.range.start {
+            SeqIndex::FromFront(start) => self.next_streamed(start),
+            SeqIndex::FromBack(start) => {
+                if !self.buffered {
+                    if let Err(err) = self.fill_buffer(start) {
+                        return Some(Err(err));
+                    }
+                }
+                self.buffer.pop_front().map(Ok)
+            }
+        }
+    }
+
+    /// Read the whole input for a range with a from-back start, keeping only the lines in range.
+    fn fill_buffer(&mut self, start: usize) -> io::Result<()> {
+        self.buffered = true;
+        while let Some(line) = self.read_line() {
+            let (_, line) = line?;
+            if self.buffer.len() == start {
+                self.buffer.pop_front();
+            }
+            if start > 0 {
+                self.buffer.push_back(line);
+            }
+        }
+        let len = self.line_no;
+        let first_line_no = len - self.buffer.len();
+        let end = match self.range.end {
+            SeqIndex::FromFront(end) => end.saturating_sub(first_line_no),
+            SeqIndex::FromBack(end) => self.buffer.len().saturating_sub(end),
+        };
+        self.buffer.truncate(end);
+        Ok(())
+    }
+}
+
+impl<B> Iterator for SeqLines<B>
+where B: BufRead {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<io::Result<String>> {
+        if self.done {
+            return None;
+        }
+        let next = self.next_in_range();
+        // an error may repeat forever, as with `io::Lines`, so stop after the first
+        self.done = matches!(next, None | Some(Err(_)));
+        next
+    }
+}
+
+impl<B> FusedIterator for SeqLines<B>
+where B: BufRead {}
+
+/// Resolve an offset against the length of seekable output.
+fn resolve_offset(index: SeqIndex<u64>, len: u64) -> io::Result<u64> {
+    let offset = match index {
+        SeqIndex::FromFront(offset) => Some(offset).filter(|&offset| offset <= len),
+        SeqIndex::FromBack(offset) => len.checked_sub(offset),
+    };
+    offset.ok_or_else(|| {
+        let msg = format!("index {index} out of range for length {len}");
+        io::Error::new(io::ErrorKind::InvalidInput, msg)
+    })
+}
+
+/// Overwrite exactly the given byte range of seekable output, resolved against its current length.
+///
+/// Returns the region written, counted from the front.
+/// Nothing is written if the range does not fit the output,
+/// or if `data` is not the same length as the range.
+/// To replace the end of a file with data of a different length, see [`write_range_truncate`].
+///
+/// # Errors
+///
+/// As well as any I/O errors, returns an error of kind [`io::ErrorKind::InvalidInput`]
+/// if the range does not fit the output or `data` is the wrong length.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::idx;
+/// # use from_back::io::write_range;
+/// let mut file = std::io::Cursor::new(b"payload\0\0".to_vec());
+/// // patch the trailing checksum
+/// assert_eq!(write_range(&mut file, idx!(^2..^0), &[0xbe, 0xef]).unwrap(), 7..9);
+/// assert_eq!(file.get_ref(), b"payload\xbe\xef");
+/// assert!(write_range(&mut file, idx!(^2..^0), &[0xbe]).is_err());
+/// ```
+pub fn write_range<W>(writer: &mut W, range: SeqRange<u64>, data: &[u8]) -> io::Result<Range<u64>>
+where W: Write + Seek {
+    let len = writer.seek(SeekFrom::End(0))?;
+    let start = resolve_offset(range.start, len)?;
+    let end = resolve_offset(range.end, len)?;
+    if start > end || end - start != data.len() as u64 {
+        let msg = format!("cannot write {} bytes to range {start}..{end}", data.len());
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, msg));
+    }
+    writer.seek(SeekFrom::Start(start))?;
+    writer.write_all(data)?;
+    Ok(start..end)
+}
+
+/// Replace the end of a file, from the given offset, with `data`,
+/// truncating or extending the file to fit it.
+///
+/// Returns the region written, counted from the front.
+///
+/// # Errors
+///
+/// As well as any I/O errors, returns an error of kind [`io::ErrorKind::InvalidInput`]
+/// if the offset does not fit the file.
+pub fn write_range_truncate(file: &mut File, range: SeqRangeFrom<u64>, data: &[u8]) -> io::Result<Range<u64>> {
+    let len = file.seek(SeekFrom::End(0))?;
+    let start = resolve_offset(range.start, len)?;
+    let end = start + data.len() as u64;
+    file.set_len(end)?;
+    file.seek(SeekFrom::Start(start))?;
+    file.write_all(data)?;
+    Ok(start..end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idx;
+
+    fn lines_seq<R>(range: R) -> Vec<String>
+    where R: Into<SeqRange<usize>> {
+        "0\n1\n2\n3\n4\n5\n6\n7\n8\n9".as_bytes()
+            .lines_seq(range)
+            .collect::<io::Result<_>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_lines_seq() {
+        assert_eq!(lines_seq(idx!(2..5)), ["2", "3", "4"]);
+        assert_eq!(lines_seq(idx!(7..20)), ["7", "8", "9"]);
+        assert_eq!(lines_seq(idx!(7..^1)), ["7", "8"]);
+        assert_eq!(lines_seq(idx!(7..^5)), [""; 0]);
+        assert_eq!(lines_seq(idx!(^3..)), ["7", "8", "9"]);
+        assert_eq!(lines_seq(idx!(^3..^1)), ["7", "8"]);
+        assert_eq!(lines_seq(idx!(^3..8)), ["7"]);
+        assert_eq!(lines_seq(idx!(^3..2)), [""; 0]);
+        assert_eq!(lines_seq(idx!(^0..)), [""; 0]);
+        assert_eq!(lines_seq(idx!(^20..^8)), ["0", "1"]);
+        assert_eq!(lines_seq(idx!(^20..^20)), [""; 0]);
+    }
+
+    #[test]
+    fn test_lines_seq_error() {
+        /// A reader which yields one line, then fails on every read.
+        struct Failing(bool);
+
+        impl io::Read for Failing {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                if std::mem::replace(&mut self.0, true) {
+                    Err(io::Error::other("broken pipe"))
+                } else {
+                    (&b"0\n"[..]).read(buf)
+                }
+            }
+        }
+
+        for range in [idx!(0..^0), idx!(^2..^0)] {
+            let mut lines = io::BufReader::new(Failing(false)).lines_seq(range);
+            if range.start == idx!(0) {
+                assert_eq!(lines.next().unwrap().unwrap(), "0");
+            }
+            assert!(lines.next().unwrap().is_err());
+            assert!(lines.next().is_none());
+            assert!(lines.next().is_none());
+        }
+    }
+
+    #[test]
+    fn test_write_range() {
+        let mut output = io::Cursor::new(b"0123456789".to_vec());
+        assert_eq!(write_range(&mut output, idx!(2..4), b"ab").unwrap(), 2..4);
+        assert_eq!(write_range(&mut output, idx!(^1..^0), b"z").unwrap(), 9..10);
+        assert_eq!(write_range(&mut output, idx!(^0..^0), b"").unwrap(), 10..10);
+        assert_eq!(output.get_ref(), b"01ab45678z");
+        let err = write_range(&mut output, idx!(^2..^0), b"xyz").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        let err = write_range(&mut output, idx!(^11..^10), b"x").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert!(write_range(&mut output, idx!(5..4), b"").is_err());
+        assert!(write_range(&mut output, idx!(9..11), b"xy").is_err());
+        assert_eq!(output.get_ref(), b"01ab45678z");
+    }
+
+    /// A temporary file path, removed when dropped, including when a test panics.
+    struct TempPath(std::path::PathBuf);
+
+    impl Drop for TempPath {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    #[test]
+    fn test_write_range_truncate() {
+        let temp = TempPath(std::env::temp_dir().join(format!("from-back-{}", std::process::id())));
+        let path = &temp.0;
+        let mut file = File::options().read(true).write(true).create(true).truncate(true).open(path).unwrap();
+        file.write_all(b"payload:old").unwrap();
+        assert_eq!(write_range_truncate(&mut file, idx!(^3..), b"new!").unwrap(), 8..12);
+        assert_eq!(std::fs::read(path).unwrap(), b"payload:new!");
+        assert_eq!(write_range_truncate(&mut file, idx!(7..), b"").unwrap(), 7..7);
+        assert_eq!(std::fs::read(path).unwrap(), b"payload");
+        let err = write_range_truncate(&mut file, idx!(^8..), b"").unwrap_err();
+        assert_eq!(err.to_string(), "index ^8 out of range for length 7");
+    }
+}
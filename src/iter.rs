@@ -0,0 +1,313 @@
+//! Extension methods for iterators.
+
+use std::collections::VecDeque;
+use std::iter::{Skip, Take};
+
+use crate::{SeqIndex, SeqRange};
+
+/// Extension methods for iterators which accept the library's structs.
+pub trait IteratorSeqExt: Iterator + Sized {
+    /// Consume the iterator up to and including the element at the given index, and return it.
+    ///
+    /// From-back indices are resolved against the iterator's remaining length.
+    /// Returns `None` if the index does not refer to an element.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, IteratorSeqExt};
+    /// let words = "the quick brown fox".split(' ').collect::<Vec<_>>();
+    /// assert_eq!(words.iter().nth_seq(idx!(^2)), Some(&"brown"));
+    /// assert_eq!(words.iter().nth_seq(idx!(^5)), None);
+    /// ```
+    fn nth_seq(mut self, index: SeqIndex<usize>) -> Option<Self::Item>
+    where Self: ExactSizeIterator {
+        let len = self.len();
+        let index = match index {
+            SeqIndex::FromFront(idx) => idx,
+            SeqIndex::FromBack(idx) => len.checked_sub(idx)?,
+        };
+        self.nth(index)
+    }
+
+    /// Iterate over only the elements within the range.
+    ///
+    /// The range is resolved against the iterator's remaining length.
+    /// Returns `None` if the range does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, IteratorSeqExt};
+    /// let middle: Vec<_> = (0..10).range_seq(idx!(2..^5)).unwrap().collect();
+    /// assert_eq!(middle, [2, 3, 4]);
+    /// assert!((0..10).range_seq(idx!(^5..2)).is_none());
+    /// ```
+    fn range_seq(self, range: SeqRange<usize>) -> Option<Take<Skip<Self>>>
+    where Self: ExactSizeIterator {
+        let range = range.checked_for_seq_len(self.len()).ok()?;
+        Some(self.skip(range.start).take(range.len()))
+    }
+
+    /// Yield the elements before the given index, like [`Iterator::take`].
+    ///
+    /// `take_seq(idx!(^3))` yields everything except the last 3 elements.
+    /// If the iterator's [size hint](Iterator::size_hint) is exact, this just counts;
+    /// otherwise it holds back the latest 3 elements in a buffer until it knows they
+    /// are not among the last 3.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, IteratorSeqExt};
+    /// let words: Vec<_> = "a b c d e".split(' ').take_seq(idx!(^2)).collect();
+    /// assert_eq!(words, ["a", "b", "c"]);
+    /// ```
+    fn take_seq(self, index: SeqIndex<usize>) -> TakeSeq<Self> {
+        let mode = match index {
+            SeqIndex::FromFront(n) => TakeMode::Remaining(n),
+            SeqIndex::FromBack(k) => match self.size_hint() {
+                (lower, Some(upper)) if lower == upper => TakeMode::Remaining(lower.saturating_sub(k)),
+                _ => TakeMode::Lagging(k),
+            },
+        };
+        TakeSeq { iter: self, mode, buffer: VecDeque::new() }
+    }
+
+    /// Skip the elements before the given index, like [`Iterator::skip`].
+    ///
+    /// `skip_seq(idx!(^3))` yields only the last 3 elements.
+    /// If the iterator's [size hint](Iterator::size_hint) is exact, this just counts;
+    /// otherwise it consumes the whole iterator on the first call to `next`,
+    /// keeping only the latest 3 elements in a buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, IteratorSeqExt};
+    /// let words: Vec<_> = "a b c d e".split(' ').skip_seq(idx!(^2)).collect();
+    /// assert_eq!(words, ["d", "e"]);
+    /// ```
+    fn skip_seq(self, index: SeqIndex<usize>) -> SkipSeq<Self> {
+        let mode = match index {
+            SeqIndex::FromFront(n) => SkipMode::Skip(n),
+            SeqIndex::FromBack(k) => match self.size_hint() {
+                (lower, Some(upper)) if lower == upper => SkipMode::Skip(lower.saturating_sub(k)),
+                _ => SkipMode::Tail(k),
+            },
+        };
+        SkipSeq { iter: self, mode, buffer: VecDeque::new() }
+    }
+
+    /// Split the iterator at the given index into the elements before it and the elements after it.
+    ///
+    /// Neither part allocates: the leading part iterates over a clone of the iterator,
+    /// so this is intended for cheaply cloneable iterators, like those over slices.
+    /// Both parts are double-ended if the iterator is.
+    /// An index past either end of the iterator is clamped to it, as with [`take_seq`](IteratorSeqExt::take_seq).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, IteratorSeqExt};
+    /// let log = ["start", "a", "b", "c", "end"];
+    /// let (body, trailer) = log.iter().partition_at_seq(idx!(^2));
+    /// assert_eq!(body.rev().collect::<Vec<_>>(), [&"b", &"a", &"start"]);
+    /// assert_eq!(trailer.collect::<Vec<_>>(), [&"c", &"end"]);
+    /// ```
+    fn partition_at_seq(self, index: SeqIndex<usize>) -> (Take<Self>, Skip<Self>)
+    where Self: ExactSizeIterator + Clone {
+        let len = self.len();
+        let split = match index {
+            SeqIndex::FromFront(idx) => idx.min(len),
+            SeqIndex::FromBack(idx) => len.saturating_sub(idx),
+        };
+        (self.clone().take(split), self.skip(split))
+    }
+}
+
+impl<I> IteratorSeqExt for I
+where I: Iterator {}
+
+#[derive(Debug, Clone, Copy)]
+enum TakeMode {
+    /// Yield up to this many more elements.
+    Remaining(usize),
+    /// Hold back this many elements, which may be the last ones.
+    Lagging(usize),
+}
+
+/// An iterator which yields the elements before a [`SeqIndex`].
+///
+/// Created by [`IteratorSeqExt::take_seq`].
+#[derive(Debug, Clone)]
+pub struct TakeSeq<I>
+where I: Iterator {
+    iter: I,
+    mode: TakeMode,
+    buffer: VecDeque<I::Item>,
+}
+
+impl<I> Iterator for TakeSeq<I>
+where I: Iterator {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        match &mut self.mode {
+            TakeMode::Remaining(0) => None,
+            TakeMode::Remaining(n) => {
+                *n -= 1;
+                self.iter.next()
+            }
+            &mut TakeMode::Lagging(k) => loop {
+                self.buffer.push_back(self.iter.next()?);
+                if self.buffer.len() > k {
+                    return self.buffer.pop_front();
+                }
+            },
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        match self.mode {
+            TakeMode::Remaining(n) => (lower.min(n), Some(upper.map_or(n, |upper| upper.min(n)))),
+            TakeMode::Lagging(k) => {
+                let buffered = self.buffer.len();
+                let lower = lower.saturating_add(buffered).saturating_sub(k);
+                let upper = upper.and_then(|upper| upper.checked_add(buffered));
+                (lower, upper.map(|upper| upper.saturating_sub(k)))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum SkipMode {
+    /// Skip this many elements before yielding the rest.
+    Skip(usize),
+    /// Keep only the last this many elements.
+    Tail(usize),
+    /// Yield the buffered elements.
+    Drain,
+}
+
+/// An iterator which skips the elements before a [`SeqIndex`].
+///
+/// Created by [`IteratorSeqExt::skip_seq`].
+#[derive(Debug, Clone)]
+pub struct SkipSeq<I>
+where I: Iterator {
+    iter: I,
+    mode: SkipMode,
+    buffer: VecDeque<I::Item>,
+}
+
+impl<I> Iterator for SkipSeq<I>
+where I: Iterator {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        match self.mode {
+            SkipMode::Skip(0) => self.iter.next(),
+            SkipMode::Skip(n) => {
+                self.mode = SkipMode::Skip(0);
+                self.iter.nth(n)
+            }
+            SkipMode::Tail(k) => {
+                for item in self.iter.by_ref() {
+                    if self.buffer.len() == k {
+                        self.buffer.pop_front();
+                    }
+                    if k > 0 {
+                        self.buffer.push_back(item);
+                    }
+                }
+                self.mode = SkipMode::Drain;
+                self.buffer.pop_front()
+            }
+            SkipMode::Drain => self.buffer.pop_front(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        match self.mode {
+            SkipMode::Skip(n) => (lower.saturating_sub(n), upper.map(|upper| upper.saturating_sub(n))),
+            SkipMode::Tail(k) => (lower.min(k), Some(upper.map_or(k, |upper| upper.min(k)))),
+            SkipMode::Drain => (self.buffer.len(), Some(self.buffer.len())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idx;
+
+    #[test]
+    fn test_nth_seq() {
+        let mut iter = 0..5;
+        iter.next();
+        assert_eq!(iter.clone().nth_seq(idx!(^4)), Some(1));
+        assert_eq!(iter.clone().nth_seq(idx!(^0)), None);
+        assert_eq!(iter.clone().nth_seq(idx!(3)), Some(4));
+        assert_eq!(iter.nth_seq(idx!(4)), None);
+    }
+
+    /// An iterator which hides its length.
+    fn unsized_iter(n: i32) -> impl Iterator<Item = i32> {
+        (0..n).filter(|_| true)
+    }
+
+    #[test]
+    fn test_take_seq() {
+        for n in [0i32, 1, 3, 5] {
+            let expected: Vec<_> = (0..n - 3).collect();
+            assert_eq!((0..n).take_seq(idx!(^3)).collect::<Vec<_>>(), expected);
+            assert_eq!(unsized_iter(n).take_seq(idx!(^3)).collect::<Vec<_>>(), expected);
+        }
+        assert_eq!(unsized_iter(3).take_seq(idx!(^0)).count(), 3);
+        assert_eq!(unsized_iter(3).take_seq(idx!(2)).count(), 2);
+        let mut iter = unsized_iter(5).take_seq(idx!(^2));
+        iter.next();
+        assert_eq!(iter.size_hint(), (0, Some(2)));
+        assert_eq!((0..5).take_seq(idx!(^2)).size_hint(), (3, Some(3)));
+    }
+
+    #[test]
+    fn test_skip_seq() {
+        for n in [0i32, 1, 3, 5] {
+            let expected: Vec<_> = ((n - 3).max(0)..n).collect();
+            assert_eq!((0..n).skip_seq(idx!(^3)).collect::<Vec<_>>(), expected);
+            assert_eq!(unsized_iter(n).skip_seq(idx!(^3)).collect::<Vec<_>>(), expected);
+        }
+        assert_eq!(unsized_iter(3).skip_seq(idx!(^0)).count(), 0);
+        assert_eq!(unsized_iter(3).skip_seq(idx!(2)).collect::<Vec<_>>(), [2]);
+        let mut iter = unsized_iter(5).skip_seq(idx!(^2));
+        iter.next();
+        assert_eq!(iter.size_hint(), (1, Some(1)));
+    }
+
+    #[test]
+    fn test_partition_at_seq() {
+        let partition = |index| {
+            let (front, back) = (0..5).partition_at_seq(index);
+            (front.collect::<Vec<_>>(), back.rev().collect::<Vec<_>>())
+        };
+        assert_eq!(partition(idx!(^2)), (vec![0, 1, 2], vec![4, 3]));
+        assert_eq!(partition(idx!(^0)), (vec![0, 1, 2, 3, 4], vec![]));
+        assert_eq!(partition(idx!(^9)), (vec![], vec![4, 3, 2, 1, 0]));
+        assert_eq!(partition(idx!(9)), (vec![0, 1, 2, 3, 4], vec![]));
+        assert_eq!(partition(idx!(1)), (vec![0], vec![4, 3, 2, 1]));
+    }
+
+    #[test]
+    fn test_range_seq() {
+        let range_seq = |range| Some((0..5).range_seq(range)?.collect::<Vec<_>>());
+        assert_eq!(range_seq(idx!(^2..^0)), Some(vec![3, 4]));
+        assert_eq!(range_seq(idx!(^5..1)), Some(vec![0]));
+        assert_eq!(range_seq(idx!(5..^0)), Some(vec![]));
+        assert_eq!(range_seq(idx!(^6..^0)), None);
+    }
+}
@@ -40,8 +40,148 @@
 //! assert_eq!(range, 2..5);
 //! assert_eq!(vec.get(range), Some(expected));
 //! ```
+//!
+//! # Panic-free builds
+//!
+//! The `no-panic` feature marks every method and function which can panic on a bad index,
+//! such as `for_seq_len`, as deprecated, so that calls to them raise a warning which
+//! `#![deny(deprecated)]` can make an error. No APIs are removed, so enabling the feature
+//! cannot break another crate in the same build. The fallible alternatives resolve indices
+//! via [`WithLen`], `try_for_seq_len`, [`SeqGet`] or [`SeqRange::anchor`], or clamp them,
+//! eg via [`Span`].
+//!
+//! Trait impls cannot be deprecated, so square bracket access through the [`Index`] impls
+//! is not flagged; use [`SeqGet`] in its place.
+//!
+//! # Tracing
+//!
+//! The `tracing` feature emits a trace-level [`tracing`] event each time an index or range
+//! is resolved against a length, including by the [`Index`] impls, recording the unresolved
+//! value, the length, the resolved result or error, and the location of the calling code.
+//! Each access emits a single event, even where a range resolves each of its bounds, so an
+//! out-of-bounds access can be traced back to the expression which produced it.
+//!
+//! # Toolchain requirements
+//!
+//! Every feature builds on stable Rust except these, which `--all-features` therefore
+//! includes; on an older or stable toolchain, list the other features explicitly instead.
+//!
+//! - `allocator_api` requires a nightly toolchain, for the unstable `allocator_api` feature.
+//! - `new-range` requires Rust 1.96 or newer, for the `core::range` types. The build script
+//!   rejects older toolchains with an error naming the version found.
 
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+#![cfg_attr(feature = "no-panic", allow(deprecated))]
+
+use std::iter::Chain;
 use std::ops::*;
+use std::slice;
+
+/// Emit a trace-level event for the resolution of `seq` against a sequence of length `len`.
+///
+/// Records the location of the caller, so every function between it and the user's code
+/// must be `#[track_caller]`. The resolutions a range delegates to for its bounds are not
+/// traced, so each access emits a single event.
+#[cfg(feature = "tracing")]
+macro_rules! trace_resolution {
+    ($seq:expr, $len:expr, $result:expr) => {
+        tracing::trace!(
+            seq = ?$seq,
+            len = $len,
+            result = ?$result,
+            location = %std::panic::Location::caller(),
+            "resolved",
+        )
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! trace_resolution {
+    ($seq:expr, $len:expr, $result:expr) => {};
+}
+
+mod anchored;
+#[cfg(feature = "ansi")]
+pub mod ansi;
+#[cfg(feature = "arrow")]
+pub mod arrow;
+mod back;
+mod bits;
+mod btree;
+mod bytes;
+#[cfg(feature = "clap")]
+pub mod clap;
+#[cfg(feature = "defmt")]
+mod defmt;
+pub mod compact;
+mod contiguous;
+mod deque;
+#[cfg(feature = "encoding")]
+pub mod encoding;
+mod expr;
+mod flat;
+mod frac;
+mod frame;
+mod get;
+pub mod io;
+mod iter;
+#[cfg(feature = "lsp")]
+pub mod lsp;
+mod map;
+#[cfg(feature = "nom")]
+pub mod nom;
+mod packed;
+mod parse;
+mod pinned;
+#[cfg(feature = "pyo3")]
+mod pyo3;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "new-range")]
+mod range;
+#[cfg(feature = "schemars")]
+mod schemars;
+#[cfg(feature = "serde")]
+mod serde;
+mod span;
+#[cfg(feature = "futures")]
+pub mod stream;
+mod strided;
+mod tagged;
+pub mod tail;
+mod text;
+mod vec;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+mod window;
+
+pub use anchored::{AnchoredIndex, AnchoredRange, Generational, StaleIndex};
+pub use back::{At, BackOffset};
+pub use bits::{BitSeqExt, Msb0};
+pub use btree::{BTreeMapSeqExt, BTreeSetSeqExt};
+pub use bytes::ByteSeqExt;
+pub use contiguous::SeqSlice;
+pub use deque::DequeSeqExt;
+pub use expr::{LenExpr, SeqRangeExpr, LEN};
+pub use flat::Flat2D;
+pub use frac::{Anchor, Frac, Rounding};
+pub use frame::{Frame, FrameSpec};
+pub use get::SeqGet;
+pub use iter::{IteratorSeqExt, SkipSeq, TakeSeq};
+pub use map::SeqRangeMap;
+pub use packed::PackedSeqIndex;
+pub use parse::ParseSeqError;
+pub use pinned::PinnedTail;
+pub use span::Span;
+pub use strided::Strided;
+pub use tagged::{SeqLenOf, Tagged};
+pub use text::{Bytes, Chars, Lines, StrIndex, StrRange, TextUnit, Utf16};
+#[cfg(feature = "width")]
+pub use text::Columns;
+#[cfg(feature = "unicode")]
+pub use text::Graphemes;
+pub use vec::{SpliceRecord, VecSeqExt};
+pub use window::WindowMut;
 
 // ===
 
@@ -55,6 +195,57 @@ pub enum SeqIndex<Idx> {
 }
 
 impl SeqIndex<usize> {
+    /// The first element of a sequence (`idx!(0)`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::SeqIndex;
+    /// let vec = vec![8, 6, 7, 5, 3, 0, 9];
+    /// assert_eq!(vec[SeqIndex::FIRST], 8);
+    /// ```
+    pub const FIRST: Self = SeqIndex::FromFront(0);
+
+    /// The last element of a sequence (`idx!(^1)`).
+    ///
+    /// Note that `idx!(^0)` refers to the position just past the last element,
+    /// which is only meaningful as the end of a range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::SeqIndex;
+    /// let vec = vec![8, 6, 7, 5, 3, 0, 9];
+    /// assert_eq!(vec[SeqIndex::LAST], 9);
+    /// ```
+    pub const LAST: Self = SeqIndex::FromBack(1);
+
+    /// Create a from-front index from a signed offset, clamping negative offsets to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, SeqIndex};
+    /// assert_eq!(SeqIndex::from_front_saturating(3), idx!(3));
+    /// assert_eq!(SeqIndex::from_front_saturating(-3), idx!(0));
+    /// ```
+    pub fn from_front_saturating(idx: isize) -> Self {
+        SeqIndex::FromFront(idx.try_into().unwrap_or(0))
+    }
+
+    /// Create a from-back index from a signed offset, clamping negative offsets to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, SeqIndex};
+    /// assert_eq!(SeqIndex::from_back_saturating(3), idx!(^3));
+    /// assert_eq!(SeqIndex::from_back_saturating(-3), idx!(^0));
+    /// ```
+    pub fn from_back_saturating(idx: isize) -> Self {
+        SeqIndex::FromBack(idx.try_into().unwrap_or(0))
+    }
+
     /// Convert this container to a native from-front [`usize`] for a sequence of the given `len`.
     ///
     /// If the value is `FromFront(index)`, returns `index`.
@@ -73,12 +264,240 @@ impl SeqIndex<usize> {
     /// assert_eq!(index, 5);
     /// assert_eq!(vec.get(index), Some(&0));
     /// ```
+    #[cfg_attr(feature = "no-panic", deprecated(note = "panics on an index which does not fit"))]
+    #[track_caller]
     pub fn for_seq_len(&self, len: usize) -> usize {
-        match self {
-            &SeqIndex::FromFront(idx) => idx,
-            &SeqIndex::FromBack(idx) => len.checked_sub(idx).unwrap(),
+        let index = self.untraced_for_seq_len(len);
+        trace_resolution!(self, len, index);
+        index.unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Convert this container to a native from-front [`usize`], or error if it falls outside
+    /// a sequence of the given `len`.
+    ///
+    /// Unlike [`for_seq_len`](SeqIndex::for_seq_len), a from-front value beyond `len` is an error too.
+    /// As with `for_seq_len`, `len` itself is in bounds, as the position one past the last element.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SeqRangeError::OutOfBounds`], holding the index and the length,
+    /// if the index resolves before the front or beyond the back of the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, SeqRangeError};
+    /// assert_eq!(idx!(^2).try_for_seq_len(7), Ok(5));
+    /// assert_eq!(idx!(^8).try_for_seq_len(7), Err(SeqRangeError::OutOfBounds { index: idx!(^8), len: 7 }));
+    /// ```
+    pub fn try_for_seq_len(&self, len: usize) -> Result<usize, SeqRangeError> {
+        self.checked_for_seq_len(len)
+    }
+
+    /// Convert to a signed index, where negative values count from the back.
+    ///
+    /// This is the inverse of the `From<isize>` conversion.
+    /// Since `-0` cannot be distinguished from `0`, `idx!(^0)` is converted to `len`.
+    /// Returns `None` if the value does not fit in an [`isize`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::idx;
+    /// assert_eq!(idx!(2).to_isize(10), Some(2));
+    /// assert_eq!(idx!(^2).to_isize(10), Some(-2));
+    /// assert_eq!(idx!(^0).to_isize(10), Some(10));
+    /// ```
+    pub fn to_isize(&self, len: usize) -> Option<isize> {
+        match *self {
+            SeqIndex::FromFront(idx) => idx.try_into().ok(),
+            SeqIndex::FromBack(0) => len.try_into().ok(),
+            SeqIndex::FromBack(idx) => 0isize.checked_sub_unsigned(idx),
+        }
+    }
+
+    /// Checked addition, moving the index `rhs` elements away from its anchor.
+    ///
+    /// Returns `None` on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::idx;
+    /// assert_eq!(idx!(^2).checked_add(3), Some(idx!(^5)));
+    /// assert_eq!(idx!(^2).checked_add(usize::MAX), None);
+    /// ```
+    pub fn checked_add(self, rhs: usize) -> Option<Self> {
+        self.try_map(|idx| idx.checked_add(rhs))
+    }
+
+    /// Checked subtraction, moving the index `rhs` elements toward its anchor.
+    ///
+    /// Returns `None` if the index would pass its anchor.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::idx;
+    /// assert_eq!(idx!(^5).checked_sub(3), Some(idx!(^2)));
+    /// assert_eq!(idx!(2).checked_sub(3), None);
+    /// ```
+    pub fn checked_sub(self, rhs: usize) -> Option<Self> {
+        self.try_map(|idx| idx.checked_sub(rhs))
+    }
+
+    /// Saturating addition, moving the index `rhs` elements away from its anchor.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::idx;
+    /// assert_eq!(idx!(^2).saturating_add(usize::MAX), idx!(^usize::MAX));
+    /// ```
+    pub fn saturating_add(self, rhs: usize) -> Self {
+        self.map(|idx| idx.saturating_add(rhs))
+    }
+
+    /// Saturating subtraction, moving the index `rhs` elements toward its anchor.
+    ///
+    /// Stops at the anchor itself: `idx!(0)` for a from-front index,
+    /// or `idx!(^0)` for a from-back index.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::idx;
+    /// assert_eq!(idx!(^2).saturating_sub(3), idx!(^0));
+    /// ```
+    pub fn saturating_sub(self, rhs: usize) -> Self {
+        self.map(|idx| idx.saturating_sub(rhs))
+    }
+
+    /// The number of positions between this index and `other`,
+    /// each resolved against a sequence of the given `len`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either index falls outside the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::idx;
+    /// assert_eq!(idx!(^2).distance_to(idx!(3), 10), Ok(5));
+    /// assert_eq!(idx!(3).distance_to(idx!(^2), 10), Ok(5));
+    /// assert!(idx!(3).distance_to(idx!(^11), 10).is_err());
+    /// ```
+    pub fn distance_to(&self, other: SeqIndex<usize>, len: usize) -> Result<usize, SeqRangeError> {
+        Ok(self.checked_for_seq_len(len)?.abs_diff(other.checked_for_seq_len(len)?))
+    }
+
+    /// The signed number of positions from this index to `other`,
+    /// each resolved against a sequence of the given `len`.
+    ///
+    /// Positive if `other` resolves nearer the back of the sequence.
+    /// The result saturates at the bounds of [`isize`], which no slice is long enough to reach.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either index falls outside the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::idx;
+    /// assert_eq!(idx!(3).signed_distance_to(idx!(^2), 10), Ok(5));
+    /// assert_eq!(idx!(^2).signed_distance_to(idx!(3), 10), Ok(-5));
+    /// ```
+    pub fn signed_distance_to(&self, other: SeqIndex<usize>, len: usize) -> Result<isize, SeqRangeError> {
+        let (from, to) = (self.checked_for_seq_len(len)?, other.checked_for_seq_len(len)?);
+        let distance = isize::try_from(from.abs_diff(to)).unwrap_or(isize::MAX);
+        Ok(if to < from { -distance } else { distance })
+    }
+
+    /// Resolve against a sequence of the given `len`, converting the position to another integer type,
+    /// such as the [`u32`] offsets of GPU index buffers and many wire formats.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index falls outside the sequence,
+    /// or if the resolved position does not fit in `U`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, SeqRangeError};
+    /// assert_eq!(idx!(^2).resolve_to::<u32>(10), Ok(8));
+    /// assert_eq!(idx!(^2).resolve_to::<u8>(300), Err(SeqRangeError::Overflow { index: 298 }));
+    /// ```
+    pub fn resolve_to<U>(&self, len: usize) -> Result<U, SeqRangeError>
+    where U: TryFrom<usize> {
+        let index = self.checked_for_seq_len(len)?;
+        U::try_from(index).map_err(|_| SeqRangeError::Overflow { index })
+    }
+
+    /// Whether this index refers to an element of a sequence of the given `len`.
+    ///
+    /// Usable in const contexts; see [`static_assert_fits!`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::idx;
+    /// assert!(idx!(^3).fits_seq_len(3));
+    /// assert!(!idx!(^0).fits_seq_len(3));
+    /// assert!(!idx!(3).fits_seq_len(3));
+    /// ```
+    pub const fn fits_seq_len(&self, len: usize) -> bool {
+        match self.const_for_seq_len(len) {
+            Some(index) => index < len,
+            None => false,
+        }
+    }
+
+    /// Resolve to a position in `0..=len`, if it falls within the sequence, in a const context.
+    const fn const_for_seq_len(&self, len: usize) -> Option<usize> {
+        match *self {
+            SeqIndex::FromFront(idx) if idx <= len => Some(idx),
+            SeqIndex::FromBack(idx) if idx <= len => Some(len - idx),
+            _ => None,
+        }
+    }
+
+    /// Resolve to a position in `0..=len`, or error if it falls outside the sequence.
+    #[track_caller]
+    fn checked_for_seq_len(&self, len: usize) -> Result<usize, SeqRangeError> {
+        let index = self.untraced_checked_for_seq_len(len);
+        trace_resolution!(self, len, index);
+        index
+    }
+
+    /// Resolve to the position of an element, for the [`Index`] impls.
+    ///
+    /// Unlike [`SeqIndex::for_seq_len`], this panics with a [`SeqRangeError`] message
+    /// for a position past the last element, such as `^0`, rather than leaving it to std.
+    #[track_caller]
+    fn element_for_seq_len(&self, len: usize) -> usize {
+        let index = self
+            .const_for_seq_len(len)
+            .filter(|&index| index < len)
+            .ok_or(SeqRangeError::OutOfBounds { index: *self, len });
+        trace_resolution!(self, len, index);
+        index.unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// As [`SeqIndex::for_seq_len`], but erroring rather than panicking, and without tracing.
+    fn untraced_for_seq_len(&self, len: usize) -> Result<usize, SeqRangeError> {
+        match *self {
+            SeqIndex::FromFront(idx) => Ok(idx),
+            SeqIndex::FromBack(idx) => len.checked_sub(idx).ok_or(SeqRangeError::OutOfBounds { index: *self, len }),
         }
     }
+
+    /// As [`SeqIndex::checked_for_seq_len`], but without tracing.
+    fn untraced_checked_for_seq_len(&self, len: usize) -> Result<usize, SeqRangeError> {
+        self.const_for_seq_len(len).ok_or(SeqRangeError::OutOfBounds { index: *self, len })
+    }
 }
 
 impl<Idx> Default for SeqIndex<Idx>
@@ -88,6 +507,105 @@ where Idx: Default {
     }
 }
 
+/// Convert a signed index, where negative values count from the back.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, SeqIndex};
+/// assert_eq!(SeqIndex::from(2), idx!(2));
+/// assert_eq!(SeqIndex::from(-1), idx!(^1));
+/// ```
+impl From<isize> for SeqIndex<usize> {
+    fn from(idx: isize) -> Self {
+        if idx < 0 {
+            SeqIndex::FromBack(idx.unsigned_abs())
+        } else {
+            SeqIndex::FromFront(idx.unsigned_abs())
+        }
+    }
+}
+
+impl<Idx> SeqIndex<Idx> {
+    /// Apply `f` to the inner value, preserving the anchor.
+    fn map<F>(self, f: F) -> Self
+    where F: FnOnce(Idx) -> Idx {
+        match self {
+            SeqIndex::FromFront(idx) => SeqIndex::FromFront(f(idx)),
+            SeqIndex::FromBack(idx) => SeqIndex::FromBack(f(idx)),
+        }
+    }
+
+    /// Apply `f` to the inner value, preserving the anchor, if `f` succeeds.
+    fn try_map<F>(self, f: F) -> Option<Self>
+    where F: FnOnce(Idx) -> Option<Idx> {
+        match self {
+            SeqIndex::FromFront(idx) => f(idx).map(SeqIndex::FromFront),
+            SeqIndex::FromBack(idx) => f(idx).map(SeqIndex::FromBack),
+        }
+    }
+}
+
+/// Move the index `rhs` elements away from its anchor.
+///
+/// A from-front index moves toward the back of the sequence, and
+/// a from-back index moves toward the front of the sequence.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::idx;
+/// assert_eq!(idx!(2) + 3, idx!(5));
+/// assert_eq!(idx!(^2) + 3, idx!(^5));
+/// ```
+impl<Idx> Add<Idx> for SeqIndex<Idx>
+where Idx: Add<Output = Idx> {
+    type Output = Self;
+
+    fn add(self, rhs: Idx) -> Self {
+        self.map(|idx| idx + rhs)
+    }
+}
+
+/// Move the index `rhs` elements toward its anchor.
+///
+/// A from-front index moves toward the front of the sequence, and
+/// a from-back index moves toward the back of the sequence.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::idx;
+/// assert_eq!(idx!(5) - 3, idx!(2));
+/// assert_eq!(idx!(^5) - 3, idx!(^2));
+/// ```
+impl<Idx> Sub<Idx> for SeqIndex<Idx>
+where Idx: Sub<Output = Idx> {
+    type Output = Self;
+
+    fn sub(self, rhs: Idx) -> Self {
+        self.map(|idx| idx - rhs)
+    }
+}
+
+impl<Idx> AddAssign<Idx> for SeqIndex<Idx>
+where Idx: AddAssign {
+    fn add_assign(&mut self, rhs: Idx) {
+        match self {
+            SeqIndex::FromFront(idx) | SeqIndex::FromBack(idx) => *idx += rhs,
+        }
+    }
+}
+
+impl<Idx> SubAssign<Idx> for SeqIndex<Idx>
+where Idx: SubAssign {
+    fn sub_assign(&mut self, rhs: Idx) {
+        match self {
+            SeqIndex::FromFront(idx) | SeqIndex::FromBack(idx) => *idx -= rhs,
+        }
+    }
+}
+
 // ===
 
 /// A parallel to [`std::ops::Range`] (`idx!(start..end)`)
@@ -133,57 +651,326 @@ impl SeqRange<usize> {
     /// assert_eq!(range, 2..5);
     /// assert_eq!(vec.get(range), Some(expected));
     /// ```
+    #[cfg_attr(feature = "no-panic", deprecated(note = "panics on an index which does not fit"))]
+    #[track_caller]
     pub fn for_seq_len(&self, len: usize) -> Range<usize> {
-        self.start.for_seq_len(len)..self.end.for_seq_len(len)
+        let range = self.start.untraced_for_seq_len(len).and_then(|start| {
+            Ok(start..self.end.untraced_for_seq_len(len)?)
+        });
+        trace_resolution!(self, len, range);
+        range.unwrap_or_else(|err| panic!("{err}"))
     }
-}
 
-// ===
-
-/// A parallel to [`std::ops::RangeFrom`] (`idx!(start..)`)
-///
-/// # Examples
-/// ```rust
-/// # use from_back::idx;
-/// let vec = vec![8, 6, 7, 5, 3, 0, 9];
-///
-/// // slice the elements starting two from the back
-/// assert_eq!(&vec[idx!(^2..)], &[0, 9]);
-/// ```
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
-pub struct SeqRangeFrom<Idx> {
-    /// The lower bound of the range (inclusive).
-    pub start: SeqIndex<Idx>,
-}
+    /// Convert this container to a native [`Range`], or error if it does not fit
+    /// a sequence of the given `len`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either index falls outside the sequence,
+    /// or if the start resolves after the end.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, SeqRangeError};
+    /// assert_eq!(idx!(2..^2).try_for_seq_len(7), Ok(2..5));
+    /// assert_eq!(idx!(^2..2).try_for_seq_len(7), Err(SeqRangeError::Inverted { start: 5, end: 2 }));
+    /// ```
+    pub fn try_for_seq_len(&self, len: usize) -> Result<Range<usize>, SeqRangeError> {
+        self.checked_for_seq_len(len)
+    }
 
-impl SeqRangeFrom<usize> {
-    /// Convert this container to a native [`RangeFrom`].
+    /// Generate `count` evenly spaced indices within this range, for a sequence of the given `len`.
     ///
-    /// Delegates to the `start` index's [`SeqIndex::for_seq_len`].
+    /// The first and last indices of the range are always included (when `count >= 2`),
+    /// and intermediate indices are rounded to the nearest integer.
+    /// If `count` exceeds the length of the range some indices will repeat,
+    /// and if the range is empty no indices are generated.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Panics if the `start` index's conversion panics.
+    /// Returns an error if the range does not fit a sequence of length `len`.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use from_back::idx;
-    /// let vec = vec![8, 6, 7, 5, 3, 0, 9];
-    /// let range = idx!(^2..).for_seq_len(vec.len());
-    /// let expected: &[_] = &[0, 9];
-    /// assert_eq!(range, 5..);
-    /// assert_eq!(vec.get(range), Some(expected));
+    /// let indices = idx!(^10..^0).linspace_for(100, 4).unwrap();
+    /// assert_eq!(indices, [90, 93, 96, 99]);
     /// ```
-    pub fn for_seq_len(&self, len: usize) -> RangeFrom<usize> {
-        self.start.for_seq_len(len)..
+    pub fn linspace_for(&self, len: usize, count: usize) -> Result<Vec<usize>, SeqRangeError> {
+        let range = self.checked_for_seq_len(len)?;
+        let Some(last) = range.len().checked_sub(1) else {
+            return Ok(Vec::new());
+        };
+        let indices = match count {
+            0 => Vec::new(),
+            1 => vec![range.start],
+            _ => {
+                // widen to avoid overflowing the intermediate product
+                let (last, steps) = (last as u128, count as u128 - 1);
+                (0..count as u128)
+                    .map(|i| range.start + ((i * last + steps / 2) / steps) as usize)
+                    .collect()
+            }
+        };
+        Ok(indices)
     }
-}
 
-// ===
+    /// The intersection of this range, resolved against a sequence of the given `len`,
+    /// with a native [`Range`].
+    ///
+    /// Disjoint ranges intersect to an empty range, positioned at whichever range starts later.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this range does not fit a sequence of length `len`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::idx;
+    /// assert_eq!(idx!(^5..^0).intersect_std(0..7, 10), Ok(5..7));
+    /// assert_eq!(idx!(^5..^0).intersect_std(0..2, 10), Ok(5..5));
+    /// ```
+    pub fn intersect_std(&self, other: Range<usize>, len: usize) -> Result<Range<usize>, SeqRangeError> {
+        let range = self.checked_for_seq_len(len)?;
+        let start = range.start.max(other.start);
+        Ok(start..range.end.min(other.end).max(start))
+    }
 
-/// A parallel to [`std::ops::RangeInclusive`] (`idx!(start..=end)`)
-///
+    /// Whether this range, resolved against a sequence of the given `len`,
+    /// shares any elements with a native [`Range`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this range does not fit a sequence of length `len`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::idx;
+    /// assert_eq!(idx!(2..^2).overlaps_std(7..9, 10), Ok(true));
+    /// assert_eq!(idx!(2..^2).overlaps_std(8..9, 10), Ok(false));
+    /// ```
+    pub fn overlaps_std(&self, other: Range<usize>, len: usize) -> Result<bool, SeqRangeError> {
+        Ok(!self.intersect_std(other, len)?.is_empty())
+    }
+
+    /// Whether this range, resolved against a sequence of the given `len`,
+    /// contains every element of a native [`Range`].
+    ///
+    /// An empty `other` is contained by any range.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this range does not fit a sequence of length `len`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::idx;
+    /// assert_eq!(idx!(2..^2).contains_std(2..8, 10), Ok(true));
+    /// assert_eq!(idx!(2..^2).contains_std(2..9, 10), Ok(false));
+    /// ```
+    pub fn contains_std(&self, other: Range<usize>, len: usize) -> Result<bool, SeqRangeError> {
+        let range = self.checked_for_seq_len(len)?;
+        Ok(other.is_empty() || (range.start <= other.start && other.end <= range.end))
+    }
+
+    /// The smallest range, resolved against a sequence of the given `len`,
+    /// which covers both this range and a native [`Range`].
+    ///
+    /// Unlike a union, the hull includes any gap between disjoint ranges.
+    /// An empty `other` does not extend the hull.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this range does not fit a sequence of length `len`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::idx;
+    /// assert_eq!(idx!(^2..^0).hull_std(2..4, 10), Ok(2..10));
+    /// ```
+    pub fn hull_std(&self, other: Range<usize>, len: usize) -> Result<Range<usize>, SeqRangeError> {
+        let range = self.checked_for_seq_len(len)?;
+        Ok(match (range.is_empty(), other.is_empty()) {
+            (_, true) => range,
+            (true, false) => other,
+            (false, false) => range.start.min(other.start)..range.end.max(other.end),
+        })
+    }
+
+    /// Resolve against a sequence of the given `len`, converting the bounds to another integer type.
+    ///
+    /// See [`SeqIndex::resolve_to`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the range does not fit the sequence,
+    /// or if either resolved bound does not fit in `U`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::idx;
+    /// let indices: Vec<u32> = idx!(^3..^0).resolve_to::<u32>(10).unwrap().collect();
+    /// assert_eq!(indices, [7, 8, 9]);
+    /// ```
+    pub fn resolve_to<U>(&self, len: usize) -> Result<Range<U>, SeqRangeError>
+    where U: TryFrom<usize> {
+        let range = self.checked_for_seq_len(len)?;
+        let convert = |index| U::try_from(index).map_err(|_| SeqRangeError::Overflow { index });
+        Ok(convert(range.start)?..convert(range.end)?)
+    }
+
+    /// Whether this range fits a sequence of the given `len`, without being inverted.
+    ///
+    /// Usable in const contexts; see [`static_assert_fits!`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::idx;
+    /// assert!(idx!(2..^3).fits_seq_len(5));
+    /// assert!(!idx!(2..^3).fits_seq_len(4));
+    /// ```
+    pub const fn fits_seq_len(&self, len: usize) -> bool {
+        match (self.start.const_for_seq_len(len), self.end.const_for_seq_len(len)) {
+            (Some(start), Some(end)) => start <= end,
+            _ => false,
+        }
+    }
+
+    /// Resolve to a native [`Range`] which is known to fit a sequence of the given `len`.
+    #[track_caller]
+    fn checked_for_seq_len(&self, len: usize) -> Result<Range<usize>, SeqRangeError> {
+        let range = self.start.untraced_checked_for_seq_len(len).and_then(|start| {
+            let end = self.end.untraced_checked_for_seq_len(len)?;
+            if start > end {
+                return Err(SeqRangeError::Inverted { start, end });
+            }
+            Ok(start..end)
+        });
+        trace_resolution!(self, len, range);
+        range
+    }
+}
+
+/// Convert `start..end`, written between two separately built indices, to `idx!(start..end)`.
+///
+/// The orphan rules forbid implementing [`Index`] for a native range of indices,
+/// so such a range must be converted before indexing with it.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, SeqRange};
+/// let vec = vec![8, 6, 7, 5, 3, 0, 9];
+/// let (start, end) = (idx!(2), idx!(^3));
+/// assert_eq!(SeqRange::from(start..end), idx!(2..^3));
+/// assert_eq!(vec[SeqRange::from(start..end)], [7, 5]);
+/// ```
+impl<Idx> From<Range<SeqIndex<Idx>>> for SeqRange<Idx> {
+    fn from(range: Range<SeqIndex<Idx>>) -> Self {
+        SeqRange { start: range.start, end: range.end }
+    }
+}
+
+// ===
+
+/// A parallel to [`std::ops::RangeFrom`] (`idx!(start..)`)
+///
+/// # Examples
+/// ```rust
+/// # use from_back::idx;
+/// let vec = vec![8, 6, 7, 5, 3, 0, 9];
+///
+/// // slice the elements starting two from the back
+/// assert_eq!(&vec[idx!(^2..)], &[0, 9]);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SeqRangeFrom<Idx> {
+    /// The lower bound of the range (inclusive).
+    pub start: SeqIndex<Idx>,
+}
+
+impl SeqRangeFrom<usize> {
+    /// Convert this container to a native [`RangeFrom`].
+    ///
+    /// Delegates to the `start` index's [`SeqIndex::for_seq_len`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `start` index's conversion panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::idx;
+    /// let vec = vec![8, 6, 7, 5, 3, 0, 9];
+    /// let range = idx!(^2..).for_seq_len(vec.len());
+    /// let expected: &[_] = &[0, 9];
+    /// assert_eq!(range, 5..);
+    /// assert_eq!(vec.get(range), Some(expected));
+    /// ```
+    #[cfg_attr(feature = "no-panic", deprecated(note = "panics on an index which does not fit"))]
+    #[track_caller]
+    pub fn for_seq_len(&self, len: usize) -> RangeFrom<usize> {
+        let range = self.start.untraced_for_seq_len(len).map(|start| start..);
+        trace_resolution!(self, len, range);
+        range.unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Convert this container to a native [`Range`] ending at the back of a sequence
+    /// of the given `len`, or error if the start falls outside it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `start` index falls outside the sequence.
+    pub fn try_for_seq_len(&self, len: usize) -> Result<Range<usize>, SeqRangeError> {
+        Ok(self.start.checked_for_seq_len(len)?..len)
+    }
+
+    /// Whether this range fits a sequence of the given `len`.
+    ///
+    /// Usable in const contexts; see [`static_assert_fits!`].
+    pub const fn fits_seq_len(&self, len: usize) -> bool {
+        self.start.const_for_seq_len(len).is_some()
+    }
+}
+
+/// Convert `start..`, written after a separately built index, to `idx!(start..)`.
+impl<Idx> From<RangeFrom<SeqIndex<Idx>>> for SeqRangeFrom<Idx> {
+    fn from(range: RangeFrom<SeqIndex<Idx>>) -> Self {
+        SeqRangeFrom { start: range.start }
+    }
+}
+
+/// Convert `idx!(start..)` to the equivalent `idx!(start..^0)`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, SeqRange};
+/// assert_eq!(SeqRange::from(idx!(^2..)), idx!(^2..^0));
+/// ```
+impl<Idx> From<SeqRangeFrom<Idx>> for SeqRange<Idx>
+where Idx: Default {
+    fn from(range: SeqRangeFrom<Idx>) -> Self {
+        SeqRange {
+            start: range.start,
+            end: SeqIndex::FromBack(Default::default()),
+        }
+    }
+}
+
+// ===
+
+/// A parallel to [`std::ops::RangeInclusive`] (`idx!(start..=end)`)
+///
 /// # Examples
 /// ```rust
 /// # use from_back::idx;
@@ -209,7 +996,9 @@ pub struct SeqRangeInclusive<Idx> {
 impl SeqRangeInclusive<usize> {
     /// Convert this container to a native [`RangeInclusive`].
     ///
-    /// Delegates to each index's [`SeqIndex::for_seq_len`].
+    /// Delegates to each index's [`SeqIndex::for_seq_len`]. Note that an end of `^0`
+    /// resolves to `len`, one past the last element, so the returned range will not fit
+    /// the sequence. Indexing with such a range panics with a [`SeqRangeError`] message.
     ///
     /// # Panics
     ///
@@ -225,147 +1014,2045 @@ impl SeqRangeInclusive<usize> {
     /// assert_eq!(range, 2..=5);
     /// assert_eq!(vec.get(range), Some(expected));
     /// ```
+    #[cfg_attr(feature = "no-panic", deprecated(note = "panics on an index which does not fit"))]
+    #[track_caller]
     pub fn for_seq_len(&self, len: usize) -> RangeInclusive<usize> {
-        self.start.for_seq_len(len)..=self.end.for_seq_len(len)
+        let range = self.start.untraced_for_seq_len(len).and_then(|start| {
+            Ok(start..=self.end.untraced_for_seq_len(len)?)
+        });
+        trace_resolution!(self, len, range);
+        range.unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Convert this container to an exclusive native [`Range`], or error if it does not fit
+    /// a sequence of the given `len`.
+    ///
+    /// A [`Range`] is returned since an empty [`RangeInclusive`] cannot start at `0`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either index falls outside the sequence, including an end of `^0`,
+    /// or if the start resolves more than one past the end.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::idx;
+    /// assert_eq!(idx!(2..=^2).try_for_seq_len(7), Ok(2..6));
+    /// assert!(idx!(2..=^0).try_for_seq_len(7).is_err());
+    /// ```
+    pub fn try_for_seq_len(&self, len: usize) -> Result<Range<usize>, SeqRangeError> {
+        self.checked_for_seq_len(len)
+    }
+
+    /// Whether this range fits a sequence of the given `len`, without being inverted.
+    ///
+    /// As with [`SeqIndex::fits_seq_len`], the end must refer to an element of the sequence.
+    /// Usable in const contexts; see [`static_assert_fits!`].
+    pub const fn fits_seq_len(&self, len: usize) -> bool {
+        match (self.start.const_for_seq_len(len), self.end.const_for_seq_len(len)) {
+            (Some(start), Some(end)) => end < len && start <= end + 1,
+            _ => false,
+        }
+    }
+
+    /// Resolve to an exclusive native [`Range`] which is known to fit a sequence of the given `len`.
+    ///
+    /// The end must resolve to an element of the sequence, so `^0` and any from-front
+    /// end of at least `len` (including `usize::MAX`) are out of bounds. As with std,
+    /// a start one past the end is an empty range.
+    #[track_caller]
+    fn checked_for_seq_len(&self, len: usize) -> Result<Range<usize>, SeqRangeError> {
+        let range = self.start.untraced_checked_for_seq_len(len).and_then(|start| {
+            let end = self.end.untraced_checked_for_seq_len(len)?;
+            if end == len {
+                return Err(SeqRangeError::OutOfBounds { index: self.end, len });
+            }
+            // `end < len`, so this cannot overflow.
+            let end = end + 1;
+            if start > end {
+                return Err(SeqRangeError::Inverted { start, end });
+            }
+            Ok(start..end)
+        });
+        trace_resolution!(self, len, range);
+        range
+    }
+}
+
+/// Convert `start..=end`, written between two separately built indices, to `idx!(start..=end)`.
+impl<Idx> From<RangeInclusive<SeqIndex<Idx>>> for SeqRangeInclusive<Idx> {
+    fn from(range: RangeInclusive<SeqIndex<Idx>>) -> Self {
+        let (start, end) = range.into_inner();
+        SeqRangeInclusive { start, end }
     }
 }
 
 // ===
-// Vec impls
 
-impl<T> Index<SeqIndex<usize>> for Vec<T> {
-    type Output = T;
+/// The error type for fallible operations on the library's structs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SeqRangeError {
+    /// An index fell outside of a sequence of length `len`.
+    OutOfBounds {
+        /// The offending index.
+        index: SeqIndex<usize>,
+        /// The length of the sequence.
+        len: usize,
+    },
+    /// A range's start resolved to a position after its end.
+    Inverted {
+        /// The resolved start of the range.
+        start: usize,
+        /// The resolved end of the range.
+        end: usize,
+    },
+    /// Ranges which must be disjoint overlapped.
+    Overlapping {
+        /// The first position contained by more than one range.
+        index: usize,
+    },
+    /// A resolved position did not fit the requested integer type.
+    Overflow {
+        /// The resolved position.
+        index: usize,
+    },
+}
 
-    fn index(&self, rng: SeqIndex<usize>) -> &T {
-        let range = rng.for_seq_len(self.len());
-        &self[range]
+impl std::fmt::Display for SeqRangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            SeqRangeError::OutOfBounds { index: SeqIndex::FromFront(idx), len } => {
+                write!(f, "index {idx} out of range for sequence of length {len}")
+            }
+            SeqRangeError::OutOfBounds { index: SeqIndex::FromBack(idx), len } => {
+                write!(f, "index ^{idx} out of range for sequence of length {len}")
+            }
+            SeqRangeError::Inverted { start, end } => {
+                write!(f, "range starts at index {start} but ends at index {end}")
+            }
+            SeqRangeError::Overlapping { index } => {
+                write!(f, "ranges overlap at index {index}")
+            }
+            SeqRangeError::Overflow { index } => {
+                write!(f, "index {index} does not fit the target integer type")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SeqRangeError {}
+
+/// The error for one of several ranges, along with its position among them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SeqRangesError {
+    /// The position of the offending range.
+    pub position: usize,
+    /// The error for the offending range.
+    pub error: SeqRangeError,
+}
+
+impl std::fmt::Display for SeqRangesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "range {}: {}", self.position, self.error)
+    }
+}
+
+impl std::error::Error for SeqRangesError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+// ===
+
+/// Pairs one of the library's structs with the length of the sequence it applies to,
+/// for fallible conversion to a native index or range via [`TryFrom`].
+///
+/// Coherence rules forbid implementing `TryFrom<(SeqIndex<usize>, usize)>` for [`usize`]
+/// directly, so this tuple struct stands in for the pair.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, WithLen};
+/// assert_eq!(usize::try_from(WithLen(idx!(^2), 10)), Ok(8));
+/// assert_eq!(std::ops::Range::try_from(WithLen(idx!(2..^2), 10)), Ok(2..8));
+/// assert!(usize::try_from(WithLen(idx!(^11), 10)).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WithLen<S>(pub S, pub usize);
+
+/// Resolve to a position in `0..=len`.
+impl TryFrom<WithLen<SeqIndex<usize>>> for usize {
+    type Error = SeqRangeError;
+
+    #[track_caller]
+    fn try_from(WithLen(index, len): WithLen<SeqIndex<usize>>) -> Result<usize, SeqRangeError> {
+        index.checked_for_seq_len(len)
+    }
+}
+
+/// Resolve to a range which fits a sequence of length `len`.
+impl TryFrom<WithLen<SeqRange<usize>>> for Range<usize> {
+    type Error = SeqRangeError;
+
+    #[track_caller]
+    fn try_from(WithLen(range, len): WithLen<SeqRange<usize>>) -> Result<Range<usize>, SeqRangeError> {
+        range.checked_for_seq_len(len)
+    }
+}
+
+/// Resolve to a range which fits a sequence of length `len`.
+impl TryFrom<WithLen<SeqRangeFrom<usize>>> for Range<usize> {
+    type Error = SeqRangeError;
+
+    #[track_caller]
+    fn try_from(WithLen(range, len): WithLen<SeqRangeFrom<usize>>) -> Result<Range<usize>, SeqRangeError> {
+        let resolved = range.start.untraced_checked_for_seq_len(len).map(|start| start..len);
+        trace_resolution!(range, len, resolved);
+        resolved
+    }
+}
+
+/// Resolve to an exclusive range which fits a sequence of length `len`.
+///
+/// The inclusive end must resolve to an element of the sequence, so an end of `^0` is out of bounds.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, WithLen};
+/// # use std::ops::Range;
+/// assert_eq!(Range::try_from(WithLen(idx!(2..=^1), 10)), Ok(2..10));
+/// assert!(Range::try_from(WithLen(idx!(2..=^0), 10)).is_err());
+/// ```
+impl TryFrom<WithLen<SeqRangeInclusive<usize>>> for Range<usize> {
+    type Error = SeqRangeError;
+
+    #[track_caller]
+    fn try_from(WithLen(range, len): WithLen<SeqRangeInclusive<usize>>) -> Result<Range<usize>, SeqRangeError> {
+        range.checked_for_seq_len(len)
+    }
+}
+
+// ===
+// Lengths
+
+/// Types with a known length, which the library's structs can be resolved against.
+///
+/// Implemented for slices, arrays, [`Vec`], [`VecDeque`](std::collections::VecDeque),
+/// [`str`] and [`String`], and for any [`ExactSizeIterator`] wrapped in [`ExactLen`],
+/// so an iterator's remaining elements can be addressed without collecting them.
+/// There is no blanket impl over every [`ExactSizeIterator`], since coherence would then
+/// reject the impls for `Vec` and `String`, which std may yet make iterators.
+/// Containers which implement it can be made indexable with the library's structs
+/// by [`impl_seq_index!`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, SeqLen};
+/// let vec = vec![8, 6, 7, 5];
+/// assert_eq!(vec.seq_len(), 4);
+/// assert_eq!(idx!(^1).for_seq(&vec), 3);
+/// assert_eq!(idx!(^2..).for_seq("naïve"), 4..);
+/// ```
+pub trait SeqLen {
+    /// The number of elements in the sequence.
+    fn seq_len(&self) -> usize;
+}
+
+macro_rules! impl_seq_len {
+    ($(<$($gen:ident $(: $bound:path)?),*> $ty:ty;)*) => { $(
+        impl<$($gen $(: $bound)?),*> SeqLen for $ty {
+            fn seq_len(&self) -> usize {
+                self.len()
+            }
+        }
+    )* };
+}
+
+impl_seq_len! {
+    <T> [T];
+    <T> std::collections::VecDeque<T>;
+    <> str;
+    <> String;
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl_seq_len! {
+    <T> Vec<T>;
+}
+
+#[cfg(feature = "allocator_api")]
+impl_seq_len! {
+    <T, A: std::alloc::Allocator> Vec<T, A>;
+}
+
+impl<T, const N: usize> SeqLen for [T; N] {
+    fn seq_len(&self) -> usize {
+        N
+    }
+}
+
+/// An [`ExactSizeIterator`], as a [`SeqLen`] of its remaining length.
+///
+/// See [`SeqLen`] for why iterators are wrapped rather than covered by a blanket impl.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, ExactLen, SeqLen};
+/// let mut iter = [8, 6, 7, 5].iter();
+/// iter.next();
+/// assert_eq!(ExactLen(&mut iter).seq_len(), 3);
+/// assert_eq!(idx!(^1).for_seq(&ExactLen(iter)), 2);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ExactLen<I>(pub I);
+
+impl<I> SeqLen for ExactLen<I>
+where I: ExactSizeIterator {
+    fn seq_len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl SeqIndex<usize> {
+    /// Convert this container to a native from-front [`usize`] for the given sequence.
+    ///
+    /// Shorthand for `self.for_seq_len(seq.seq_len())`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the from-back value exceeds the length of the sequence.
+    #[cfg_attr(feature = "no-panic", deprecated(note = "panics on an index which does not fit"))]
+    #[track_caller]
+    pub fn for_seq<S>(&self, seq: &S) -> usize
+    where S: SeqLen + ?Sized {
+        self.for_seq_len(seq.seq_len())
+    }
+}
+
+impl SeqRange<usize> {
+    /// Convert this container to a native [`Range`] for the given sequence.
+    ///
+    /// Shorthand for `self.for_seq_len(seq.seq_len())`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index's conversion panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::idx;
+    /// # use from_back::ExactLen;
+    /// let iter = (0..10).map(|x| x * x);
+    /// assert_eq!(idx!(2..^2).for_seq(&ExactLen(iter)), 2..8);
+    /// ```
+    #[cfg_attr(feature = "no-panic", deprecated(note = "panics on an index which does not fit"))]
+    #[track_caller]
+    pub fn for_seq<S>(&self, seq: &S) -> Range<usize>
+    where S: SeqLen + ?Sized {
+        self.for_seq_len(seq.seq_len())
+    }
+}
+
+impl SeqRangeFrom<usize> {
+    /// Convert this container to a native [`RangeFrom`] for the given sequence.
+    ///
+    /// Shorthand for `self.for_seq_len(seq.seq_len())`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `start` index's conversion panics.
+    #[cfg_attr(feature = "no-panic", deprecated(note = "panics on an index which does not fit"))]
+    #[track_caller]
+    pub fn for_seq<S>(&self, seq: &S) -> RangeFrom<usize>
+    where S: SeqLen + ?Sized {
+        self.for_seq_len(seq.seq_len())
+    }
+}
+
+impl SeqRangeInclusive<usize> {
+    /// Convert this container to a native [`RangeInclusive`] for the given sequence.
+    ///
+    /// Shorthand for `self.for_seq_len(seq.seq_len())`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index's conversion panics.
+    #[cfg_attr(feature = "no-panic", deprecated(note = "panics on an index which does not fit"))]
+    #[track_caller]
+    pub fn for_seq<S>(&self, seq: &S) -> RangeInclusive<usize>
+    where S: SeqLen + ?Sized {
+        self.for_seq_len(seq.seq_len())
+    }
+}
+
+// ===
+// Vec impls
+//
+// With the `allocator_api` feature, which requires a nightly compiler, these are instead
+// forwarded to the slice impls for a `Vec` with any allocator, alongside the smart pointer impls below.
+
+#[cfg(not(feature = "allocator_api"))]
+impl<T> Index<SeqIndex<usize>> for Vec<T> {
+    type Output = T;
+
+    #[track_caller]
+    fn index(&self, rng: SeqIndex<usize>) -> &T {
+        let index = rng.element_for_seq_len(self.len());
+        &self[index]
+    }
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl<T> IndexMut<SeqIndex<usize>> for Vec<T> {
+    #[track_caller]
+    fn index_mut(&mut self, rng: SeqIndex<usize>) -> &mut T {
+        let index = rng.element_for_seq_len(self.len());
+        &mut self[index]
+    }
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl<T> Index<SeqRange<usize>> for Vec<T> {
+    type Output = [T];
+
+    #[track_caller]
+    fn index(&self, rng: SeqRange<usize>) -> &[T] {
+        let range = rng.for_seq_len(self.len());
+        &self[range]
+    }
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl<T> IndexMut<SeqRange<usize>> for Vec<T> {
+    #[track_caller]
+    fn index_mut(&mut self, rng: SeqRange<usize>) -> &mut [T] {
+        let range = rng.for_seq_len(self.len());
+        &mut self[range]
+    }
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl<T> Index<SeqRangeFrom<usize>> for Vec<T> {
+    type Output = [T];
+
+    #[track_caller]
+    fn index(&self, rng: SeqRangeFrom<usize>) -> &[T] {
+        let range = rng.for_seq_len(self.len());
+        &self[range]
+    }
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl<T> IndexMut<SeqRangeFrom<usize>> for Vec<T> {
+    #[track_caller]
+    fn index_mut(&mut self, rng: SeqRangeFrom<usize>) -> &mut [T] {
+        let range = rng.for_seq_len(self.len());
+        &mut self[range]
+    }
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl<T> Index<SeqRangeInclusive<usize>> for Vec<T> {
+    type Output = [T];
+
+    #[track_caller]
+    fn index(&self, rng: SeqRangeInclusive<usize>) -> &[T] {
+        let range = rng.checked_for_seq_len(self.len()).unwrap_or_else(|err| panic!("{err}"));
+        &self[range]
+    }
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl<T> IndexMut<SeqRangeInclusive<usize>> for Vec<T> {
+    #[track_caller]
+    fn index_mut(&mut self, rng: SeqRangeInclusive<usize>) -> &mut [T] {
+        let range = rng.checked_for_seq_len(self.len()).unwrap_or_else(|err| panic!("{err}"));
+        &mut self[range]
+    }
+}
+
+// ===
+// Slice impls
+
+impl<T> Index<SeqIndex<usize>> for [T] {
+    type Output = T;
+
+    #[track_caller]
+    fn index(&self, rng: SeqIndex<usize>) -> &T {
+        let index = rng.element_for_seq_len(self.len());
+        &self[index]
+    }
+}
+
+impl<T> IndexMut<SeqIndex<usize>> for [T] {
+    #[track_caller]
+    fn index_mut(&mut self, rng: SeqIndex<usize>) -> &mut T {
+        let index = rng.element_for_seq_len(self.len());
+        &mut self[index]
+    }
+}
+
+impl<T> Index<SeqRange<usize>> for [T] {
+    type Output = [T];
+
+    #[track_caller]
+    fn index(&self, rng: SeqRange<usize>) -> &[T] {
+        let range = rng.for_seq_len(self.len());
+        &self[range]
+    }
+}
+
+impl<T> IndexMut<SeqRange<usize>> for [T] {
+    #[track_caller]
+    fn index_mut(&mut self, rng: SeqRange<usize>) -> &mut [T] {
+        let range = rng.for_seq_len(self.len());
+        &mut self[range]
+    }
+}
+
+impl<T> Index<SeqRangeFrom<usize>> for [T] {
+    type Output = [T];
+
+    #[track_caller]
+    fn index(&self, rng: SeqRangeFrom<usize>) -> &[T] {
+        let range = rng.for_seq_len(self.len());
+        &self[range]
+    }
+}
+
+impl<T> IndexMut<SeqRangeFrom<usize>> for [T] {
+    #[track_caller]
+    fn index_mut(&mut self, rng: SeqRangeFrom<usize>) -> &mut [T] {
+        let range = rng.for_seq_len(self.len());
+        &mut self[range]
+    }
+}
+
+impl<T> Index<SeqRangeInclusive<usize>> for [T] {
+    type Output = [T];
+
+    #[track_caller]
+    fn index(&self, rng: SeqRangeInclusive<usize>) -> &[T] {
+        let range = rng.checked_for_seq_len(self.len()).unwrap_or_else(|err| panic!("{err}"));
+        &self[range]
+    }
+}
+
+impl<T> IndexMut<SeqRangeInclusive<usize>> for [T] {
+    #[track_caller]
+    fn index_mut(&mut self, rng: SeqRangeInclusive<usize>) -> &mut [T] {
+        let range = rng.checked_for_seq_len(self.len()).unwrap_or_else(|err| panic!("{err}"));
+        &mut self[range]
+    }
+}
+
+// ===
+// str impls
+
+impl Index<SeqRange<usize>> for str {
+    type Output = str;
+
+    #[track_caller]
+    fn index(&self, rng: SeqRange<usize>) -> &str {
+        let range = rng.for_seq_len(self.len());
+        &self[range]
+    }
+}
+
+impl IndexMut<SeqRange<usize>> for str {
+    #[track_caller]
+    fn index_mut(&mut self, rng: SeqRange<usize>) -> &mut str {
+        let range = rng.for_seq_len(self.len());
+        &mut self[range]
+    }
+}
+
+impl Index<SeqRangeFrom<usize>> for str {
+    type Output = str;
+
+    #[track_caller]
+    fn index(&self, rng: SeqRangeFrom<usize>) -> &str {
+        let range = rng.for_seq_len(self.len());
+        &self[range]
+    }
+}
+
+impl IndexMut<SeqRangeFrom<usize>> for str {
+    #[track_caller]
+    fn index_mut(&mut self, rng: SeqRangeFrom<usize>) -> &mut str {
+        let range = rng.for_seq_len(self.len());
+        &mut self[range]
+    }
+}
+
+impl Index<SeqRangeInclusive<usize>> for str {
+    type Output = str;
+
+    #[track_caller]
+    fn index(&self, rng: SeqRangeInclusive<usize>) -> &str {
+        let range = rng.checked_for_seq_len(self.len()).unwrap_or_else(|err| panic!("{err}"));
+        &self[range]
+    }
+}
+
+impl IndexMut<SeqRangeInclusive<usize>> for str {
+    #[track_caller]
+    fn index_mut(&mut self, rng: SeqRangeInclusive<usize>) -> &mut str {
+        let range = rng.checked_for_seq_len(self.len()).unwrap_or_else(|err| panic!("{err}"));
+        &mut self[range]
+    }
+}
+
+// ===
+// String impls
+
+impl Index<SeqRange<usize>> for String {
+    type Output = str;
+
+    #[track_caller]
+    fn index(&self, rng: SeqRange<usize>) -> &str {
+        let range = rng.for_seq_len(self.len());
+        &self[range]
+    }
+}
+
+impl IndexMut<SeqRange<usize>> for String {
+    #[track_caller]
+    fn index_mut(&mut self, rng: SeqRange<usize>) -> &mut str {
+        let range = rng.for_seq_len(self.len());
+        &mut self[range]
+    }
+}
+
+impl Index<SeqRangeFrom<usize>> for String {
+    type Output = str;
+
+    #[track_caller]
+    fn index(&self, rng: SeqRangeFrom<usize>) -> &str {
+        let range = rng.for_seq_len(self.len());
+        &self[range]
+    }
+}
+
+impl IndexMut<SeqRangeFrom<usize>> for String {
+    #[track_caller]
+    fn index_mut(&mut self, rng: SeqRangeFrom<usize>) -> &mut str {
+        let range = rng.for_seq_len(self.len());
+        &mut self[range]
+    }
+}
+
+impl Index<SeqRangeInclusive<usize>> for String {
+    type Output = str;
+
+    #[track_caller]
+    fn index(&self, rng: SeqRangeInclusive<usize>) -> &str {
+        let range = rng.checked_for_seq_len(self.len()).unwrap_or_else(|err| panic!("{err}"));
+        &self[range]
+    }
+}
+
+impl IndexMut<SeqRangeInclusive<usize>> for String {
+    #[track_caller]
+    fn index_mut(&mut self, rng: SeqRangeInclusive<usize>) -> &mut str {
+        let range = rng.checked_for_seq_len(self.len()).unwrap_or_else(|err| panic!("{err}"));
+        &mut self[range]
+    }
+}
+
+// ===
+// Smart pointer impls
+//
+// Coherence rules forbid a blanket impl over every `Deref<Target = [T]>`,
+// so instead we forward the common std pointer and guard types to their targets.
+
+macro_rules! impl_deref_slice_index {
+    ($(<$($gen:tt $(: $bound:path)?),*> $ty:ty;)*) => { $(
+        impl<$($gen $(: $bound)?),*> Index<SeqIndex<usize>> for $ty {
+            type Output = T;
+
+            #[track_caller]
+            fn index(&self, rng: SeqIndex<usize>) -> &T {
+                &(**self)[rng]
+            }
+        }
+
+        impl<$($gen $(: $bound)?),*> Index<SeqRange<usize>> for $ty {
+            type Output = [T];
+
+            #[track_caller]
+            fn index(&self, rng: SeqRange<usize>) -> &[T] {
+                &(**self)[rng]
+            }
+        }
+
+        impl<$($gen $(: $bound)?),*> Index<SeqRangeFrom<usize>> for $ty {
+            type Output = [T];
+
+            #[track_caller]
+            fn index(&self, rng: SeqRangeFrom<usize>) -> &[T] {
+                &(**self)[rng]
+            }
+        }
+
+        impl<$($gen $(: $bound)?),*> Index<SeqRangeInclusive<usize>> for $ty {
+            type Output = [T];
+
+            #[track_caller]
+            fn index(&self, rng: SeqRangeInclusive<usize>) -> &[T] {
+                &(**self)[rng]
+            }
+        }
+    )* };
+}
+
+// The pointer and guard types with mutable access to their targets also forward `IndexMut`.
+macro_rules! impl_deref_slice_index_mut {
+    ($(<$($gen:tt $(: $bound:path)?),*> $ty:ty;)*) => { $(
+        impl<$($gen $(: $bound)?),*> IndexMut<SeqIndex<usize>> for $ty {
+            #[track_caller]
+            fn index_mut(&mut self, rng: SeqIndex<usize>) -> &mut T {
+                &mut (**self)[rng]
+            }
+        }
+
+        impl<$($gen $(: $bound)?),*> IndexMut<SeqRange<usize>> for $ty {
+            #[track_caller]
+            fn index_mut(&mut self, rng: SeqRange<usize>) -> &mut [T] {
+                &mut (**self)[rng]
+            }
+        }
+
+        impl<$($gen $(: $bound)?),*> IndexMut<SeqRangeFrom<usize>> for $ty {
+            #[track_caller]
+            fn index_mut(&mut self, rng: SeqRangeFrom<usize>) -> &mut [T] {
+                &mut (**self)[rng]
+            }
+        }
+
+        impl<$($gen $(: $bound)?),*> IndexMut<SeqRangeInclusive<usize>> for $ty {
+            #[track_caller]
+            fn index_mut(&mut self, rng: SeqRangeInclusive<usize>) -> &mut [T] {
+                &mut (**self)[rng]
+            }
+        }
+    )* };
+}
+
+macro_rules! impl_deref_str_index {
+    ($(<$($gen:tt $(: $bound:path)?),*> $ty:ty;)*) => { $(
+        impl_deref_str_index!(@impl [$($gen $(: $bound)?),*] $ty);
+    )* };
+    ($($ty:ty;)*) => { $(
+        impl_deref_str_index!(@impl [] $ty);
+    )* };
+    (@impl [$($generics:tt)*] $ty:ty) => {
+        impl<$($generics)*> Index<SeqRange<usize>> for $ty {
+            type Output = str;
+
+            #[track_caller]
+            fn index(&self, rng: SeqRange<usize>) -> &str {
+                &(**self)[rng]
+            }
+        }
+
+        impl<$($generics)*> Index<SeqRangeFrom<usize>> for $ty {
+            type Output = str;
+
+            #[track_caller]
+            fn index(&self, rng: SeqRangeFrom<usize>) -> &str {
+                &(**self)[rng]
+            }
+        }
+
+        impl<$($generics)*> Index<SeqRangeInclusive<usize>> for $ty {
+            type Output = str;
+
+            #[track_caller]
+            fn index(&self, rng: SeqRangeInclusive<usize>) -> &str {
+                &(**self)[rng]
+            }
+        }
+    };
+}
+
+impl_deref_slice_index! {
+    <T> Box<[T]>;
+    <T> std::rc::Rc<[T]>;
+    <T> std::sync::Arc<[T]>;
+    <'a, T: Clone> std::borrow::Cow<'a, [T]>;
+    <'a, T> std::cell::Ref<'a, Vec<T>>;
+    <'a, T> std::cell::Ref<'a, [T]>;
+    <'a, T> std::cell::RefMut<'a, Vec<T>>;
+    <'a, T> std::cell::RefMut<'a, [T]>;
+    <'a, T> std::sync::MutexGuard<'a, Vec<T>>;
+    <'a, T> std::sync::RwLockReadGuard<'a, Vec<T>>;
+    <'a, T> std::sync::RwLockWriteGuard<'a, Vec<T>>;
+}
+
+impl_deref_slice_index_mut! {
+    <T> Box<[T]>;
+    <'a, T> std::cell::RefMut<'a, Vec<T>>;
+    <'a, T> std::cell::RefMut<'a, [T]>;
+    <'a, T> std::sync::MutexGuard<'a, Vec<T>>;
+    <'a, T> std::sync::RwLockWriteGuard<'a, Vec<T>>;
+}
+
+#[cfg(feature = "generic-array")]
+impl_deref_slice_index! {
+    <T, N: generic_array::ArrayLength> generic_array::GenericArray<T, N>;
+}
+
+#[cfg(feature = "generic-array")]
+impl_deref_slice_index_mut! {
+    <T, N: generic_array::ArrayLength> generic_array::GenericArray<T, N>;
+}
+
+#[cfg(feature = "allocator_api")]
+impl_deref_slice_index! {
+    <T, A: std::alloc::Allocator> Vec<T, A>;
+}
+
+#[cfg(feature = "allocator_api")]
+impl_deref_slice_index_mut! {
+    <T, A: std::alloc::Allocator> Vec<T, A>;
+}
+
+impl_deref_str_index! {
+    Box<str>;
+    std::rc::Rc<str>;
+    std::sync::Arc<str>;
+}
+
+impl_deref_str_index! {
+    <'a> std::borrow::Cow<'a, str>;
+    <'a> std::cell::Ref<'a, String>;
+    <'a> std::cell::Ref<'a, str>;
+    <'a> std::cell::RefMut<'a, String>;
+    <'a> std::sync::MutexGuard<'a, String>;
+    <'a> std::sync::RwLockReadGuard<'a, String>;
+    <'a> std::sync::RwLockWriteGuard<'a, String>;
+}
+
+#[cfg(feature = "compact_str")]
+impl_deref_str_index! {
+    compact_str::CompactString;
+}
+
+#[cfg(feature = "smol_str")]
+impl_deref_str_index! {
+    smol_str::SmolStr;
+}
+
+// ===
+// Nested impls
+
+/// A pair of indices into a jagged sequence of rows, eg a `Vec<Vec<T>>`.
+///
+/// The outer index selects a row, and the inner index is resolved against that row's length.
+///
+/// Coherence rules forbid implementing `Index<(SeqIndex<usize>, SeqIndex<usize>)>` for [`Vec`]
+/// directly, so this tuple struct stands in for the pair.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, Nested};
+/// let grid = vec![vec![1, 2, 3], vec![4, 5]];
+/// // the first element of the last row
+/// assert_eq!(grid[Nested(idx!(^1), idx!(0))], 4);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Nested(pub SeqIndex<usize>, pub SeqIndex<usize>);
+
+impl From<(SeqIndex<usize>, SeqIndex<usize>)> for Nested {
+    fn from((row, col): (SeqIndex<usize>, SeqIndex<usize>)) -> Self {
+        Nested(row, col)
+    }
+}
+
+impl<T> Index<Nested> for Vec<Vec<T>> {
+    type Output = T;
+
+    fn index(&self, index: Nested) -> &T {
+        &self.as_slice()[index]
+    }
+}
+
+impl<T> Index<Nested> for [Vec<T>] {
+    type Output = T;
+
+    fn index(&self, Nested(row, col): Nested) -> &T {
+        &self[row][col]
+    }
+}
+
+impl<T> Index<Nested> for [&[T]] {
+    type Output = T;
+
+    fn index(&self, Nested(row, col): Nested) -> &T {
+        &self[row][col]
+    }
+}
+
+// ===
+// Slice extensions
+
+/// Extension methods for slices which accept the library's structs
+/// in place of native indices and ranges.
+pub trait SliceSeqExt<T> {
+    /// Run [`slice::partition_point`] within the given range only.
+    ///
+    /// The returned index is relative to the whole slice, not the range,
+    /// so it can be passed directly to eg [`Vec::insert`]. This is useful
+    /// for ignoring sentinel elements at either end of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range does not fit the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, SliceSeqExt};
+    /// // sorted, save for a trailing sentinel
+    /// let vec = vec![1, 2, 4, 5, 7, 0];
+    /// let point = vec.partition_point_seq(idx!(..^1), |&x| x < 6);
+    /// assert_eq!(point, 4);
+    /// ```
+    #[cfg_attr(feature = "no-panic", deprecated(note = "panics on an index which does not fit"))]
+    fn partition_point_seq<P>(&self, range: SeqRange<usize>, pred: P) -> usize
+    where P: FnMut(&T) -> bool;
+
+    /// Copy the elements of each range, in order, into a single [`Vec`].
+    ///
+    /// All ranges are validated before anything is copied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the ranges does not fit the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, SliceSeqExt};
+    /// let vec = vec![8, 6, 7, 5, 3, 0, 9];
+    /// let packet = vec.extract_seq(&[idx!(..2), idx!(^2..^0)]).unwrap();
+    /// assert_eq!(packet, [8, 6, 0, 9]);
+    /// assert!(vec.extract_seq(&[idx!(..2), idx!(^8..^0)]).is_err());
+    /// ```
+    fn extract_seq(&self, ranges: &[SeqRange<usize>]) -> Result<Vec<T>, SeqRangeError>
+    where T: Clone;
+
+    /// Borrow the elements of each range at once, in order.
+    ///
+    /// Unlike [`scoped_ranges_mut`], the ranges may overlap.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for the first range which does not fit the slice, along with its position.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, SliceSeqExt};
+    /// let vec = vec![8, 6, 7, 5, 3, 0, 9];
+    /// let parts = vec.multi_slice(&[idx!(..3), idx!(2..^2), idx!(^1..^0)]).unwrap();
+    /// assert_eq!(parts, [&[8, 6, 7][..], &[7, 5, 3], &[9]]);
+    /// let err = vec.multi_slice(&[idx!(..3), idx!(^8..^0)]).unwrap_err();
+    /// assert_eq!(err.position, 1);
+    /// ```
+    fn multi_slice(&self, ranges: &[SeqRange<usize>]) -> Result<Vec<&[T]>, SeqRangesError>;
+
+    /// The last `n` elements (`idx!(^n..)`), or `None` if there are fewer than `n`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::SliceSeqExt;
+    /// let vec = vec![8, 6, 7, 5, 3, 0, 9];
+    /// assert_eq!(vec.tail(2), Some(&[0, 9][..]));
+    /// assert_eq!(vec.tail(8), None);
+    /// ```
+    fn tail(&self, n: usize) -> Option<&[T]>;
+
+    /// The first `n` elements (`idx!(..n)`), or `None` if there are fewer than `n`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::SliceSeqExt;
+    /// let vec = vec![8, 6, 7, 5, 3, 0, 9];
+    /// assert_eq!(vec.head(2), Some(&[8, 6][..]));
+    /// assert_eq!(vec.head(8), None);
+    /// ```
+    fn head(&self, n: usize) -> Option<&[T]>;
+
+    /// All but the last `n` elements (`idx!(..^n)`), or `None` if there are fewer than `n`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::SliceSeqExt;
+    /// let vec = vec![8, 6, 7, 5, 3, 0, 9];
+    /// assert_eq!(vec.without_tail(2), Some(&[8, 6, 7, 5, 3][..]));
+    /// assert_eq!(vec.without_tail(8), None);
+    /// ```
+    fn without_tail(&self, n: usize) -> Option<&[T]>;
+
+    /// All but the first `n` elements (`idx!(n..)`), or `None` if there are fewer than `n`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::SliceSeqExt;
+    /// let vec = vec![8, 6, 7, 5, 3, 0, 9];
+    /// assert_eq!(vec.without_head(2), Some(&[7, 5, 3, 0, 9][..]));
+    /// assert_eq!(vec.without_head(8), None);
+    /// ```
+    fn without_head(&self, n: usize) -> Option<&[T]>;
+
+    /// Split off the last `n` elements as a trailer (`(idx!(..^n), idx!(^n..))`),
+    /// or `None` if there are fewer than `n`.
+    ///
+    /// For a fixed-size trailer array, see [`slice::split_last_chunk`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::SliceSeqExt;
+    /// // a payload, followed by a 2 byte checksum
+    /// let frame = [b'h', b'i', 0xbe, 0xef];
+    /// assert_eq!(frame.split_footer(2), Some((&b"hi"[..], &[0xbe, 0xef][..])));
+    /// assert_eq!(frame.split_footer(5), None);
+    /// ```
+    fn split_footer(&self, n: usize) -> Option<(&[T], &[T])>;
+
+    /// As [`split_footer`](SliceSeqExt::split_footer), but mutable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::SliceSeqExt;
+    /// let mut frame = [b'h', b'i', 0, 0];
+    /// let (payload, footer) = frame.split_footer_mut(2).unwrap();
+    /// footer.copy_from_slice(&[payload.len() as u8, 0xff]);
+    /// assert_eq!(frame, [b'h', b'i', 2, 0xff]);
+    /// ```
+    fn split_footer_mut(&mut self, n: usize) -> Option<(&mut [T], &mut [T])>;
+
+    /// Split off the first `n` elements as a header (`(idx!(..n), idx!(n..))`),
+    /// or `None` if there are fewer than `n`.
+    ///
+    /// For a fixed-size header array, see [`slice::split_first_chunk`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::SliceSeqExt;
+    /// // a 1 byte tag, followed by a payload
+    /// let frame = [0x01, b'h', b'i'];
+    /// assert_eq!(frame.split_header(1), Some((&[0x01][..], &b"hi"[..])));
+    /// assert_eq!(frame.split_header(4), None);
+    /// ```
+    fn split_header(&self, n: usize) -> Option<(&[T], &[T])>;
+
+    /// As [`split_header`](SliceSeqExt::split_header), but mutable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::SliceSeqExt;
+    /// let mut frame = [0, b'h', b'i'];
+    /// let (header, payload) = frame.split_header_mut(1).unwrap();
+    /// header[0] = payload.len() as u8;
+    /// assert_eq!(frame, [2, b'h', b'i']);
+    /// ```
+    fn split_header_mut(&mut self, n: usize) -> Option<(&mut [T], &mut [T])>;
+
+    /// The position of the `n`th occurrence of `delim`, counting from the back as `idx!(^n)` does,
+    /// or `None` if there are fewer than `n` occurrences.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, SliceSeqExt};
+    /// let path = [0, 1, 0, 2, 0, 3];
+    /// assert_eq!(path.rfind_n_seq(&0, 2), Some(idx!(^4)));
+    /// assert_eq!(path.rfind_n_seq(&0, 4), None);
+    /// ```
+    fn rfind_n_seq(&self, delim: &T, n: usize) -> Option<SeqIndex<usize>>
+    where T: PartialEq;
+
+    /// Split the slice around the `n`th occurrence of `delim`, counting from the back
+    /// as `idx!(^n)` does, or `None` if there are fewer than `n` occurrences.
+    ///
+    /// Neither part includes the delimiter itself.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::SliceSeqExt;
+    /// let path = b"usr/local/lib/libfoo.so";
+    /// assert_eq!(path.rsplit_n_seq(&b'/', 2), Some((&b"usr/local"[..], &b"lib/libfoo.so"[..])));
+    /// assert_eq!(path.rsplit_n_seq(&b'/', 4), None);
+    /// ```
+    fn rsplit_n_seq(&self, delim: &T, n: usize) -> Option<(&[T], &[T])>
+    where T: PartialEq;
+
+    /// Split the slice mutably at two indices into the parts before, between, and after them.
+    ///
+    /// Returns an error if either index does not fit the slice,
+    /// or if `a` resolves to a position after `b`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, SliceSeqExt};
+    /// // a 1 byte tag, a payload, and a 1 byte checksum
+    /// let mut frame = [0x01, 3, 4, 0];
+    /// let (header, body, footer) = frame.split3_mut_seq(idx!(1), idx!(^1)).unwrap();
+    /// body.reverse();
+    /// footer[0] = header[0] ^ body[0] ^ body[1];
+    /// assert_eq!(frame, [0x01, 4, 3, 0x06]);
+    /// assert!(frame.split3_mut_seq(idx!(^1), idx!(1)).is_err());
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn split3_mut_seq(&mut self, a: SeqIndex<usize>, b: SeqIndex<usize>) -> Result<(&mut [T], &mut [T], &mut [T]), SeqRangeError>;
+
+    /// Split the slice at each of the given indices, into one more part than there are indices.
+    ///
+    /// Returns an error if any index does not fit the slice,
+    /// or if the indices do not resolve to positions in ascending order.
+    /// Equal positions are allowed, and produce an empty part between them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, SliceSeqExt};
+    /// // a 1 byte tag, a payload, a 2 byte checksum, and a 1 byte terminator
+    /// let frame = [0x01, b'h', b'i', 0xbe, 0xef, 0x00];
+    /// let parts = frame.split_at_many_seq(&[idx!(1), idx!(^3), idx!(^1)]).unwrap();
+    /// assert_eq!(parts, [&[0x01][..], b"hi", &[0xbe, 0xef], &[0x00]]);
+    /// assert!(frame.split_at_many_seq(&[idx!(^1), idx!(1)]).is_err());
+    /// ```
+    fn split_at_many_seq(&self, cuts: &[SeqIndex<usize>]) -> Result<Vec<&[T]>, SeqRangeError>;
+
+    /// As [`split_at_many_seq`](SliceSeqExt::split_at_many_seq), but mutable.
+    fn split_at_many_seq_mut(&mut self, cuts: &[SeqIndex<usize>]) -> Result<Vec<&mut [T]>, SeqRangeError>;
+
+    /// Iterate over the elements before and after the range, skipping the range itself.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range does not fit the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, SliceSeqExt};
+    /// let vec = vec![8, 6, 7, 5, 3, 0, 9];
+    /// let outside: Vec<_> = vec.iter_excluding_seq(idx!(2..^2)).collect();
+    /// assert_eq!(outside, [&8, &6, &0, &9]);
+    /// ```
+    #[cfg_attr(feature = "no-panic", deprecated(note = "panics on an index which does not fit"))]
+    fn iter_excluding_seq(&self, range: SeqRange<usize>) -> Chain<slice::Iter<'_, T>, slice::Iter<'_, T>>;
+
+    /// Mutably iterate over the elements before and after the range, skipping the range itself.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range does not fit the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, SliceSeqExt};
+    /// let mut vec = vec![8, 6, 7, 5, 3, 0, 9];
+    /// for elem in vec.iter_excluding_seq_mut(idx!(1..^1)) {
+    ///     *elem = 0;
+    /// }
+    /// assert_eq!(vec, [0, 6, 7, 5, 3, 0, 0]);
+    /// ```
+    #[cfg_attr(feature = "no-panic", deprecated(note = "panics on an index which does not fit"))]
+    fn iter_excluding_seq_mut(&mut self, range: SeqRange<usize>) -> Chain<slice::IterMut<'_, T>, slice::IterMut<'_, T>>;
+
+    /// The `M` elements starting at the given index, as a fixed-size array,
+    /// or `None` if they do not fit the slice.
+    ///
+    /// Like [`first_chunk`](slice::first_chunk) and [`last_chunk`](slice::last_chunk),
+    /// but at any offset.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, SliceSeqExt};
+    /// // a 4 byte length field, followed by a 2 byte checksum
+    /// let buf = [0xff, 0, 0, 0, 5, 0xbe, 0xef];
+    /// let len = buf.array_seq::<4>(idx!(^6)).copied().map(u32::from_be_bytes);
+    /// assert_eq!(len, Some(5));
+    /// assert_eq!(buf.array_seq::<4>(idx!(^3)), None);
+    /// ```
+    fn array_seq<const M: usize>(&self, start: SeqIndex<usize>) -> Option<&[T; M]>;
+
+    /// The `M` elements starting at the given index, as a mutable fixed-size array,
+    /// or `None` if they do not fit the slice.
+    ///
+    /// See [`array_seq`](SliceSeqExt::array_seq).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, SliceSeqExt};
+    /// let mut buf = [0xff, 0, 0, 0, 0, 0, 0];
+    /// *buf.array_seq_mut(idx!(^6)).unwrap() = 5u32.to_be_bytes();
+    /// assert_eq!(buf, [0xff, 0, 0, 0, 5, 0, 0]);
+    /// ```
+    fn array_seq_mut<const M: usize>(&mut self, start: SeqIndex<usize>) -> Option<&mut [T; M]>;
+
+    /// A mutable view over the given range, which can be moved and resized within the slice.
+    ///
+    /// See [`WindowMut`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the range does not fit the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, SliceSeqExt};
+    /// let mut vec = vec![8, 6, 7, 5, 3, 0, 9];
+    /// let mut window = vec.window_mut(idx!(2..^2)).unwrap();
+    /// window.sort();
+    /// assert_eq!(vec, [8, 6, 3, 5, 7, 0, 9]);
+    /// ```
+    fn window_mut(&mut self, range: SeqRange<usize>) -> Result<WindowMut<'_, T>, SeqRangeError>;
+}
+
+impl<T> SliceSeqExt<T> for [T] {
+    fn partition_point_seq<P>(&self, range: SeqRange<usize>, pred: P) -> usize
+    where P: FnMut(&T) -> bool {
+        let range = range.for_seq_len(self.len());
+        range.start + self[range].partition_point(pred)
+    }
+
+    fn extract_seq(&self, ranges: &[SeqRange<usize>]) -> Result<Vec<T>, SeqRangeError>
+    where T: Clone {
+        let ranges = ranges
+            .iter()
+            .map(|range| range.checked_for_seq_len(self.len()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut out = Vec::with_capacity(ranges.iter().map(|range| range.len()).sum());
+        for range in ranges {
+            out.extend_from_slice(&self[range]);
+        }
+        Ok(out)
+    }
+
+    fn multi_slice(&self, ranges: &[SeqRange<usize>]) -> Result<Vec<&[T]>, SeqRangesError> {
+        ranges
+            .iter()
+            .enumerate()
+            .map(|(position, range)| {
+                let range = range.checked_for_seq_len(self.len()).map_err(|error| SeqRangesError { position, error })?;
+                Ok(&self[range])
+            })
+            .collect()
+    }
+
+    fn tail(&self, n: usize) -> Option<&[T]> {
+        self.get(self.len().checked_sub(n)?..)
+    }
+
+    fn head(&self, n: usize) -> Option<&[T]> {
+        self.get(..n)
+    }
+
+    fn without_tail(&self, n: usize) -> Option<&[T]> {
+        self.get(..self.len().checked_sub(n)?)
+    }
+
+    fn without_head(&self, n: usize) -> Option<&[T]> {
+        self.get(n..)
+    }
+
+    fn split_footer(&self, n: usize) -> Option<(&[T], &[T])> {
+        self.split_at_checked(self.len().checked_sub(n)?)
+    }
+
+    fn split_footer_mut(&mut self, n: usize) -> Option<(&mut [T], &mut [T])> {
+        self.split_at_mut_checked(self.len().checked_sub(n)?)
+    }
+
+    fn split_header(&self, n: usize) -> Option<(&[T], &[T])> {
+        self.split_at_checked(n)
+    }
+
+    fn split_header_mut(&mut self, n: usize) -> Option<(&mut [T], &mut [T])> {
+        self.split_at_mut_checked(n)
+    }
+
+    fn rfind_n_seq(&self, delim: &T, n: usize) -> Option<SeqIndex<usize>>
+    where T: PartialEq {
+        let (position, _) = self.iter().enumerate().rev().filter(|&(_, x)| x == delim).nth(n.checked_sub(1)?)?;
+        Some(SeqIndex::FromBack(self.len() - position))
+    }
+
+    fn rsplit_n_seq(&self, delim: &T, n: usize) -> Option<(&[T], &[T])>
+    where T: PartialEq {
+        let position = self.rfind_n_seq(delim, n)?.checked_for_seq_len(self.len()).ok()?;
+        Some((&self[..position], &self[position + 1..]))
+    }
+
+    fn split3_mut_seq(&mut self, a: SeqIndex<usize>, b: SeqIndex<usize>) -> Result<(&mut [T], &mut [T], &mut [T]), SeqRangeError> {
+        let range = SeqRange { start: a, end: b }.checked_for_seq_len(self.len())?;
+        let (rest, after) = self.split_at_mut(range.end);
+        let (before, between) = rest.split_at_mut(range.start);
+        Ok((before, between, after))
+    }
+
+    fn split_at_many_seq(&self, cuts: &[SeqIndex<usize>]) -> Result<Vec<&[T]>, SeqRangeError> {
+        let cuts = ascending_cuts(cuts, self.len())?;
+        let mut parts = Vec::with_capacity(cuts.len() + 1);
+        let (mut rest, mut offset) = (self, 0);
+        for cut in cuts {
+            let (part, tail) = rest.split_at(cut - offset);
+            parts.push(part);
+            (rest, offset) = (tail, cut);
+        }
+        parts.push(rest);
+        Ok(parts)
+    }
+
+    fn split_at_many_seq_mut(&mut self, cuts: &[SeqIndex<usize>]) -> Result<Vec<&mut [T]>, SeqRangeError> {
+        let cuts = ascending_cuts(cuts, self.len())?;
+        let mut parts = Vec::with_capacity(cuts.len() + 1);
+        let (mut rest, mut offset) = (self, 0);
+        for cut in cuts {
+            let (part, tail) = rest.split_at_mut(cut - offset);
+            parts.push(part);
+            (rest, offset) = (tail, cut);
+        }
+        parts.push(rest);
+        Ok(parts)
+    }
+
+    fn iter_excluding_seq(&self, range: SeqRange<usize>) -> Chain<slice::Iter<'_, T>, slice::Iter<'_, T>> {
+        let range = range.for_seq_len(self.len());
+        let (rest, after) = self.split_at(range.end);
+        let (before, _) = rest.split_at(range.start);
+        before.iter().chain(after)
+    }
+
+    fn iter_excluding_seq_mut(&mut self, range: SeqRange<usize>) -> Chain<slice::IterMut<'_, T>, slice::IterMut<'_, T>> {
+        let range = range.for_seq_len(self.len());
+        let (rest, after) = self.split_at_mut(range.end);
+        let (before, _) = rest.split_at_mut(range.start);
+        before.iter_mut().chain(after)
+    }
+
+    fn array_seq<const M: usize>(&self, start: SeqIndex<usize>) -> Option<&[T; M]> {
+        let start = start.checked_for_seq_len(self.len()).ok()?;
+        self.get(start..start.checked_add(M)?)?.try_into().ok()
+    }
+
+    fn array_seq_mut<const M: usize>(&mut self, start: SeqIndex<usize>) -> Option<&mut [T; M]> {
+        let start = start.checked_for_seq_len(self.len()).ok()?;
+        self.get_mut(start..start.checked_add(M)?)?.try_into().ok()
+    }
+
+    fn window_mut(&mut self, range: SeqRange<usize>) -> Result<WindowMut<'_, T>, SeqRangeError> {
+        WindowMut::new(self, range)
+    }
+}
+
+/// Resolve each cut against a sequence of length `len`, requiring them to be in ascending order.
+fn ascending_cuts(cuts: &[SeqIndex<usize>], len: usize) -> Result<Vec<usize>, SeqRangeError> {
+    let cuts = cuts
+        .iter()
+        .map(|cut| cut.checked_for_seq_len(len))
+        .collect::<Result<Vec<_>, _>>()?;
+    if let Some(pair) = cuts.windows(2).find(|pair| pair[0] > pair[1]) {
+        return Err(SeqRangeError::Inverted { start: pair[0], end: pair[1] });
+    }
+    Ok(cuts)
+}
+
+/// Extension methods for slice references used as cursors, which consume
+/// a chunk from either end of the slice and leave the rest in place.
+///
+/// Mirrors the [`split_off`](slice::split_off) family of methods.
+pub trait SliceCursorSeqExt: Sized {
+    /// Remove the given range from the slice reference, returning it,
+    /// and shrink the reference to the remainder.
+    ///
+    /// The range must resolve to a prefix or a suffix of the slice, so that
+    /// the remainder is contiguous. Returns `None`, leaving the slice reference
+    /// unchanged, if the range does not fit the slice or lies strictly within it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, SliceCursorSeqExt};
+    /// // a payload, followed by a 2 byte checksum
+    /// let mut packet: &[u8] = &[7, 1, 2, 3, 0xbe, 0xef];
+    /// assert_eq!(packet.take_seq(idx!(^2..)), Some(&[0xbe, 0xef][..]));
+    /// assert_eq!(packet.take_seq(idx!(..1)), Some(&[7][..]));
+    /// assert_eq!(packet, [1, 2, 3]);
+    /// assert_eq!(packet.take_seq(idx!(1..^1)), None);
+    /// ```
+    fn take_seq(&mut self, range: impl Into<SeqRange<usize>>) -> Option<Self>;
+}
+
+/// Whether the resolved range is a prefix of a sequence of length `len`, or else a suffix,
+/// or `None` if it is neither.
+fn cursor_split(range: SeqRange<usize>, len: usize) -> Option<(usize, bool)> {
+    let range = range.checked_for_seq_len(len).ok()?;
+    match range {
+        Range { start: 0, end } => Some((end, true)),
+        Range { start, end } if end == len => Some((start, false)),
+        _ => None,
+    }
+}
+
+impl<'a, T> SliceCursorSeqExt for &'a [T] {
+    fn take_seq(&mut self, range: impl Into<SeqRange<usize>>) -> Option<&'a [T]> {
+        let (mid, prefix) = cursor_split(range.into(), self.len())?;
+        let (front, back) = self.split_at(mid);
+        let (taken, rest) = if prefix { (front, back) } else { (back, front) };
+        *self = rest;
+        Some(taken)
+    }
+}
+
+impl<'a, T> SliceCursorSeqExt for &'a mut [T] {
+    fn take_seq(&mut self, range: impl Into<SeqRange<usize>>) -> Option<&'a mut [T]> {
+        let (mid, prefix) = cursor_split(range.into(), self.len())?;
+        let (front, back) = std::mem::take(self).split_at_mut(mid);
+        let (taken, rest) = if prefix { (front, back) } else { (back, front) };
+        *self = rest;
+        Some(taken)
+    }
+}
+
+// ===
+// str extensions
+
+/// Extension methods for [`str`] which accept the library's structs.
+pub trait StrSeqExt {
+    /// Get the [`char`] at the given index, counting in chars rather than bytes.
+    ///
+    /// From-back indices iterate from the back of the string,
+    /// so only the chars between the index and its anchor are decoded.
+    /// Returns `None` if the index is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, StrSeqExt};
+    /// let s = "señor";
+    /// assert_eq!(s.char_at_seq(idx!(^1)), Some('r'));
+    /// assert_eq!(s.char_at_seq(idx!(^3)), Some('ñ'));
+    /// assert_eq!(s.char_at_seq(idx!(3)), Some('o'));
+    /// assert_eq!(s.char_at_seq(idx!(^0)), None);
+    /// ```
+    fn char_at_seq(&self, index: SeqIndex<usize>) -> Option<char>;
+
+    /// Split the string in two at the given byte index.
+    ///
+    /// See [`str::split_at`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of range, or does not fall on a char boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, StrSeqExt};
+    /// assert_eq!("report.tar.gz".split_at_seq(idx!(^3)), ("report.tar", ".gz"));
+    /// ```
+    #[cfg_attr(feature = "no-panic", deprecated(note = "panics on an index which does not fit"))]
+    fn split_at_seq(&self, index: SeqIndex<usize>) -> (&str, &str);
+
+    /// Split the string in two at the given byte index,
+    /// or `None` if it is out of range or does not fall on a char boundary.
+    ///
+    /// See [`str::split_at_checked`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, StrSeqExt};
+    /// // 'ñ' is two bytes
+    /// assert_eq!("señor".try_split_at_seq(idx!(^2)), Some(("señ", "or")));
+    /// assert_eq!("señor".try_split_at_seq(idx!(^3)), None);
+    /// assert_eq!("señor".try_split_at_seq(idx!(^7)), None);
+    /// ```
+    fn try_split_at_seq(&self, index: SeqIndex<usize>) -> Option<(&str, &str)>;
+
+    /// Split the string in two at the given index, counting in chars rather than bytes,
+    /// or `None` if it is out of range.
+    ///
+    /// As with [`char_at_seq`](StrSeqExt::char_at_seq), only the chars between
+    /// the index and its anchor are decoded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, StrSeqExt};
+    /// assert_eq!("¡señor!".split_at_chars_seq(idx!(^4)), Some(("¡se", "ñor!")));
+    /// assert_eq!("¡señor!".split_at_chars_seq(idx!(^8)), None);
+    /// ```
+    fn split_at_chars_seq(&self, index: SeqIndex<usize>) -> Option<(&str, &str)>;
+
+    /// The byte position of the `n`th occurrence of `delim`, counting from the back
+    /// as `idx!(^n)` does, or `None` if there are fewer than `n` occurrences.
+    ///
+    /// Occurrences are found as by [`str::rmatch_indices`], so they do not overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, StrSeqExt};
+    /// assert_eq!("a::b::c".rfind_n_seq("::", 2), Some(idx!(^6)));
+    /// assert_eq!("a::b::c".rfind_n_seq("::", 3), None);
+    /// ```
+    fn rfind_n_seq(&self, delim: &str, n: usize) -> Option<SeqIndex<usize>>;
+
+    /// Split the string around the `n`th occurrence of `delim`, counting from the back
+    /// as `idx!(^n)` does, or `None` if there are fewer than `n` occurrences.
+    ///
+    /// Neither part includes the delimiter itself. The first part is the first piece
+    /// of Python's `s.rsplit(delim, n)`, and the second is the rest of its pieces rejoined.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::StrSeqExt;
+    /// let name = "report.final.tar.gz";
+    /// assert_eq!(name.rsplit_n_seq(".", 2), Some(("report.final", "tar.gz")));
+    /// assert_eq!(name.rsplit_n_seq(".", 4), None);
+    /// ```
+    fn rsplit_n_seq(&self, delim: &str, n: usize) -> Option<(&str, &str)>;
+
+    /// All but the last `n` bytes (`idx!(..^n)`),
+    /// or `None` if there are fewer than `n` or the result would split a char.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::StrSeqExt;
+    /// assert_eq!("frame_042.png".strip_back(4), Some("frame_042"));
+    /// assert_eq!("png".strip_back(4), None);
+    /// ```
+    fn strip_back(&self, n: usize) -> Option<&str>;
+
+    /// All but the first `n` bytes (`idx!(n..)`),
+    /// or `None` if there are fewer than `n` or the result would split a char.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::StrSeqExt;
+    /// assert_eq!("0x1f".strip_front(2), Some("1f"));
+    /// assert_eq!("€1".strip_front(1), None);
+    /// ```
+    fn strip_front(&self, n: usize) -> Option<&str>;
+
+    /// All but the last `n` chars, or `None` if there are fewer than `n`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::StrSeqExt;
+    /// assert_eq!("12,50 €".strip_back_chars(2), Some("12,50"));
+    /// assert_eq!("€".strip_back_chars(2), None);
+    /// ```
+    fn strip_back_chars(&self, n: usize) -> Option<&str>;
+
+    /// All but the first `n` chars, or `None` if there are fewer than `n`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::StrSeqExt;
+    /// assert_eq!("¿qué?".strip_front_chars(1), Some("qué?"));
+    /// assert_eq!("¿".strip_front_chars(2), None);
+    /// ```
+    fn strip_front_chars(&self, n: usize) -> Option<&str>;
+
+    /// The last `n` lines, including their line terminators, or the whole string if it has fewer.
+    ///
+    /// Only the lines between the back of the string and the result are scanned.
+    /// Lines are split as [`str::lines`] splits them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::StrSeqExt;
+    /// let log = "one\ntwo\nthree\n";
+    /// assert_eq!(log.tail_lines(2), "two\nthree\n");
+    /// assert_eq!(log.tail_lines(5), log);
+    /// ```
+    fn tail_lines(&self, n: usize) -> &str;
+
+    /// The first `n` lines, including their line terminators, or the whole string if it has fewer.
+    ///
+    /// Only the lines between the front of the string and the end of the result are scanned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::StrSeqExt;
+    /// let log = "one\ntwo\nthree\n";
+    /// assert_eq!(log.head_lines(1), "one\n");
+    /// ```
+    fn head_lines(&self, n: usize) -> &str;
+
+    /// The lines within the given range of line numbers, including their line terminators,
+    /// or `None` if the range does not fit.
+    ///
+    /// As with [`tail_lines`](StrSeqExt::tail_lines), each bound only scans the lines
+    /// between it and its anchor. See also [`Lines`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, StrSeqExt};
+    /// let log = "header\none\ntwo\nfooter";
+    /// assert_eq!(log.slice_lines(idx!(1..^1)), Some("one\ntwo\n"));
+    /// assert_eq!(log.slice_lines(idx!(^5..)), None);
+    /// ```
+    fn slice_lines(&self, range: impl Into<SeqRange<usize>>) -> Option<&str>;
+
+    /// The word at the given index, counting words by Unicode word boundaries,
+    /// or `None` if the index is out of range.
+    ///
+    /// Words are as yielded by [`unicode_words`](unicode_segmentation::UnicodeSegmentation::unicode_words),
+    /// so whitespace and punctuation between them are not counted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, StrSeqExt};
+    /// let s = "The quick (\"brown\") fox can't jump 32.3 feet, right?";
+    /// assert_eq!(s.word_at_seq(idx!(^3)), Some("32.3"));
+    /// assert_eq!(s.word_at_seq(idx!(4)), Some("can't"));
+    /// ```
+    #[cfg(feature = "unicode")]
+    fn word_at_seq(&self, index: SeqIndex<usize>) -> Option<&str>;
+
+    /// The words within the given range, counting words by Unicode word boundaries,
+    /// or `None` if the range does not fit.
+    ///
+    /// The slice runs from the start of the first word in range to the end of the last,
+    /// so it keeps any whitespace and punctuation between them, but none around them.
+    /// An empty range slices an empty string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, StrSeqExt};
+    /// let s = "  Hello, wide world!";
+    /// assert_eq!(s.slice_words(idx!(^2..)), Some("wide world"));
+    /// assert_eq!(s.slice_words(idx!(..2)), Some("Hello, wide"));
+    /// assert_eq!(s.slice_words(idx!(^4..)), None);
+    /// ```
+    #[cfg(feature = "unicode")]
+    fn slice_words(&self, range: impl Into<SeqRange<usize>>) -> Option<&str>;
+}
+
+impl StrSeqExt for str {
+    fn char_at_seq(&self, index: SeqIndex<usize>) -> Option<char> {
+        match index {
+            SeqIndex::FromFront(idx) => self.chars().nth(idx),
+            SeqIndex::FromBack(idx) => self.chars().rev().nth(idx.checked_sub(1)?),
+        }
+    }
+
+    fn split_at_seq(&self, index: SeqIndex<usize>) -> (&str, &str) {
+        self.split_at(index.for_seq_len(self.len()))
     }
-}
 
-impl<T> Index<SeqRange<usize>> for Vec<T> {
-    type Output = [T];
+    fn try_split_at_seq(&self, index: SeqIndex<usize>) -> Option<(&str, &str)> {
+        self.split_at_checked(index.checked_for_seq_len(self.len()).ok()?)
+    }
 
-    fn index(&self, rng: SeqRange<usize>) -> &[T] {
-        let range = rng.for_seq_len(self.len());
-        &self[range]
+    fn split_at_chars_seq(&self, index: SeqIndex<usize>) -> Option<(&str, &str)> {
+        Some(self.split_at(Chars::byte_offset(self, index)?))
     }
-}
 
-impl<T> Index<SeqRangeFrom<usize>> for Vec<T> {
-    type Output = [T];
+    fn rfind_n_seq(&self, delim: &str, n: usize) -> Option<SeqIndex<usize>> {
+        let (position, _) = self.rmatch_indices(delim).nth(n.checked_sub(1)?)?;
+        Some(SeqIndex::FromBack(self.len() - position))
+    }
 
-    fn index(&self, rng: SeqRangeFrom<usize>) -> &[T] {
-        let range = rng.for_seq_len(self.len());
-        &self[range]
+    fn rsplit_n_seq(&self, delim: &str, n: usize) -> Option<(&str, &str)> {
+        let position = self.rfind_n_seq(delim, n)?.checked_for_seq_len(self.len()).ok()?;
+        Some((&self[..position], &self[position + delim.len()..]))
     }
-}
 
-impl<T> Index<SeqRangeInclusive<usize>> for Vec<T> {
-    type Output = [T];
+    fn strip_back(&self, n: usize) -> Option<&str> {
+        self.try_split_at_seq(SeqIndex::FromBack(n)).map(|(rest, _)| rest)
+    }
 
-    fn index(&self, rng: SeqRangeInclusive<usize>) -> &[T] {
-        let range = rng.for_seq_len(self.len());
-        &self[range]
+    fn strip_front(&self, n: usize) -> Option<&str> {
+        self.try_split_at_seq(SeqIndex::FromFront(n)).map(|(_, rest)| rest)
     }
-}
 
-// ===
-// Slice impls
+    fn strip_back_chars(&self, n: usize) -> Option<&str> {
+        self.split_at_chars_seq(SeqIndex::FromBack(n)).map(|(rest, _)| rest)
+    }
 
-impl<T> Index<SeqIndex<usize>> for [T] {
-    type Output = T;
+    fn strip_front_chars(&self, n: usize) -> Option<&str> {
+        self.split_at_chars_seq(SeqIndex::FromFront(n)).map(|(_, rest)| rest)
+    }
 
-    fn index(&self, rng: SeqIndex<usize>) -> &T {
-        let range = rng.for_seq_len(self.len());
-        &self[range]
+    fn tail_lines(&self, n: usize) -> &str {
+        &self[Lines::byte_offset(self, SeqIndex::FromBack(n)).unwrap_or(0)..]
     }
-}
 
-impl<T> Index<SeqRange<usize>> for [T] {
-    type Output = [T];
+    fn head_lines(&self, n: usize) -> &str {
+        &self[..Lines::byte_offset(self, SeqIndex::FromFront(n)).unwrap_or(self.len())]
+    }
 
-    fn index(&self, rng: SeqRange<usize>) -> &[T] {
-        let range = rng.for_seq_len(self.len());
-        &self[range]
+    fn slice_lines(&self, range: impl Into<SeqRange<usize>>) -> Option<&str> {
+        self.get(Lines::range(range.into()).byte_range(self)?)
     }
-}
 
-impl<T> Index<SeqRangeFrom<usize>> for [T] {
-    type Output = [T];
+    #[cfg(feature = "unicode")]
+    fn word_at_seq(&self, index: SeqIndex<usize>) -> Option<&str> {
+        use unicode_segmentation::UnicodeSegmentation;
 
-    fn index(&self, rng: SeqRangeFrom<usize>) -> &[T] {
-        let range = rng.for_seq_len(self.len());
-        &self[range]
+        match index {
+            SeqIndex::FromFront(idx) => self.unicode_words().nth(idx),
+            SeqIndex::FromBack(idx) => self.unicode_words().rev().nth(idx.checked_sub(1)?),
+        }
     }
-}
 
-impl<T> Index<SeqRangeInclusive<usize>> for [T] {
-    type Output = [T];
+    #[cfg(feature = "unicode")]
+    fn slice_words(&self, range: impl Into<SeqRange<usize>>) -> Option<&str> {
+        use unicode_segmentation::UnicodeSegmentation;
 
-    fn index(&self, rng: SeqRangeInclusive<usize>) -> &[T] {
-        let range = rng.for_seq_len(self.len());
-        &self[range]
+        let words: Vec<_> = self
+            .unicode_word_indices()
+            .map(|(offset, word)| offset..offset + word.len())
+            .collect();
+        let range = range.into().checked_for_seq_len(words.len()).ok()?;
+        match words.get(range.clone()) {
+            Some([first, .., last]) => Some(&self[first.start..last.end]),
+            Some([word]) => Some(&self[word.clone()]),
+            _ => {
+                let offset = words.get(range.start).map_or(self.len(), |word| word.start);
+                Some(&self[offset..offset])
+            }
+        }
     }
 }
 
 // ===
-// str impls
-
-impl Index<SeqRange<usize>> for str {
-    type Output = str;
+// Utilities
 
-    fn index(&self, rng: SeqRange<usize>) -> &str {
-        let range = rng.for_seq_len(self.len());
-        &self[range]
-    }
+/// Compare the contents of a range of `a` against a range of `b`.
+///
+/// Both ranges are resolved against their own slice. Slices of
+/// different lengths compare unequal without inspecting any elements.
+///
+/// # Panics
+///
+/// Panics if either range does not fit its slice.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{eq_range, idx};
+/// let a = [1, 2, 3, 4];
+/// let b = [3, 4, 5];
+/// // the tail of `a` matches the head of `b`
+/// assert!(eq_range(&a, idx!(^2..^0), &b, idx!(..2)));
+/// assert!(!eq_range(&a, idx!(^3..^0), &b, idx!(..2)));
+/// ```
+#[cfg_attr(feature = "no-panic", deprecated(note = "panics on an index which does not fit"))]
+pub fn eq_range<T>(a: &[T], ra: SeqRange<usize>, b: &[T], rb: SeqRange<usize>) -> bool
+where T: PartialEq {
+    a[ra] == b[rb]
 }
 
-impl Index<SeqRangeFrom<usize>> for str {
-    type Output = str;
+/// Compare the contents of two ranges of the same slice.
+///
+/// See [`eq_range`].
+///
+/// # Panics
+///
+/// Panics if either range does not fit the slice.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{eq_range_in, idx};
+/// let vec = vec![1, 2, 0, 1, 2];
+/// assert!(eq_range_in(&vec, idx!(..2), idx!(^2..^0)));
+/// ```
+#[cfg_attr(feature = "no-panic", deprecated(note = "panics on an index which does not fit"))]
+pub fn eq_range_in<T>(slice: &[T], ra: SeqRange<usize>, rb: SeqRange<usize>) -> bool
+where T: PartialEq {
+    eq_range(slice, ra, slice, rb)
+}
 
-    fn index(&self, rng: SeqRangeFrom<usize>) -> &str {
-        let range = rng.for_seq_len(self.len());
-        &self[range]
+/// Locate a subslice borrowed from `parent`, as a range of `parent`.
+///
+/// Each bound of the range is expressed from whichever end of `parent` it is nearer to,
+/// preferring the front on a tie, so a subslice which reaches the end of `parent`
+/// ends at `^0`. Returns `None` if `sub` does not lie within `parent`,
+/// or if `T` is zero-sized, since any position would then be ambiguous.
+///
+/// For strings, pass each string's [`as_bytes`](str::as_bytes).
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, subslice_offset_seq};
+/// let line = "GET /index.html HTTP/1.1";
+/// let version = line.rsplit(' ').next().unwrap();
+/// assert_eq!(subslice_offset_seq(line.as_bytes(), version.as_bytes()), Some(idx!(^8..^0)));
+/// let method = line.split(' ').next().unwrap();
+/// assert_eq!(subslice_offset_seq(line.as_bytes(), method.as_bytes()), Some(idx!(0..3)));
+/// ```
+pub fn subslice_offset_seq<T>(parent: &[T], sub: &[T]) -> Option<SeqRange<usize>> {
+    let size = std::mem::size_of::<T>();
+    if size == 0 {
+        return None;
+    }
+    let offset = (sub.as_ptr() as usize).checked_sub(parent.as_ptr() as usize)?;
+    if !offset.is_multiple_of(size) {
+        return None;
     }
+    let start = offset / size;
+    let end = start.checked_add(sub.len()).filter(|&end| end <= parent.len())?;
+    let nearer = |position: usize| match parent.len() - position {
+        from_back if from_back < position => SeqIndex::FromBack(from_back),
+        _ => SeqIndex::FromFront(position),
+    };
+    Some(SeqRange { start: nearer(start), end: nearer(end) })
 }
 
-impl Index<SeqRangeInclusive<usize>> for str {
-    type Output = str;
+/// Slice each row of a jagged table by the same range,
+/// resolving it against each row's own length.
+///
+/// Yields `None` for rows which the range does not fit.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, slice_rows};
+/// let rows = vec![vec![1, 2, 3, 4], vec![5, 6, 7], vec![8]];
+/// let tails: Vec<_> = slice_rows(&rows, idx!(^2..^0)).collect();
+/// assert_eq!(tails, [Some(&[3, 4][..]), Some(&[6, 7][..]), None]);
+/// ```
+pub fn slice_rows<'a, T, V>(rows: &'a [V], range: SeqRange<usize>) -> impl Iterator<Item = Option<&'a [T]>> + 'a
+where T: 'a, V: AsRef<[T]> {
+    rows.iter().map(move |row| {
+        let row = row.as_ref();
+        range.checked_for_seq_len(row.len()).ok().map(|range| &row[range])
+    })
+}
 
-    fn index(&self, rng: SeqRangeInclusive<usize>) -> &str {
-        let range = rng.for_seq_len(self.len());
-        &self[range]
-    }
+/// Slice each row of a jagged table by its own range,
+/// resolving each range against its row's length.
+///
+/// Rows and ranges are paired up in order, stopping at the shorter of the two.
+/// Yields `None` for rows which their range does not fit.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, slice_rows_each};
+/// let rows = vec![vec![1, 2, 3, 4], vec![5, 6, 7]];
+/// let sliced: Vec<_> = slice_rows_each(&rows, &[idx!(1..^1), idx!(^1..^0)]).collect();
+/// assert_eq!(sliced, [Some(&[2, 3][..]), Some(&[7][..])]);
+/// ```
+pub fn slice_rows_each<'a, T, V>(
+    rows: &'a [V],
+    ranges: &'a [SeqRange<usize>],
+) -> impl Iterator<Item = Option<&'a [T]>> + 'a
+where T: 'a, V: AsRef<[T]> {
+    rows.iter().zip(ranges).map(|(row, range)| {
+        let row = row.as_ref();
+        range.checked_for_seq_len(row.len()).ok().map(|range| &row[range])
+    })
 }
 
-// ===
-// String impls
+/// Run `f` on the given range of `slice`, returning its result.
+///
+/// A non-panicking alternative to indexing, for brief mutable access to a window of the slice.
+///
+/// # Errors
+///
+/// Returns an error, without running `f`, if the range does not fit the slice.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, with_range_mut};
+/// let mut vec = vec![8, 6, 7, 5, 3, 0, 9];
+/// let max = with_range_mut(&mut vec, idx!(^4..), |window| {
+///     window.sort();
+///     window[window.len() - 1]
+/// });
+/// assert_eq!(max, Ok(9));
+/// assert_eq!(vec, [8, 6, 7, 0, 3, 5, 9]);
+/// assert!(with_range_mut(&mut vec, idx!(^8..), |_| ()).is_err());
+/// ```
+pub fn with_range_mut<T, R, F>(slice: &mut [T], range: impl Into<SeqRange<usize>>, f: F) -> Result<R, SeqRangeError>
+where F: FnOnce(&mut [T]) -> R {
+    let range = range.into().checked_for_seq_len(slice.len())?;
+    Ok(f(&mut slice[range]))
+}
 
-impl Index<SeqRange<usize>> for String {
-    type Output = str;
+/// Borrow the given ranges of a slice mutably at once, in the order given.
+///
+/// Empty ranges may fall anywhere, but other ranges must not overlap.
+fn disjoint_mut<'a, T>(slice: &'a mut [T], ranges: &[SeqRange<usize>]) -> Result<Vec<&'a mut [T]>, SeqRangeError> {
+    let mut resolved = ranges
+        .iter()
+        .map(|range| range.checked_for_seq_len(slice.len()))
+        .enumerate()
+        .filter(|(_, range)| range.as_ref().map_or(true, |range| !range.is_empty()))
+        .map(|(i, range)| range.map(|range| (i, range)))
+        .collect::<Result<Vec<_>, _>>()?;
+    resolved.sort_by_key(|(_, range)| range.start);
+    if let Some(pair) = resolved.windows(2).find(|pair| pair[0].1.end > pair[1].1.start) {
+        return Err(SeqRangeError::Overlapping { index: pair[1].1.start });
+    }
 
-    fn index(&self, rng: SeqRange<usize>) -> &str {
-        let range = rng.for_seq_len(self.len());
-        &self[range]
+    let mut parts: Vec<&mut [T]> = ranges.iter().map(|_| Default::default()).collect();
+    let (mut rest, mut offset) = (slice, 0);
+    for (i, range) in resolved {
+        let (_, tail) = std::mem::take(&mut rest).split_at_mut(range.start - offset);
+        let (part, tail) = tail.split_at_mut(range.len());
+        parts[i] = part;
+        (rest, offset) = (tail, range.end);
     }
+    Ok(parts)
 }
 
-impl Index<SeqRangeFrom<usize>> for String {
-    type Output = str;
+/// Run a closure on each of the given ranges of a slice in parallel, on scoped threads,
+/// and collect the results in the order of the ranges.
+///
+/// The closure is passed the position of the range within `ranges`, and the range of the slice.
+/// Empty ranges may fall anywhere, but other ranges must not overlap.
+/// Returns an error, without running the closure, if any range does not fit the slice
+/// or if the ranges overlap.
+///
+/// # Panics
+///
+/// If the closure panics on any thread, the panic is propagated once every thread has finished.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, scoped_ranges_mut, SeqRangeError};
+/// let mut samples = vec![8, 6, 7, 5, 3, 0, 9];
+/// let sums = scoped_ranges_mut(&mut samples, &[idx!(..3), idx!(^4..^0)], |_, part| {
+///     part.sort();
+///     part.iter().sum::<i32>()
+/// });
+/// assert_eq!(sums, Ok(vec![21, 17]));
+/// assert_eq!(samples, [6, 7, 8, 0, 3, 5, 9]);
+///
+/// let overlapping = scoped_ranges_mut(&mut samples, &[idx!(..4), idx!(^4..^0)], |_, _| ());
+/// assert_eq!(overlapping, Err(SeqRangeError::Overlapping { index: 3 }));
+/// ```
+pub fn scoped_ranges_mut<T, R, F>(slice: &mut [T], ranges: &[SeqRange<usize>], f: F) -> Result<Vec<R>, SeqRangeError>
+where
+    T: Send,
+    R: Send,
+    F: Fn(usize, &mut [T]) -> R + Sync,
+{
+    let parts = disjoint_mut(slice, ranges)?;
+    let f = &f;
+    Ok(std::thread::scope(|scope| {
+        let handles: Vec<_> = parts
+            .into_iter()
+            .enumerate()
+            .map(|(i, part)| scope.spawn(move || f(i, part)))
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic)))
+            .collect()
+    }))
+}
 
-    fn index(&self, rng: SeqRangeFrom<usize>) -> &str {
-        let range = rng.for_seq_len(self.len());
-        &self[range]
-    }
+// ===
+// Constructors
+//
+// Macro-free alternatives to `idx!`, for use in closures and iterator chains.
+
+/// An index counting from the front of a sequence (`idx!(idx)`).
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{front, idx};
+/// assert_eq!(front(3), idx!(3));
+/// ```
+pub const fn front<Idx>(idx: Idx) -> SeqIndex<Idx> {
+    SeqIndex::FromFront(idx)
 }
 
-impl Index<SeqRangeInclusive<usize>> for String {
-    type Output = str;
+/// An index counting from the back of a sequence (`idx!(^idx)`).
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::back;
+/// let vec = vec![8, 6, 7, 5, 3, 0, 9];
+/// let lasts: Vec<_> = (1..=3).map(|n| vec[back(n)]).collect();
+/// assert_eq!(lasts, [9, 0, 3]);
+/// ```
+pub const fn back<Idx>(idx: Idx) -> SeqIndex<Idx> {
+    SeqIndex::FromBack(idx)
+}
 
-    fn index(&self, rng: SeqRangeInclusive<usize>) -> &str {
-        let range = rng.for_seq_len(self.len());
-        &self[range]
-    }
+/// A range whose bounds both count from the back of a sequence (`idx!(^start..^end)`).
+///
+/// Note that the start of a non-empty range is further from the back than its end,
+/// so `range.start >= range.end`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{back_range, idx};
+/// let vec = vec![8, 6, 7, 5, 3, 0, 9];
+/// assert_eq!(back_range(3..1), idx!(^3..^1));
+/// assert_eq!(&vec[back_range(3..1)], &[3, 0]);
+/// ```
+pub fn back_range<Idx>(range: Range<Idx>) -> SeqRange<Idx> {
+    SeqRange { start: SeqIndex::FromBack(range.start), end: SeqIndex::FromBack(range.end) }
+}
+
+/// A range from the given index to the end of a sequence, counting from the back (`idx!(^start..)`).
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{back_range_from, idx};
+/// let vec = vec![8, 6, 7, 5, 3, 0, 9];
+/// assert_eq!(back_range_from(2), idx!(^2..));
+/// assert_eq!(&vec[back_range_from(2)], &[0, 9]);
+/// ```
+pub const fn back_range_from<Idx>(start: Idx) -> SeqRangeFrom<Idx> {
+    SeqRangeFrom { start: SeqIndex::FromBack(start) }
 }
 
 // ===
@@ -453,6 +3140,392 @@ macro_rules! idx {
     ( $x:expr ) => { $crate::SeqIndex::FromFront($x) };
 }
 
+/// Index through several levels of a nested structure in one expression.
+///
+/// Each comma-separated step is passed to [`idx!`], and applied in order,
+/// so `idx_path!(data => ^1, 2..^3)` expands to `data[idx!(^1)][idx!(2..^3)]`.
+/// Steps may mix element and range indices.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::idx_path;
+/// let data = vec![
+///     vec![String::from("header"), String::from("x")],
+///     vec![String::from("name"), String::from("value:42;")],
+/// ];
+/// assert_eq!(&idx_path!(data => ^1, ^1, 6..^1), "42");
+/// assert_eq!(idx_path!(data => 0, 0).len(), 6);
+/// ```
+#[macro_export]
+macro_rules! idx_path {
+    ( $data:expr => $($steps:tt)+ ) => {
+        $crate::idx_path!(@step ($data) [] $($steps)+)
+    };
+    ( @step ($acc:expr) [$($step:tt)+] , $($rest:tt)+ ) => {
+        $crate::idx_path!(@step ($acc[$crate::idx!($($step)+)]) [] $($rest)+)
+    };
+    ( @step ($acc:expr) [$($step:tt)+] $(,)? ) => {
+        $acc[$crate::idx!($($step)+)]
+    };
+    ( @step ($acc:expr) [$($step:tt)*] $next:tt $($rest:tt)* ) => {
+        $crate::idx_path!(@step ($acc) [$($step)* $next] $($rest)*)
+    };
+}
+
+/// Assert that a range of a sequence equals the expected elements.
+///
+/// Written as `assert_slice_eq!(seq; range, expected)`, where `seq` may be any expression
+/// which slices with `[..]`. On failure, the panic message includes the range as written, the range it resolved to,
+/// the length of the sequence, and the positions at which the elements differ.
+/// The range may be any of the library's range structs.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{assert_slice_eq, idx};
+/// let vec = vec![8, 6, 7, 5, 3, 0, 9];
+/// assert_slice_eq!(vec; idx!(2..^3), [7, 5]);
+/// assert_slice_eq!(vec; idx!(^2..), vec![0, 9]);
+/// ```
+///
+/// ```rust,should_panic
+/// # use from_back::{assert_slice_eq, idx};
+/// let vec = vec![8, 6, 7, 5, 3, 0, 9];
+/// // assertion `left == right` failed: vec[idx!(2..^3)]
+/// //     range: SeqRange { start: FromFront(2), end: FromBack(3) }
+/// //  resolved: 2..4 (sequence length 7)
+/// //      left: [7, 5]
+/// //     right: [7, 3]
+/// //      diff:
+/// //            at 1 (index 3): 5 != 3
+/// assert_slice_eq!(vec; idx!(2..^3), [7, 3]);
+/// ```
+#[macro_export]
+macro_rules! assert_slice_eq {
+    ( $seq:expr; $range:expr, $expected:expr $(,)? ) => {
+        $crate::__assert_slice_eq(
+            &$seq[..],
+            $range,
+            ::core::concat!(::core::stringify!($seq), "[", ::core::stringify!($range), "]"),
+            &$expected[..],
+        )
+    };
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn __assert_slice_eq<T, U, R>(seq: &[T], range: R, expr: &str, expected: &[U])
+where
+    T: PartialEq<U> + std::fmt::Debug,
+    U: std::fmt::Debug,
+    R: Copy + std::fmt::Debug,
+    Range<usize>: TryFrom<WithLen<R>, Error = SeqRangeError>,
+{
+    use std::fmt::Write;
+
+    let len = seq.len();
+    let resolved = match Range::try_from(WithLen(range, len)) {
+        Ok(resolved) => resolved,
+        Err(err) => panic!("assertion `left == right` failed: {expr}\n    range: {range:?}\n resolved: {err}"),
+    };
+    let actual = &seq[resolved.clone()];
+    if actual == expected {
+        return;
+    }
+    let mut diff = String::new();
+    for i in 0..actual.len().max(expected.len()) {
+        let (left, right) = (actual.get(i), expected.get(i));
+        if matches!((left, right), (Some(left), Some(right)) if left == right) {
+            continue;
+        }
+        let left = left.map_or_else(|| "<missing>".to_owned(), |left| format!("{left:?}"));
+        let right = right.map_or_else(|| "<missing>".to_owned(), |right| format!("{right:?}"));
+        let _ = write!(diff, "\n           at {i} (index {}): {left} != {right}", resolved.start + i);
+    }
+    panic!(
+        "assertion `left == right` failed: {expr}\n    range: {range:?}\n resolved: {resolved:?} (sequence length {len})\n     left: {actual:?}\n    right: {expected:?}\n     diff:{diff}"
+    );
+}
+
+/// Assert at compile time that an index or range fits a sequence of a fixed length.
+///
+/// The index or range is any of the library's structs, as built by [`idx!`],
+/// and is checked with its `fits_seq_len` method in a const context.
+/// This suits ranges applied to fixed-size buffers, such as register maps and packet layouts.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, static_assert_fits};
+/// const PAYLOAD: from_back::SeqRange<usize> = idx!(2..^3);
+/// static_assert_fits!(PAYLOAD, 16);
+/// static_assert_fits!(idx!(^16), 16);
+/// let packet = [0u8; 16];
+/// assert_eq!(packet[PAYLOAD].len(), 11);
+/// ```
+///
+/// ```rust,compile_fail
+/// # use from_back::{idx, static_assert_fits};
+/// static_assert_fits!(idx!(2..^15), 16);
+/// ```
+#[macro_export]
+macro_rules! static_assert_fits {
+    ( $range:expr, $len:expr $(,)? ) => {
+        const _: () = ::core::assert!(
+            $range.fits_seq_len($len),
+            ::core::concat!(::core::stringify!($range), " does not fit a sequence of length ", ::core::stringify!($len)),
+        );
+    };
+}
+
+/// Implement [`Index`] and [`IndexMut`] with the library's structs for a wrapper type,
+/// by forwarding to one of its fields, or for any container which implements [`SeqLen`],
+/// by forwarding to its own native impls.
+///
+/// Each line names the type, optionally with generics as in `<T> Buffer<T>`,
+/// then the field to forward to and the slice type it indexes as.
+/// The field may be any type which coerces to that slice type, such as a [`Vec`],
+/// array, or boxed slice.
+/// The generated impls panic as the library's own [`Index`] impls do.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, impl_seq_index};
+/// struct Samples(Vec<f32>);
+/// struct Frame<T> { header: u8, items: Box<[T]> }
+///
+/// impl_seq_index! {
+///     Samples => 0: [f32];
+///     <T> Frame<T> => items: [T];
+/// }
+///
+/// let mut samples = Samples(vec![0.0, 0.5, 1.0]);
+/// samples[idx!(^1)] = 0.75;
+/// assert_eq!(samples[idx!(^2..)], [0.5, 0.75]);
+/// let frame = Frame { header: 0, items: Box::new([1, 2, 3]) };
+/// assert_eq!(frame[idx!(1..=^1)], [2, 3]);
+/// ```
+///
+/// Containers which do not store their elements in a slice, such as ring buffers and arenas,
+/// can instead name `SeqLen`, then the native impls to forward to, of
+/// `Index<usize>`, `IndexMut<usize>`, `Index<Range<usize>>`, and `IndexMut<Range<usize>>`.
+/// Indices and ranges are resolved against [`SeqLen::seq_len`], and then passed to those impls.
+///
+/// ```rust
+/// # use std::ops::{Index, IndexMut};
+/// # use from_back::{idx, impl_seq_index, SeqLen};
+/// struct Ring<T> { items: Vec<T>, head: usize }
+///
+/// impl<T> SeqLen for Ring<T> {
+///     fn seq_len(&self) -> usize {
+///         self.items.len()
+///     }
+/// }
+///
+/// impl<T> Index<usize> for Ring<T> {
+///     type Output = T;
+///
+///     fn index(&self, index: usize) -> &T {
+///         &self.items[(self.head + index) % self.items.len()]
+///     }
+/// }
+///
+/// impl<T> IndexMut<usize> for Ring<T> {
+///     fn index_mut(&mut self, index: usize) -> &mut T {
+///         let len = self.items.len();
+///         &mut self.items[(self.head + index) % len]
+///     }
+/// }
+///
+/// impl_seq_index! {
+///     <T> Ring<T> => SeqLen: Index<usize>, IndexMut<usize>;
+/// }
+///
+/// let mut ring = Ring { items: vec![3, 4, 0, 1, 2], head: 2 };
+/// assert_eq!(ring[idx!(^1)], 4);
+/// ring[idx!(0)] = 9;
+/// assert_eq!(ring.items, [3, 4, 9, 1, 2]);
+/// ```
+#[macro_export]
+macro_rules! impl_seq_index {
+    ( @impl [$($generics:tt)*] $ty:ty => $field:tt : [$elem:ty] ) => {
+        impl<$($generics)*> ::core::ops::Index<$crate::SeqIndex<usize>> for $ty {
+            type Output = $elem;
+
+            #[track_caller]
+            fn index(&self, index: $crate::SeqIndex<usize>) -> &$elem {
+                let slice: &[$elem] = &self.$field;
+                &slice[index]
+            }
+        }
+
+        impl<$($generics)*> ::core::ops::IndexMut<$crate::SeqIndex<usize>> for $ty {
+            #[track_caller]
+            fn index_mut(&mut self, index: $crate::SeqIndex<usize>) -> &mut $elem {
+                let slice: &mut [$elem] = &mut self.$field;
+                &mut slice[index]
+            }
+        }
+
+        $crate::impl_seq_index!(@range [$($generics)*] $ty => $field : [$elem] $crate::SeqRange<usize>);
+        $crate::impl_seq_index!(@range [$($generics)*] $ty => $field : [$elem] $crate::SeqRangeFrom<usize>);
+        $crate::impl_seq_index!(@range [$($generics)*] $ty => $field : [$elem] $crate::SeqRangeInclusive<usize>);
+    };
+    ( @range [$($generics:tt)*] $ty:ty => $field:tt : [$elem:ty] $range:ty ) => {
+        impl<$($generics)*> ::core::ops::Index<$range> for $ty {
+            type Output = [$elem];
+
+            #[track_caller]
+            fn index(&self, range: $range) -> &[$elem] {
+                let slice: &[$elem] = &self.$field;
+                &slice[range]
+            }
+        }
+
+        impl<$($generics)*> ::core::ops::IndexMut<$range> for $ty {
+            #[track_caller]
+            fn index_mut(&mut self, range: $range) -> &mut [$elem] {
+                let slice: &mut [$elem] = &mut self.$field;
+                &mut slice[range]
+            }
+        }
+    };
+    ( @hooks [$($generics:tt)*] $ty:ty; Index<usize> $($rest:tt)* ) => {
+        impl<$($generics)*> ::core::ops::Index<$crate::SeqIndex<usize>> for $ty {
+            type Output = <$ty as ::core::ops::Index<usize>>::Output;
+
+            #[track_caller]
+            fn index(&self, index: $crate::SeqIndex<usize>) -> &Self::Output {
+                let index: usize = $crate::__resolve_seq(index, $crate::SeqLen::seq_len(self));
+                &self[index]
+            }
+        }
+
+        $crate::impl_seq_index!(@hooks [$($generics)*] $ty; $($rest)*);
+    };
+    ( @hooks [$($generics:tt)*] $ty:ty; IndexMut<usize> $($rest:tt)* ) => {
+        impl<$($generics)*> ::core::ops::IndexMut<$crate::SeqIndex<usize>> for $ty {
+            #[track_caller]
+            fn index_mut(&mut self, index: $crate::SeqIndex<usize>) -> &mut Self::Output {
+                let index: usize = $crate::__resolve_seq(index, $crate::SeqLen::seq_len(self));
+                &mut self[index]
+            }
+        }
+
+        $crate::impl_seq_index!(@hooks [$($generics)*] $ty; $($rest)*);
+    };
+    ( @hooks [$($generics:tt)*] $ty:ty; Index<Range<usize>> $($rest:tt)* ) => {
+        $crate::impl_seq_index!(@hook_range [$($generics)*] $ty; $crate::SeqRange<usize>);
+        $crate::impl_seq_index!(@hook_range [$($generics)*] $ty; $crate::SeqRangeFrom<usize>);
+        $crate::impl_seq_index!(@hook_range [$($generics)*] $ty; $crate::SeqRangeInclusive<usize>);
+        $crate::impl_seq_index!(@hooks [$($generics)*] $ty; $($rest)*);
+    };
+    ( @hooks [$($generics:tt)*] $ty:ty; IndexMut<Range<usize>> $($rest:tt)* ) => {
+        $crate::impl_seq_index!(@hook_range_mut [$($generics)*] $ty; $crate::SeqRange<usize>);
+        $crate::impl_seq_index!(@hook_range_mut [$($generics)*] $ty; $crate::SeqRangeFrom<usize>);
+        $crate::impl_seq_index!(@hook_range_mut [$($generics)*] $ty; $crate::SeqRangeInclusive<usize>);
+        $crate::impl_seq_index!(@hooks [$($generics)*] $ty; $($rest)*);
+    };
+    ( @hooks [$($generics:tt)*] $ty:ty; , $($rest:tt)* ) => {
+        $crate::impl_seq_index!(@hooks [$($generics)*] $ty; $($rest)*);
+    };
+    ( @hooks [$($generics:tt)*] $ty:ty; ; $($rest:tt)* ) => {
+        $crate::impl_seq_index!($($rest)*);
+    };
+    ( @hook_range [$($generics:tt)*] $ty:ty; $range:ty ) => {
+        impl<$($generics)*> ::core::ops::Index<$range> for $ty {
+            type Output = <$ty as ::core::ops::Index<::core::ops::Range<usize>>>::Output;
+
+            #[track_caller]
+            fn index(&self, range: $range) -> &Self::Output {
+                let range: ::core::ops::Range<usize> = $crate::__resolve_seq(range, $crate::SeqLen::seq_len(self));
+                &self[range]
+            }
+        }
+    };
+    ( @hook_range_mut [$($generics:tt)*] $ty:ty; $range:ty ) => {
+        impl<$($generics)*> ::core::ops::IndexMut<$range> for $ty {
+            #[track_caller]
+            fn index_mut(&mut self, range: $range) -> &mut Self::Output {
+                let range: ::core::ops::Range<usize> = $crate::__resolve_seq(range, $crate::SeqLen::seq_len(self));
+                &mut self[range]
+            }
+        }
+    };
+    ( <$($gen:tt $(: $bound:path)?),*> $ty:ty => SeqLen: $($rest:tt)* ) => {
+        $crate::impl_seq_index!(@hooks [$($gen $(: $bound)?),*] $ty; $($rest)*);
+    };
+    ( <$($gen:tt $(: $bound:path)?),*> $ty:ty => $field:tt : [$elem:ty]; $($rest:tt)* ) => {
+        $crate::impl_seq_index!(@impl [$($gen $(: $bound)?),*] $ty => $field : [$elem]);
+        $crate::impl_seq_index!($($rest)*);
+    };
+    ( $ty:ty => SeqLen: $($rest:tt)* ) => {
+        $crate::impl_seq_index!(@hooks [] $ty; $($rest)*);
+    };
+    ( $ty:ty => $field:tt : [$elem:ty]; $($rest:tt)* ) => {
+        $crate::impl_seq_index!(@impl [] $ty => $field : [$elem]);
+        $crate::impl_seq_index!($($rest)*);
+    };
+    () => {};
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn __resolve_seq<S, R>(seq: S, len: usize) -> R
+where R: TryFrom<WithLen<S>, Error = SeqRangeError> {
+    R::try_from(WithLen(seq, len)).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Bind the trailing elements of a sequence, after a prefix of any length.
+///
+/// `tail!(let [.., a, b] = seq else { ... })` binds the last elements of `seq`
+/// by reference, running the `else` block if it is too short. The pattern may bind
+/// the prefix, as in `[rest @ .., a, b]`, and `seq` may be anything which slices with `[..]`.
+///
+/// `tail!(seq => n)` evaluates to an `Option` of a tuple of the prefix and the last `n`
+/// elements, for a literal `n` from 1 to 8, or `None` if `seq` has fewer than `n` elements.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::tail;
+/// let vec = vec![8, 6, 7, 5, 3, 0, 9];
+/// tail!(let [.., a, b] = vec else { unreachable!() });
+/// assert_eq!((a, b), (&0, &9));
+///
+/// let (rest, last) = tail!(vec => 1).unwrap();
+/// assert_eq!((rest.len(), last), (6, &9));
+/// assert_eq!(tail!(vec[..2] => 3), None);
+/// ```
+#[macro_export]
+macro_rules! tail {
+    ( @split ($seq:expr) $($name:ident)+ ) => {
+        match &$seq[..] {
+            [rest @ .., $($name),+] => ::core::option::Option::Some((rest, $($name),+)),
+            _ => ::core::option::Option::None,
+        }
+    };
+    ( @let [$($pat:tt)*] ($($seq:tt)+) else $else:block $(;)? ) => {
+        let [$($pat)*] = &($($seq)+)[..] else $else;
+    };
+    ( @let [$($pat:tt)*] ($($seq:tt)*) $next:tt $($rest:tt)* ) => {
+        $crate::tail!(@let [$($pat)*] ($($seq)* $next) $($rest)*)
+    };
+    ( let [ $($pat:tt)* ] = $($rest:tt)+ ) => {
+        $crate::tail!(@let [$($pat)*] () $($rest)+)
+    };
+    ( $seq:expr => 1 ) => { $crate::tail!(@split ($seq) a) };
+    ( $seq:expr => 2 ) => { $crate::tail!(@split ($seq) a b) };
+    ( $seq:expr => 3 ) => { $crate::tail!(@split ($seq) a b c) };
+    ( $seq:expr => 4 ) => { $crate::tail!(@split ($seq) a b c d) };
+    ( $seq:expr => 5 ) => { $crate::tail!(@split ($seq) a b c d e) };
+    ( $seq:expr => 6 ) => { $crate::tail!(@split ($seq) a b c d e f) };
+    ( $seq:expr => 7 ) => { $crate::tail!(@split ($seq) a b c d e f g) };
+    ( $seq:expr => 8 ) => { $crate::tail!(@split ($seq) a b c d e f g h) };
+}
+
 // ===
 
 #[cfg(test)]
@@ -492,6 +3565,155 @@ mod tests {
         assert!(matches!(idx, SeqIndex::FromFront(5)));
     }
 
+    #[test]
+    fn test_index_arithmetic() {
+        let vec: Vec<_> = (0..10).collect();
+        let mut cursor = idx!(^1);
+        cursor += 2;
+        assert_eq!(vec[cursor], 7);
+        cursor -= 1;
+        assert_eq!(vec[cursor], 8);
+        assert_eq!(vec[cursor + 4], 4);
+        assert_eq!(vec[idx!(3) - 1], 2);
+    }
+
+    #[test]
+    fn test_index_checked_arithmetic() {
+        assert_eq!(idx!(3).checked_sub(3), Some(idx!(0)));
+        assert_eq!(idx!(^3).checked_sub(4), None);
+        assert_eq!(idx!(usize::MAX).checked_add(1), None);
+        assert_eq!(idx!(3).saturating_sub(4), idx!(0));
+        assert_eq!(idx!(^usize::MAX).saturating_add(1), idx!(^usize::MAX));
+    }
+
+    #[test]
+    fn test_distance_to() {
+        assert_eq!(idx!(^0).distance_to(idx!(0), 7), Ok(7));
+        assert_eq!(idx!(^3).distance_to(idx!(4), 7), Ok(0));
+        assert_eq!(idx!(^0).signed_distance_to(idx!(0), 7), Ok(-7));
+        assert_eq!(idx!(0).signed_distance_to(idx!(^0), usize::MAX), Ok(isize::MAX));
+        assert_eq!(idx!(^0).signed_distance_to(idx!(0), usize::MAX), Ok(-isize::MAX));
+        let err = idx!(8).signed_distance_to(idx!(0), 7).unwrap_err();
+        assert_eq!(err, SeqRangeError::OutOfBounds { index: idx!(8), len: 7 });
+    }
+
+    #[test]
+    fn test_resolve_to() {
+        // Overflow a `u8` rather than a `u32`, so the length fits a `usize` on every target.
+        let len = u8::MAX as usize + 2;
+        assert_eq!(idx!(^2).resolve_to::<u8>(len), Ok(u8::MAX));
+        assert_eq!(idx!(^1).resolve_to::<u8>(len), Err(SeqRangeError::Overflow { index: len - 1 }));
+        assert_eq!(idx!(^3).resolve_to::<u32>(2), Err(SeqRangeError::OutOfBounds { index: idx!(^3), len: 2 }));
+        assert_eq!(idx!(0..^2).resolve_to::<u8>(len), Ok(0..u8::MAX));
+        assert_eq!(idx!(0..^1).resolve_to::<u8>(len), Err(SeqRangeError::Overflow { index: len - 1 }));
+        assert_eq!(idx!(1..^0).resolve_to::<u16>(4), Ok(1..4));
+        assert!(idx!(2..1).resolve_to::<u16>(4).is_err());
+    }
+
+    #[test]
+    fn test_saturating_constructors() {
+        assert_eq!(SeqIndex::from_front_saturating(isize::MAX), idx!(isize::MAX as usize));
+        assert_eq!(SeqIndex::from_front_saturating(isize::MIN), idx!(0));
+        assert_eq!(SeqIndex::from_back_saturating(0), idx!(^0));
+        assert_eq!(SeqIndex::from_back_saturating(-1), idx!(^0));
+    }
+
+    #[test]
+    fn test_isize_conversion() {
+        let vec: Vec<_> = (0..10).collect();
+        assert_eq!(vec[SeqIndex::from(-1)], 9);
+        assert_eq!(vec[SeqIndex::from(0)], 0);
+        assert_eq!(SeqIndex::from(isize::MIN), idx!(^isize::MIN.unsigned_abs()));
+        for i in [-10, -1, 0, 1, 10, isize::MIN, isize::MAX] {
+            assert_eq!(SeqIndex::from(i).to_isize(10), Some(i));
+        }
+        assert_eq!(idx!(^0).to_isize(10), Some(10));
+        assert_eq!(idx!(usize::MAX).to_isize(10), None);
+        assert_eq!(idx!(^usize::MAX).to_isize(10), None);
+    }
+
+    #[test]
+    fn test_native_range_conversion() {
+        let vec: Vec<_> = (0..10).collect();
+        let (start, end) = (idx!(^4), SeqIndex::from(-1));
+        assert_eq!(SeqRange::from(start..end), idx!(^4..^1));
+        assert_eq!(vec[SeqRange::from(start..end)], [6, 7, 8]);
+        assert_eq!(vec[SeqRangeFrom::from(start..)], [6, 7, 8, 9]);
+        assert_eq!(vec[SeqRangeInclusive::from(start..=end)], [6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn test_try_for_seq_len() {
+        assert_eq!(idx!(7).try_for_seq_len(7), Ok(7));
+        assert_eq!(idx!(8).try_for_seq_len(7), Err(SeqRangeError::OutOfBounds { index: idx!(8), len: 7 }));
+        assert_eq!(idx!(^0).try_for_seq_len(0), Ok(0));
+        assert_eq!(idx!(^7..^0).try_for_seq_len(7), Ok(0..7));
+        assert_eq!(idx!(1..^8).try_for_seq_len(7), Err(SeqRangeError::OutOfBounds { index: idx!(^8), len: 7 }));
+        assert_eq!(idx!(^3..).try_for_seq_len(7), Ok(4..7));
+        assert!(idx!(^8..).try_for_seq_len(7).is_err());
+        assert_eq!(idx!(0..=^7).try_for_seq_len(7), Ok(0..1));
+        assert_eq!(idx!(1..=0).try_for_seq_len(7), Ok(1..1));
+        let err = idx!(^1..=^1).try_for_seq_len(0).unwrap_err();
+        assert_eq!(err.to_string(), "index ^1 out of range for sequence of length 0");
+    }
+
+    #[test]
+    fn test_try_from_with_len() {
+        assert_eq!(usize::try_from(WithLen(idx!(3), 10)), Ok(3));
+        assert_eq!(usize::try_from(WithLen(idx!(10), 10)), Ok(10));
+        assert_eq!(
+            usize::try_from(WithLen(idx!(11), 10)),
+            Err(SeqRangeError::OutOfBounds { index: idx!(11), len: 10 }),
+        );
+        assert_eq!(Range::try_from(WithLen(idx!(^10..^0), 10)), Ok(0..10));
+        assert_eq!(
+            Range::try_from(WithLen(idx!(^3..3), 10)),
+            Err(SeqRangeError::Inverted { start: 7, end: 3 }),
+        );
+    }
+
+    #[test]
+    fn test_linspace_for() {
+        assert_eq!(idx!(0..^0).linspace_for(11, 3), Ok(vec![0, 5, 10]));
+        assert_eq!(idx!(2..^2).linspace_for(10, 2), Ok(vec![2, 7]));
+        assert_eq!(idx!(2..^2).linspace_for(10, 1), Ok(vec![2]));
+        assert_eq!(idx!(2..^2).linspace_for(10, 0), Ok(vec![]));
+        assert_eq!(idx!(2..^6).linspace_for(10, 4), Ok(vec![2, 2, 3, 3]));
+        assert_eq!(idx!(^2..^2).linspace_for(10, 4), Ok(vec![]));
+        assert!(idx!(^11..^0).linspace_for(10, 4).is_err());
+        assert_eq!(
+            idx!(0..^0).linspace_for(usize::MAX, 3),
+            Ok(vec![0, usize::MAX / 2, usize::MAX - 1]),
+        );
+    }
+
+    #[test]
+    fn test_std_set_operations() {
+        let range = idx!(2..^3);
+        assert_eq!(range.intersect_std(0..4, 10), Ok(2..4));
+        assert_eq!(range.intersect_std(5..20, 10), Ok(5..7));
+        assert_eq!(range.intersect_std(8..9, 10), Ok(8..8));
+        assert_eq!(range.intersect_std(0..1, 10), Ok(2..2));
+        assert!(range.intersect_std(0..1, 4).is_err());
+        assert_eq!(range.overlaps_std(6..7, 10), Ok(true));
+        assert_eq!(range.overlaps_std(4..4, 10), Ok(false));
+        assert_eq!(range.contains_std(3..3, 10), Ok(true));
+        assert_eq!(range.contains_std(9..9, 10), Ok(true));
+        assert_eq!(range.contains_std(1..3, 10), Ok(false));
+        assert_eq!(range.hull_std(8..9, 10), Ok(2..9));
+        assert_eq!(range.hull_std(9..9, 10), Ok(2..7));
+        assert_eq!(idx!(^3..^3).hull_std(0..1, 10), Ok(0..1));
+    }
+
+    #[test]
+    fn test_first_last() {
+        assert_eq!(SeqIndex::FIRST, idx!(0));
+        assert_eq!(SeqIndex::LAST, idx!(^1));
+        let vec: Vec<_> = (0..10).collect();
+        assert_eq!(vec[SeqIndex::FIRST], 0);
+        assert_eq!(vec[SeqIndex::LAST], 9);
+    }
+
     #[test]
     fn test_range_macro() {
         let idx = idx!(2..^3);
@@ -539,6 +3761,264 @@ mod tests {
         assert_eq!(&s[idx!(1..^2)], "ang");
     }
 
+    #[test]
+    fn test_smart_pointers() {
+        let boxed: Box<[_]> = (0..10).collect();
+        assert_eq!(boxed[idx!(^1)], 9);
+        assert_eq!(boxed[idx!(2..^3)], [2, 3, 4, 5, 6]);
+
+        let mutex = std::sync::Mutex::new((0..10).collect::<Vec<_>>());
+        let guard = mutex.lock().unwrap();
+        assert_eq!(guard[idx!(^3..)], [7, 8, 9]);
+
+        let cell = std::cell::RefCell::new("ranges".to_string());
+        assert_eq!(&cell.borrow()[idx!(1..^2)], "ang");
+
+        let cow = std::borrow::Cow::Borrowed("ranges");
+        assert_eq!(&cow[idx!(1..=^3)], "ang");
+
+        let boxed: Box<str> = "ranges".into();
+        assert_eq!(&boxed[idx!(^3..)], "ges");
+        let rc: std::rc::Rc<str> = "ranges".into();
+        assert_eq!(&rc[idx!(1..^2)], "ang");
+        let arc: std::sync::Arc<str> = "ranges".into();
+        assert_eq!(&arc[idx!(..=^4)], "ran");
+        assert_eq!(arc.char_at_seq(idx!(^1)), Some('s'));
+    }
+
+    #[test]
+    fn test_index_mut() {
+        let mut vec = vec![8, 6, 7, 5, 3, 0, 9];
+        vec[idx!(^2)] = 5;
+        vec[idx!(1..^1)].sort();
+        assert_eq!(vec, [8, 3, 5, 5, 6, 7, 9]);
+        vec[idx!(^2..)].fill(0);
+        vec.as_mut_slice()[idx!(..=^6)].reverse();
+        assert_eq!(vec, [3, 8, 5, 5, 6, 0, 0]);
+
+        let mut s = String::from("from back");
+        s[idx!(^4..)].make_ascii_uppercase();
+        s.as_mut_str()[idx!(1..^5)].make_ascii_uppercase();
+        s[idx!(..=^9)].make_ascii_lowercase();
+        assert_eq!(s, "fROM BACK");
+
+        let mut boxed: Box<[_]> = Box::new([1, 2, 3]);
+        boxed[idx!(^1)] = 4;
+        let cell = std::cell::RefCell::new(boxed.into_vec());
+        cell.borrow_mut()[idx!(^2..)].fill(0);
+        assert_eq!(*cell.borrow(), [1, 0, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index ^4 out of range for sequence of length 3")]
+    fn test_index_mut_out_of_bounds() {
+        let mut vec: Vec<_> = (1..4).collect();
+        vec[idx!(^4)] = 0;
+    }
+
+    #[test]
+    #[cfg(feature = "generic-array")]
+    fn test_generic_array() {
+        use generic_array::{typenum::U8, GenericArray};
+
+        let digest = GenericArray::<u8, U8>::from([8, 6, 7, 5, 3, 0, 9, 1]);
+        assert_eq!(digest[idx!(^1)], 1);
+        assert_eq!(digest[idx!(^4..)], [3, 0, 9, 1]);
+        assert_eq!(digest[idx!(..^4)], [8, 6, 7, 5]);
+        assert_eq!(digest[idx!(^2..=^1)], [9, 1]);
+    }
+
+    #[test]
+    #[cfg(feature = "compact_str")]
+    fn test_compact_str() {
+        let ident = compact_str::CompactString::from("parse_expr");
+        assert_eq!(&ident[idx!(^4..)], "expr");
+        assert_eq!(&ident[idx!(..^5)], "parse");
+        assert_eq!(&ident[idx!(1..=^6)], "arse");
+    }
+
+    #[test]
+    #[cfg(feature = "smol_str")]
+    fn test_smol_str() {
+        let ident = smol_str::SmolStr::new("parse_expr");
+        assert_eq!(&ident[idx!(^4..)], "expr");
+        assert_eq!(&ident[idx!(..^5)], "parse");
+        assert_eq!(&ident[idx!(1..=^6)], "arse");
+    }
+
+    #[test]
+    #[cfg(feature = "tracing")]
+    fn test_tracing() {
+        use std::fmt::Write;
+        use std::sync::{Arc, Mutex};
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        struct Fields(String);
+
+        impl Visit for Fields {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                write!(self.0, " {}={value:?}", field.name()).unwrap();
+            }
+        }
+
+        struct Collect(Arc<Mutex<Vec<String>>>);
+
+        impl Subscriber for Collect {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+            fn new_span(&self, _: &Attributes<'_>) -> Id {
+                Id::from_u64(1)
+            }
+            fn record(&self, _: &Id, _: &Record<'_>) {}
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+            fn event(&self, event: &Event<'_>) {
+                let mut fields = Fields(String::new());
+                event.record(&mut fields);
+                self.0.lock().unwrap().push(fields.0);
+            }
+            fn enter(&self, _: &Id) {}
+            fn exit(&self, _: &Id) {}
+        }
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let line = line!();
+        tracing::subscriber::with_default(Collect(events.clone()), || {
+            let slice: &[_] = &[8, 6, 7];
+            assert_eq!(slice[idx!(^1)], 7);
+            assert_eq!(slice[idx!(1..=^1)], [6, 7]);
+            assert!(Range::try_from(WithLen(idx!(^4..), 3)).is_err());
+        });
+        // Drop the column, leaving the caller's file and line.
+        let events: Vec<_> = events.lock().unwrap().iter().map(|event| event.rsplit_once(':').unwrap().0.to_owned()).collect();
+        let file = file!();
+        assert_eq!(events, [
+            format!(" message=resolved seq=FromBack(1) len=3 result=Ok(2) location={file}:{}", line + 3),
+            format!(
+                " message=resolved seq=SeqRangeInclusive {{ start: FromFront(1), end: FromBack(1) }} len=3 \
+                 result=Ok(1..3) location={file}:{}",
+                line + 4,
+            ),
+            format!(
+                " message=resolved seq=SeqRangeFrom {{ start: FromBack(4) }} len=3 \
+                 result=Err(OutOfBounds {{ index: FromBack(4), len: 3 }}) location={file}:{}",
+                line + 5,
+            ),
+        ]);
+    }
+
+    #[test]
+    #[cfg(feature = "allocator_api")]
+    fn test_vec_with_allocator() {
+        let mut vec = Vec::new_in(std::alloc::System);
+        vec.extend([8, 6, 7, 5, 3, 0, 9]);
+        assert_eq!(vec[idx!(^2)], 0);
+        assert_eq!(vec[idx!(2..^3)], [7, 5]);
+        assert_eq!(vec[idx!(^2..)], [0, 9]);
+        assert_eq!(vec[idx!(2..=^3)], [7, 5, 3]);
+        vec[idx!(^2..)].fill(0);
+        assert_eq!(vec[idx!(^3..)], [3, 0, 0]);
+    }
+
+    #[test]
+    fn test_split_at_seq() {
+        let s = "añb€";
+        assert_eq!(s.split_at_seq(idx!(^3)), ("añb", "€"));
+        assert_eq!(s.split_at_seq(idx!(^0)), (s, ""));
+        assert_eq!(s.try_split_at_seq(idx!(^7)), Some(("", s)));
+        assert_eq!(s.try_split_at_seq(idx!(^8)), None);
+        assert_eq!(s.try_split_at_seq(idx!(^2)), None);
+        assert_eq!(s.try_split_at_seq(idx!(9)), None);
+        assert_eq!(s.split_at_chars_seq(idx!(^1)), Some(("añb", "€")));
+        assert_eq!(s.split_at_chars_seq(idx!(4)), Some((s, "")));
+        assert_eq!(s.split_at_chars_seq(idx!(^5)), None);
+    }
+
+    #[test]
+    fn test_rsplit_n_seq() {
+        assert_eq!("a,b,,c".rsplit_n_seq(",", 1), Some(("a,b,", "c")));
+        assert_eq!("a,b,,c".rsplit_n_seq(",", 2), Some(("a,b", ",c")));
+        assert_eq!("a,b,,c".rsplit_n_seq(",", 3), Some(("a", "b,,c")));
+        assert_eq!("a,b,,c".rsplit_n_seq(",", 4), None);
+        assert_eq!("a,b,,c".rsplit_n_seq(",", 0), None);
+        assert_eq!("añb".rsplit_n_seq("ñ", 1), Some(("a", "b")));
+        assert_eq!("aaa".rfind_n_seq("aa", 1), Some(idx!(^2)));
+        assert_eq!("aaa".rfind_n_seq("aa", 2), None);
+        let nums = [1, 0, 2, 0];
+        assert_eq!(nums.rsplit_n_seq(&0, 1), Some((&nums[..3], &[][..])));
+        assert_eq!(nums.rsplit_n_seq(&0, 2), Some((&nums[..1], &nums[2..])));
+        assert_eq!(nums.rsplit_n_seq(&0, 3), None);
+        assert_eq!(nums.rsplit_n_seq(&0, 0), None);
+        assert_eq!(nums.rfind_n_seq(&1, 1), Some(idx!(^4)));
+    }
+
+    #[test]
+    fn test_strip() {
+        let s = "añb€";
+        assert_eq!(s.strip_back(3), Some("añb"));
+        assert_eq!(s.strip_back(1), None);
+        assert_eq!(s.strip_back(7), Some(""));
+        assert_eq!(s.strip_back(8), None);
+        assert_eq!(s.strip_front(3), Some("b€"));
+        assert_eq!(s.strip_front(2), None);
+        assert_eq!(s.strip_back_chars(0), Some(s));
+        assert_eq!(s.strip_back_chars(4), Some(""));
+        assert_eq!(s.strip_back_chars(5), None);
+        assert_eq!(s.strip_front_chars(2), Some("b€"));
+        assert_eq!(s.strip_front_chars(5), None);
+    }
+
+    #[test]
+    fn test_lines_views() {
+        let s = "añb\n€\n\nz";
+        assert_eq!(s.tail_lines(0), "");
+        assert_eq!(s.tail_lines(2), "\nz");
+        assert_eq!(s.tail_lines(4), s);
+        assert_eq!(s.head_lines(0), "");
+        assert_eq!(s.head_lines(2), "añb\n€\n");
+        assert_eq!(s.head_lines(9), s);
+        assert_eq!(s.slice_lines(idx!(1..^1)), Some("€\n\n"));
+        assert_eq!(s.slice_lines(idx!(^1..1)), None);
+        assert_eq!("".tail_lines(1), "");
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_words() {
+        let s = "¿Qué tal? Très bien.";
+        assert_eq!(s.word_at_seq(idx!(0)), Some("Qué"));
+        assert_eq!(s.word_at_seq(idx!(^1)), Some("bien"));
+        assert_eq!(s.word_at_seq(idx!(^0)), None);
+        assert_eq!(s.word_at_seq(idx!(4)), None);
+        assert_eq!(s.slice_words(idx!(1..^1)), Some("tal? Très"));
+        assert_eq!(s.slice_words(idx!(^1..)), Some("bien"));
+        assert_eq!(s.slice_words(idx!(^0..)), Some(""));
+        assert_eq!(s.slice_words(idx!(2..2)), Some(""));
+        assert_eq!(s.slice_words(idx!(^2..1)), None);
+        assert_eq!("".slice_words(idx!(0..)), Some(""));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_split_at_seq_not_char_boundary() {
+        let _ = "añb€".split_at_seq(idx!(^1));
+    }
+
+    #[test]
+    fn test_char_at_seq() {
+        let s = "añb€";
+        assert_eq!(s.char_at_seq(idx!(0)), Some('a'));
+        assert_eq!(s.char_at_seq(idx!(3)), Some('€'));
+        assert_eq!(s.char_at_seq(idx!(4)), None);
+        assert_eq!(s.char_at_seq(SeqIndex::LAST), Some('€'));
+        assert_eq!(s.char_at_seq(idx!(^4)), Some('a'));
+        assert_eq!(s.char_at_seq(idx!(^5)), None);
+        assert_eq!(s.char_at_seq(idx!(^0)), None);
+        assert_eq!("".char_at_seq(SeqIndex::LAST), None);
+    }
+
     #[test]
     fn test_range_to() {
         let vec: Vec<_> = (0..10).collect();
@@ -550,4 +4030,492 @@ mod tests {
         let vec: Vec<_> = (0..10).collect();
         assert_eq!(vec[idx!(..=^3)], [0, 1, 2, 3, 4, 5, 6, 7]);
     }
+
+    #[test]
+    fn test_partition_point_seq() {
+        let slice = [9, 1, 3, 5, 7, 0];
+        assert_eq!(slice.partition_point_seq(idx!(1..^1), |&x| x < 4), 3);
+        assert_eq!(slice.partition_point_seq(idx!(1..^1), |&x| x < 100), 5);
+        assert_eq!(slice.partition_point_seq(idx!(1..^1), |&x| x < 0), 1);
+    }
+
+    #[test]
+    fn test_extract_seq() {
+        let vec: Vec<_> = (0..10).collect();
+        let extracted = vec.extract_seq(&[idx!(..2), idx!(4..^5), idx!(^2..^0)]);
+        assert_eq!(extracted, Ok(vec![0, 1, 4, 8, 9]));
+        assert_eq!(vec.extract_seq(&[]), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_extract_seq_invalid() {
+        let vec: Vec<_> = (0..10).collect();
+        assert_eq!(
+            vec.extract_seq(&[idx!(..2), idx!(^11..^0)]),
+            Err(SeqRangeError::OutOfBounds { index: SeqIndex::FromBack(11), len: 10 }),
+        );
+        assert_eq!(
+            vec.extract_seq(&[idx!(^2..3)]),
+            Err(SeqRangeError::Inverted { start: 8, end: 3 }),
+        );
+        assert!(vec.extract_seq(&[idx!(0..11)]).is_err());
+    }
+
+    #[test]
+    fn test_multi_slice() {
+        let nums = [8, 6, 7, 5, 3, 0, 9];
+        assert_eq!(nums.multi_slice(&[]), Ok(vec![]));
+        let parts = nums.multi_slice(&[idx!(^2..^0), idx!(0..^0), idx!(3..3)]).unwrap();
+        assert_eq!(parts, [&nums[5..], &nums[..], &[]]);
+        let err = nums.multi_slice(&[idx!(0..1), idx!(^1..1), idx!(8..9)]).unwrap_err();
+        assert_eq!(err, SeqRangesError { position: 1, error: SeqRangeError::Inverted { start: 6, end: 1 } });
+        assert_eq!(err.to_string(), "range 1: range starts at index 6 but ends at index 1");
+    }
+
+    #[test]
+    fn test_head_tail() {
+        let vec: Vec<_> = (0..5).collect();
+        assert_eq!(vec.tail(2), Some(&vec[idx!(^2..)]));
+        assert_eq!(vec.head(2), Some(&vec[idx!(..2)]));
+        assert_eq!(vec.without_tail(2), Some(&vec[idx!(..^2)]));
+        assert_eq!(vec.without_head(2), Some(&vec[idx!(2..)]));
+        assert_eq!(vec.tail(5), Some(&vec[..]));
+        assert_eq!(vec.without_tail(5), Some(&[][..]));
+        assert_eq!(vec.tail(6), None);
+        assert_eq!(vec.head(6), None);
+        assert_eq!(vec.without_tail(6), None);
+        assert_eq!(vec.without_head(6), None);
+    }
+
+    #[test]
+    fn test_split_footer_header() {
+        let mut vec = vec![8, 6, 7, 5, 3, 0, 9];
+        assert_eq!(vec.split_footer(0), Some((&vec[..], &[][..])));
+        assert_eq!(vec.split_footer(7), Some((&[][..], &vec[..])));
+        assert_eq!(vec.split_footer(8), None);
+        assert_eq!(vec.split_header(3), Some((&[8, 6, 7][..], &[5, 3, 0, 9][..])));
+        assert_eq!(vec.split_header(8), None);
+        assert!(vec.split_footer_mut(8).is_none());
+        assert!(vec.split_header_mut(8).is_none());
+        vec.split_footer_mut(1).unwrap().1[0] = 1;
+        vec.split_header_mut(1).unwrap().0[0] = 2;
+        assert_eq!(vec, [2, 6, 7, 5, 3, 0, 1]);
+    }
+
+    #[test]
+    fn test_split3_mut_seq() {
+        let mut nums = [8, 6, 7, 5, 3, 0, 9];
+        let (a, b, c) = nums.split3_mut_seq(idx!(2), idx!(^2)).unwrap();
+        assert_eq!((&*a, &*b, &*c), (&[8, 6][..], &[7, 5, 3][..], &[0, 9][..]));
+        let (a, b, c) = nums.split3_mut_seq(idx!(^0), idx!(^0)).unwrap();
+        assert_eq!((a.len(), b.len(), c.len()), (7, 0, 0));
+        assert_eq!(nums.split3_mut_seq(idx!(3), idx!(2)), Err(SeqRangeError::Inverted { start: 3, end: 2 }));
+        assert_eq!(nums.split3_mut_seq(idx!(0), idx!(8)), Err(SeqRangeError::OutOfBounds { index: idx!(8), len: 7 }));
+    }
+
+    #[test]
+    fn test_split_at_many_seq() {
+        let nums = [8, 6, 7, 5, 3, 0, 9];
+        let parts = nums.split_at_many_seq(&[idx!(2), idx!(^2), idx!(^2), idx!(^0)]).unwrap();
+        assert_eq!(parts, [&nums[..2], &nums[2..5], &[], &nums[5..], &[]]);
+        assert_eq!(nums.split_at_many_seq(&[]).unwrap(), [&nums[..]]);
+        let err = nums.split_at_many_seq(&[idx!(^2), idx!(2)]).unwrap_err();
+        assert_eq!(err, SeqRangeError::Inverted { start: 5, end: 2 });
+        let err = nums.split_at_many_seq(&[idx!(^8)]).unwrap_err();
+        assert_eq!(err, SeqRangeError::OutOfBounds { index: idx!(^8), len: 7 });
+
+        let mut nums = nums;
+        for part in nums.split_at_many_seq_mut(&[idx!(^4), idx!(^2)]).unwrap() {
+            part.reverse();
+        }
+        assert_eq!(nums, [7, 6, 8, 3, 5, 9, 0]);
+    }
+
+    #[test]
+    fn test_array_seq() {
+        let mut vec: Vec<_> = (0..6).collect();
+        assert_eq!(vec.array_seq::<2>(idx!(^2)), Some(&[4, 5]));
+        assert_eq!(vec.array_seq::<0>(idx!(^0)), Some(&[]));
+        assert_eq!(vec.array_seq::<3>(idx!(^2)), None);
+        assert_eq!(vec.array_seq::<1>(idx!(^7)), None);
+        assert_eq!(vec.array_seq::<1>(idx!(usize::MAX)), None);
+        vec.array_seq_mut::<2>(idx!(1)).unwrap().swap(0, 1);
+        assert_eq!(vec, [0, 2, 1, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_take_seq() {
+        let vec: Vec<_> = (0..6).collect();
+        let mut cursor = &vec[..];
+        assert_eq!(cursor.take_seq(idx!(1..^1)), None);
+        assert_eq!(cursor.take_seq(idx!(..7)), None);
+        assert_eq!(cursor.take_seq(idx!(^2..^0)), Some(&[4, 5][..]));
+        assert_eq!(cursor.take_seq(idx!(..0)), Some(&[][..]));
+        assert_eq!(cursor.take_seq(idx!(..^1)), Some(&[0, 1, 2][..]));
+        assert_eq!(cursor, [3]);
+        assert_eq!(cursor.take_seq(idx!(0..)), Some(&[3][..]));
+        assert!(cursor.is_empty());
+
+        let mut arr = [0, 1, 2, 3];
+        let mut cursor = &mut arr[..];
+        cursor.take_seq(idx!(^1..)).unwrap()[0] = 9;
+        assert_eq!(cursor.take_seq(idx!(1..2)), None);
+        cursor.take_seq(idx!(..1)).unwrap()[0] = 8;
+        assert_eq!(cursor, [1, 2]);
+        assert_eq!(arr, [8, 1, 2, 9]);
+    }
+
+    #[test]
+    fn test_iter_excluding_seq() {
+        let mut vec: Vec<_> = (0..10).collect();
+        let outside: Vec<_> = vec.iter_excluding_seq(idx!(2..^3)).copied().collect();
+        assert_eq!(outside, [0, 1, 7, 8, 9]);
+        let outside: Vec<_> = vec.iter_excluding_seq(idx!(^0..^0)).copied().collect();
+        assert_eq!(outside, vec);
+        assert_eq!(vec.iter_excluding_seq(idx!(0..^0)).count(), 0);
+
+        vec.iter_excluding_seq_mut(idx!(^9..^1)).for_each(|x| *x *= 10);
+        assert_eq!(vec, [0, 1, 2, 3, 4, 5, 6, 7, 8, 90]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_iter_excluding_seq_inverted() {
+        let vec: Vec<_> = (0..10).collect();
+        let _ = vec.iter_excluding_seq(idx!(^2..3));
+    }
+
+    #[test]
+    fn test_eq_range() {
+        let a: Vec<_> = (0..10).collect();
+        let b: Vec<_> = (7..12).collect();
+        assert!(eq_range(&a, idx!(^3..^0), &b, idx!(..3)));
+        assert!(eq_range(&a, idx!(^3..^3), &b, idx!(^0..^0)));
+        assert!(!eq_range(&a, idx!(^3..^0), &b, idx!(..4)));
+        assert!(!eq_range(&a, idx!(^3..^0), &b, idx!(1..4)));
+        assert!(eq_range_in(&a, idx!(2..4), idx!(^8..^6)));
+    }
+
+    #[test]
+    fn test_nested_index() {
+        let grid = Vec::from([vec![1, 2, 3], vec![], vec![4, 5]]);
+        assert_eq!(grid[Nested(idx!(0), idx!(^1))], 3);
+        assert_eq!(grid[Nested::from((idx!(^1), idx!(^2)))], 4);
+        let rows: [&[i32]; 2] = [&[1, 2, 3], &[4]];
+        assert_eq!(rows[..][Nested(idx!(^2), idx!(^3))], 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_nested_index_empty_row() {
+        let grid = Vec::from([vec![1, 2, 3], vec![], vec![4, 5]]);
+        let _ = grid[Nested(idx!(^2), idx!(^1))];
+    }
+
+    #[test]
+    fn test_idx_path() {
+        let cube = Vec::from([vec![vec![1, 2, 3], vec![4, 5, 6]], vec![vec![7, 8, 9]]]);
+        assert_eq!(idx_path!(cube => ^1, 0, ^1), 9);
+        assert_eq!(idx_path!(cube => 0, ^1, 1..,), [5, 6]);
+        assert_eq!(idx_path!(cube => 0, ..^1, ^1, ..=1), [1, 2]);
+    }
+
+    #[test]
+    fn test_inclusive_edges() {
+        let len = 7;
+        assert_eq!(idx!(2..=^1).checked_for_seq_len(len), Ok(2..7));
+        assert_eq!(idx!(3..=^5).checked_for_seq_len(len), Ok(3..3));
+        assert_eq!(idx!(^0..=^1).checked_for_seq_len(len), Ok(7..7));
+        assert_eq!(
+            idx!(2..=^0).checked_for_seq_len(len),
+            Err(SeqRangeError::OutOfBounds { index: idx!(^0), len }),
+        );
+        assert_eq!(
+            idx!(4..=^5).checked_for_seq_len(len),
+            Err(SeqRangeError::Inverted { start: 4, end: 3 }),
+        );
+        let max = usize::MAX;
+        assert_eq!(
+            idx!(0..=max).checked_for_seq_len(max),
+            Err(SeqRangeError::OutOfBounds { index: idx!(max), len: max }),
+        );
+        assert_eq!(idx!(^max..=^1).checked_for_seq_len(max), Ok(0..max));
+    }
+
+    #[test]
+    #[should_panic = "index ^0 out of range for sequence of length 3"]
+    fn test_inclusive_end_at_len() {
+        let vec = Vec::from([1, 2, 3]);
+        let _ = &vec[idx!(0..=^0)];
+    }
+
+    #[test]
+    #[should_panic = "out of range for sequence of length 3"]
+    fn test_inclusive_end_max() {
+        let max = usize::MAX;
+        let _ = &"abc"[idx!(0..=max)];
+    }
+
+    #[test]
+    #[should_panic = "index ^4 out of range for sequence of length 3"]
+    fn test_from_back_past_front() {
+        idx!(^4).for_seq_len(3);
+    }
+
+    #[test]
+    fn test_constructors() {
+        let vec: Vec<_> = (0..10).collect();
+        let tails: Vec<_> = (0..3).map(|n| &vec[back_range(n + 2..n)]).collect();
+        assert_eq!(tails, [&[8, 9][..], &[7, 8], &[6, 7]]);
+        assert_eq!(vec[SeqRange { start: front(2), end: back(2) }], [2, 3, 4, 5, 6, 7]);
+        assert_eq!(vec[back_range_from(1)], [9]);
+    }
+
+    #[test]
+    fn test_disjoint_mut() {
+        let mut vec = vec![8, 6, 7, 5, 3, 0, 9];
+        let parts = disjoint_mut(&mut vec, &[idx!(^2..^0), idx!(3..3), idx!(1..^4), idx!(0..0)]).unwrap();
+        assert_eq!(parts, [&mut [0, 9][..], &mut [], &mut [6, 7], &mut []]);
+        assert_eq!(
+            disjoint_mut(&mut vec, &[idx!(0..3), idx!(^5..^4)]),
+            Err(SeqRangeError::Overlapping { index: 2 }),
+        );
+        assert_eq!(
+            disjoint_mut(&mut vec, &[idx!(0..3), idx!(^8..^0)]),
+            Err(SeqRangeError::OutOfBounds { index: idx!(^8), len: 7 }),
+        );
+        assert!(disjoint_mut(&mut vec, &[idx!(0..3), idx!(3..^0)]).is_ok());
+        assert!(disjoint_mut(&mut vec, &[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_scoped_ranges_mut() {
+        let mut vec: Vec<_> = (0..100).collect();
+        let ranges: Vec<_> = (0..10).map(|i| SeqRange { start: front(i * 10), end: front(i * 10 + 5) }).collect();
+        let firsts = scoped_ranges_mut(&mut vec, &ranges, |i, part| {
+            part.iter_mut().for_each(|n| *n = i);
+            part.len()
+        });
+        assert_eq!(firsts, Ok(vec![5; 10]));
+        assert_eq!(vec[10..16], [1, 1, 1, 1, 1, 15]);
+    }
+
+    #[test]
+    #[should_panic(expected = "oops")]
+    fn test_scoped_ranges_mut_panic() {
+        let _ = scoped_ranges_mut(&mut [1, 2, 3], &[idx!(..1), idx!(1..^0)], |i, _| assert!(i == 0, "oops"));
+    }
+
+    #[test]
+    fn test_assert_slice_eq() {
+        let nums = [8, 6, 7, 5, 3, 0, 9];
+        assert_slice_eq!(nums; idx!(2..^3), [7, 5]);
+        assert_slice_eq!(nums; idx!(^2..), [0, 9]);
+        assert_slice_eq!(nums; idx!(^2..=^1), [0, 9]);
+        assert_slice_eq!(nums; idx!(^0..^0), [0; 0]);
+        let nested = (nums, vec![1, 2]);
+        assert_slice_eq!(nested.0; idx!(^1..), [9]);
+        assert_slice_eq!(&nested.1[1..]; idx!(..^0), [2]);
+        let result = std::panic::catch_unwind(|| assert_slice_eq!(nums; idx!(^3..), [3, 1]));
+        let msg = *result.unwrap_err().downcast::<String>().unwrap();
+        assert_eq!(
+            msg,
+            "assertion `left == right` failed: nums[idx!(^3..)]
+    range: SeqRangeFrom { start: FromBack(3) }
+ resolved: 4..7 (sequence length 7)
+     left: [3, 0, 9]
+    right: [3, 1]
+     diff:
+           at 1 (index 5): 0 != 1
+           at 2 (index 6): 9 != <missing>",
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "resolved: index ^8 out of range for sequence of length 7")]
+    fn test_assert_slice_eq_out_of_bounds() {
+        let nums = [8, 6, 7, 5, 3, 0, 9];
+        assert_slice_eq!(nums; idx!(^8..), [8]);
+    }
+
+    #[test]
+    fn test_fits_seq_len() {
+        static_assert_fits!(idx!(0..^0), 0);
+        static_assert_fits!(idx!(^0..), 0);
+        static_assert_fits!(idx!(0..=^1), 1);
+        static_assert_fits!(idx!(1..=0), 1);
+        for len in 0..4 {
+            let fits = |range: SeqRangeInclusive<usize>| range.checked_for_seq_len(len).is_ok();
+            for start in 0..5 {
+                for end in 0..5 {
+                    for range in [
+                        SeqRangeInclusive { start: idx!(start), end: idx!(end) },
+                        SeqRangeInclusive { start: idx!(^start), end: idx!(end) },
+                        SeqRangeInclusive { start: idx!(start), end: idx!(^end) },
+                        SeqRangeInclusive { start: idx!(^start), end: idx!(^end) },
+                    ] {
+                        assert_eq!(range.fits_seq_len(len), fits(range));
+                        let exclusive = SeqRange { start: range.start, end: range.end };
+                        assert_eq!(exclusive.fits_seq_len(len), exclusive.checked_for_seq_len(len).is_ok());
+                    }
+                }
+                assert_eq!(idx!(^start).fits_seq_len(len), idx!(^start).checked_for_seq_len(len).is_ok_and(|i| i < len));
+            }
+        }
+    }
+
+    struct Samples(Vec<i32>);
+    struct Frame<T> {
+        items: [T; 4],
+    }
+
+    impl_seq_index! {
+        Samples => 0: [i32];
+        <T> Frame<T> => items: [T];
+    }
+
+    #[test]
+    fn test_impl_seq_index() {
+        let mut samples = Samples(vec![8, 6, 7, 5, 3, 0, 9]);
+        assert_eq!(samples[idx!(^2)], 0);
+        assert_eq!(samples[idx!(2..^3)], [7, 5]);
+        samples[idx!(^2..)].fill(1);
+        samples[idx!(..=1)].fill(2);
+        samples[idx!(0)] = 3;
+        assert_eq!(samples.0, [3, 2, 7, 5, 3, 1, 1]);
+        let frame = Frame { items: ['a', 'b', 'c', 'd'] };
+        assert_eq!(frame[idx!(^1)], 'd');
+        assert_eq!(frame[idx!(^3..^1)], ['b', 'c']);
+    }
+
+    struct Gap {
+        before: Vec<char>,
+        after: Vec<char>,
+    }
+
+    impl SeqLen for Gap {
+        fn seq_len(&self) -> usize {
+            self.before.len() + self.after.len()
+        }
+    }
+
+    impl Index<usize> for Gap {
+        type Output = char;
+
+        fn index(&self, index: usize) -> &char {
+            self.before.get(index).unwrap_or_else(|| &self.after[index - self.before.len()])
+        }
+    }
+
+    // only ranges after the gap are contiguous
+    impl Index<Range<usize>> for Gap {
+        type Output = [char];
+
+        fn index(&self, range: Range<usize>) -> &[char] {
+            let gap = self.before.len();
+            &self.after[range.start - gap..range.end - gap]
+        }
+    }
+
+    impl IndexMut<Range<usize>> for Gap {
+        fn index_mut(&mut self, range: Range<usize>) -> &mut [char] {
+            let gap = self.before.len();
+            &mut self.after[range.start - gap..range.end - gap]
+        }
+    }
+
+    impl_seq_index! {
+        Gap => SeqLen: Index<usize>, Index<Range<usize>>, IndexMut<Range<usize>>;
+    }
+
+    #[test]
+    fn test_impl_seq_index_seq_len() {
+        let mut gap = Gap { before: vec!['a', 'b'], after: vec!['c', 'd', 'e'] };
+        assert_eq!(gap[idx!(^4)], 'b');
+        assert_eq!(gap[idx!(^1)], 'e');
+        assert_eq!(gap[idx!(^3..)], ['c', 'd', 'e']);
+        assert_eq!(gap[idx!(2..=^2)], ['c', 'd']);
+        gap[idx!(^2..^0)].reverse();
+        assert_eq!(gap.after, ['c', 'e', 'd']);
+    }
+
+    #[test]
+    fn test_seq_len() {
+        assert_eq!([1, 2, 3].seq_len(), 3);
+        assert_eq!(vec![1, 2].seq_len(), 2);
+        assert_eq!(std::collections::VecDeque::from([1]).seq_len(), 1);
+        assert_eq!("naïve".seq_len(), 6);
+        assert_eq!(String::new().seq_len(), 0);
+        let iter = "abc".chars().collect::<Vec<_>>().into_iter();
+        assert_eq!(ExactLen(iter.clone()).seq_len(), 3);
+        assert_eq!(idx!(1..^1).for_seq(&ExactLen(iter)), 1..2);
+    }
+
+    #[test]
+    #[should_panic(expected = "index ^6 out of range for sequence of length 5")]
+    fn test_impl_seq_index_seq_len_out_of_bounds() {
+        let gap = Gap { before: vec!['a', 'b'], after: vec!['c', 'd', 'e'] };
+        let _ = gap[idx!(^6)];
+    }
+
+    #[test]
+    #[should_panic(expected = "index ^8 out of range for sequence of length 7")]
+    fn test_impl_seq_index_out_of_bounds() {
+        let samples = Samples(vec![8, 6, 7, 5, 3, 0, 9]);
+        let _ = &samples[idx!(^8..)];
+    }
+
+    #[test]
+    fn test_tail_macro() {
+        let nums = [8, 6, 7, 5, 3, 0, 9];
+        tail!(let [rest @ .., a, b] = nums else { panic!() });
+        assert_eq!((rest, a, b), (&nums[..5], &0, &9));
+        let slice: &[i32] = &nums[..1];
+        tail!(let [.., _, _] = slice else { return });
+        unreachable!();
+    }
+
+    #[test]
+    fn test_tail_macro_split() {
+        let vec: Vec<_> = (0..7).collect();
+        assert_eq!(tail!(vec => 2), Some((&vec[..5], &5, &6)));
+        assert_eq!(tail!(vec => 7).map(|(rest, ..)| rest.len()), Some(0));
+        assert_eq!(tail!(vec => 8), None);
+        assert_eq!(tail!(&vec[..0] => 1), None);
+    }
+
+    #[test]
+    fn test_with_range_mut() {
+        let mut vec: Vec<_> = (0..5).collect();
+        assert_eq!(with_range_mut(&mut vec, idx!(1..^1), |window| window.len()), Ok(3));
+        with_range_mut(&mut vec, idx!(^2..), |window| window.fill(0)).unwrap();
+        assert_eq!(vec, [0, 1, 2, 0, 0]);
+        let err = with_range_mut(&mut vec, idx!(^1..1), |_| unreachable!()).unwrap_err();
+        assert_eq!(err, SeqRangeError::Inverted { start: 4, end: 1 });
+    }
+
+    #[test]
+    fn test_subslice_offset_seq() {
+        let nums = [8, 6, 7, 5, 3, 0, 9];
+        assert_eq!(subslice_offset_seq(&nums, &nums[1..3]), Some(idx!(1..3)));
+        assert_eq!(subslice_offset_seq(&nums, &nums[3..5]), Some(idx!(3..^2)));
+        assert_eq!(subslice_offset_seq(&nums, &nums[4..]), Some(idx!(^3..^0)));
+        assert_eq!(subslice_offset_seq(&nums, &nums[..]), Some(idx!(0..^0)));
+        assert_eq!(subslice_offset_seq(&nums[2..], &nums[..3]), None);
+        assert_eq!(subslice_offset_seq(&nums[..3], &nums[2..5]), None);
+        assert_eq!(subslice_offset_seq(&nums, &[8]), None);
+        assert_eq!(subslice_offset_seq(&nums, &nums[..0]), Some(idx!(0..0)));
+        assert_eq!(subslice_offset_seq(&[(); 4], &[(); 4][1..]), None);
+    }
+
+    #[test]
+    fn test_slice_rows() {
+        let rows: [&[u8]; 3] = [b"abc", b"", b"de"];
+        let sliced: Vec<_> = slice_rows(&rows, idx!(1..^0)).collect();
+        assert_eq!(sliced, [Some(&b"bc"[..]), None, Some(&b"e"[..])]);
+        let sliced: Vec<_> = slice_rows_each(&rows, &[idx!(^1..^0), idx!(0..^0)]).collect();
+        assert_eq!(sliced, [Some(&b"c"[..]), Some(&b""[..])]);
+        let sliced: Vec<_> = slice_rows_each(&rows, &[idx!(4..^0)]).collect();
+        assert_eq!(sliced, [None]);
+    }
 }
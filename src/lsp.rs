@@ -0,0 +1,164 @@
+//! Conversions to and from [`lsp_types`] positions and ranges.
+//!
+//! Positions in the Language Server Protocol are a zero-based line number and
+//! a character offset within that line, counted in UTF-16 code units.
+//! Lines are separated by `'\n'`; a trailing `'\r'` is treated as part of its line.
+
+use std::ops::Range;
+
+use crate::{SeqIndex, TextUnit, Utf16};
+
+/// A position within a document, whose line and character may count from the back.
+///
+/// `line: idx!(^1)` refers to the last line of the document, and
+/// `character: idx!(^0)` to the end of the line.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::idx;
+/// # use from_back::lsp::SeqPosition;
+/// let doc = "fn main() {\n    todo!()\n}\n";
+/// // the end of the second-to-last line
+/// let pos = SeqPosition { line: idx!(^2), character: idx!(^0) };
+/// assert_eq!(pos.to_lsp(doc), Some(lsp_types::Position::new(2, 1)));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SeqPosition {
+    /// The line of the document.
+    pub line: SeqIndex<usize>,
+    /// The offset within the line, in UTF-16 code units.
+    pub character: SeqIndex<usize>,
+}
+
+impl SeqPosition {
+    /// Resolve to a byte offset within `doc`, or `None` if the position is not within `doc`.
+    pub fn byte_offset(&self, doc: &str) -> Option<usize> {
+        let line = match self.line {
+            SeqIndex::FromFront(idx) => doc.split('\n').nth(idx)?,
+            SeqIndex::FromBack(idx) => doc.rsplit('\n').nth(idx.checked_sub(1)?)?,
+        };
+        let line_start = line.as_ptr() as usize - doc.as_ptr() as usize;
+        Some(line_start + Utf16::byte_offset(line, self.character)?)
+    }
+
+    /// Resolve to an LSP [`Position`](lsp_types::Position) within `doc`,
+    /// or `None` if the position is not within `doc`.
+    pub fn to_lsp(&self, doc: &str) -> Option<lsp_types::Position> {
+        position_of(doc, self.byte_offset(doc)?)
+    }
+}
+
+impl From<lsp_types::Position> for SeqPosition {
+    fn from(pos: lsp_types::Position) -> Self {
+        SeqPosition {
+            line: SeqIndex::FromFront(pos.line as usize),
+            character: SeqIndex::FromFront(pos.character as usize),
+        }
+    }
+}
+
+/// The LSP [`Position`](lsp_types::Position) of a byte offset within `doc`.
+///
+/// Returns `None` if the offset is not a char boundary within `doc`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::lsp::position_of;
+/// let doc = "let 𝄞 = 1;\nlet x = 𝄞;";
+/// assert_eq!(position_of(doc, 26), Some(lsp_types::Position::new(1, 10)));
+/// ```
+pub fn position_of(doc: &str, offset: usize) -> Option<lsp_types::Position> {
+    let before = doc.get(..offset)?;
+    let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+    let line = before.matches('\n').count();
+    let character = before[line_start..].encode_utf16().count();
+    Some(lsp_types::Position {
+        line: line.try_into().ok()?,
+        character: character.try_into().ok()?,
+    })
+}
+
+/// The LSP [`Range`](lsp_types::Range) of a byte range within `doc`.
+///
+/// Returns `None` if either bound is not a char boundary within `doc`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, Chars};
+/// # use from_back::lsp::range_of;
+/// let doc = "let 𝄞 = 1;\nlet x = 𝄞;";
+/// let last_char = Chars::range(idx!(^1..)).byte_range(doc).unwrap();
+/// let expected = lsp_types::Range::new(
+///     lsp_types::Position::new(1, 10),
+///     lsp_types::Position::new(1, 11),
+/// );
+/// assert_eq!(range_of(doc, last_char), Some(expected));
+/// ```
+pub fn range_of(doc: &str, range: Range<usize>) -> Option<lsp_types::Range> {
+    Some(lsp_types::Range {
+        start: position_of(doc, range.start)?,
+        end: position_of(doc, range.end)?,
+    })
+}
+
+/// The byte range within `doc` of an LSP [`Range`](lsp_types::Range).
+///
+/// Returns `None` if either bound is not within `doc`, or if the range is inverted.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::lsp::byte_range_of;
+/// let doc = "let 𝄞 = 1;\nlet x = 𝄞;";
+/// let range = lsp_types::Range::new(
+///     lsp_types::Position::new(0, 4),
+///     lsp_types::Position::new(0, 6),
+/// );
+/// let range = byte_range_of(doc, range).unwrap();
+/// assert_eq!(&doc[range], "𝄞");
+/// ```
+pub fn byte_range_of(doc: &str, range: lsp_types::Range) -> Option<Range<usize>> {
+    let start = SeqPosition::from(range.start).byte_offset(doc)?;
+    let end = SeqPosition::from(range.end).byte_offset(doc)?;
+    (start <= end).then_some(start..end)
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::Position;
+
+    use super::*;
+    use crate::idx;
+
+    #[test]
+    fn test_seq_position() {
+        let doc = "ab\n€𝄞\n\nxyz";
+        let pos = |line, character| SeqPosition { line, character };
+        assert_eq!(pos(idx!(1), idx!(^2)).to_lsp(doc), Some(Position::new(1, 1)));
+        assert_eq!(pos(idx!(^3), idx!(^2)).to_lsp(doc), Some(Position::new(1, 1)));
+        assert_eq!(pos(idx!(^1), idx!(^0)).to_lsp(doc), Some(Position::new(3, 3)));
+        assert_eq!(pos(idx!(^2), idx!(0)).byte_offset(doc), Some(11));
+        assert_eq!(pos(idx!(^4), idx!(0)).byte_offset(doc), Some(0));
+        assert_eq!(pos(idx!(^5), idx!(0)).byte_offset(doc), None);
+        assert_eq!(pos(idx!(^0), idx!(0)).byte_offset(doc), None);
+        assert_eq!(pos(idx!(4), idx!(0)).byte_offset(doc), None);
+        assert_eq!(pos(idx!(0), idx!(3)).byte_offset(doc), None);
+        // splits a surrogate pair
+        assert_eq!(pos(idx!(1), idx!(2)).byte_offset(doc), None);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let doc = "ab\n€𝄞\n\nxyz\n";
+        for offset in (0..=doc.len()).filter(|&offset| doc.is_char_boundary(offset)) {
+            let pos = position_of(doc, offset).unwrap();
+            assert_eq!(SeqPosition::from(pos).byte_offset(doc), Some(offset));
+        }
+        assert_eq!(position_of(doc, doc.len()), Some(Position::new(4, 0)));
+        assert_eq!(position_of(doc, doc.len() + 1), None);
+        assert_eq!(position_of(doc, 4), None);
+    }
+}
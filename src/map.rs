@@ -0,0 +1,137 @@
+//! Associating values with ranges which are resolved at query time.
+
+use std::ops::Range;
+
+use crate::{SeqIndex, SeqRange, SeqRangeError};
+
+/// A collection of values, each associated with a [`SeqRange`].
+///
+/// Ranges are stored unresolved, so the map can be built before the length of the sequence
+/// is known, and each query resolves them against the length it is given.
+/// Ranges may overlap, and queries yield matches in the order they were inserted.
+/// Entries whose range does not fit the length of a query are skipped by it.
+///
+/// Queries scan every entry, since the order of the resolved ranges depends on the length.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, SeqRangeMap};
+/// let mut layers = SeqRangeMap::new();
+/// layers.insert(idx!(0..^0), "text");
+/// layers.insert(idx!(^4..^1), "string");
+/// layers.insert(idx!(..2), "keyword");
+///
+/// let line = "if x == \"ab\"";
+/// let styles: Vec<_> = layers.lookup(idx!(^2), line.len()).collect();
+/// assert_eq!(styles, [&"text", &"string"]);
+/// let spans: Vec<_> = layers.overlapping(idx!(..3), line.len()).unwrap().collect();
+/// assert_eq!(spans, [(0..12, &"text"), (0..2, &"keyword")]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SeqRangeMap<V> {
+    entries: Vec<(SeqRange<usize>, V)>,
+}
+
+impl<V> SeqRangeMap<V> {
+    /// An empty map.
+    pub const fn new() -> Self {
+        SeqRangeMap { entries: Vec::new() }
+    }
+
+    /// Associate `value` with `range`.
+    ///
+    /// Existing entries are kept, even those with an equal range.
+    pub fn insert(&mut self, range: impl Into<SeqRange<usize>>, value: V) {
+        self.entries.push((range.into(), value));
+    }
+
+    /// The number of entries in the map.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the map has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterate over the entries, unresolved, in the order they were inserted.
+    pub fn iter(&self) -> impl Iterator<Item = (SeqRange<usize>, &V)> {
+        self.entries.iter().map(|(range, value)| (*range, value))
+    }
+
+    /// Iterate over the values whose range contains the given index,
+    /// resolving each against a sequence of length `len`.
+    ///
+    /// Yields nothing if the index does not refer to an element of the sequence.
+    pub fn lookup(&self, index: SeqIndex<usize>, len: usize) -> impl Iterator<Item = &V> {
+        let index = index.checked_for_seq_len(len).ok().filter(|&index| index < len);
+        self.resolved(len)
+            .filter(move |(range, _)| index.is_some_and(|index| range.contains(&index)))
+            .map(|(_, value)| value)
+    }
+
+    /// Iterate over the resolved ranges, and their values, which share any elements
+    /// with the given range, resolving each against a sequence of length `len`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the given range does not fit a sequence of length `len`.
+    pub fn overlapping(
+        &self,
+        range: impl Into<SeqRange<usize>>,
+        len: usize,
+    ) -> Result<impl Iterator<Item = (Range<usize>, &V)>, SeqRangeError> {
+        let query = range.into().checked_for_seq_len(len)?;
+        Ok(self
+            .resolved(len)
+            .filter(move |(range, _)| range.start.max(query.start) < range.end.min(query.end)))
+    }
+
+    /// Iterate over the entries whose range fits a sequence of length `len`, resolved against it.
+    fn resolved(&self, len: usize) -> impl Iterator<Item = (Range<usize>, &V)> {
+        self.entries
+            .iter()
+            .filter_map(move |(range, value)| Some((range.checked_for_seq_len(len).ok()?, value)))
+    }
+}
+
+impl<V> Default for SeqRangeMap<V> {
+    fn default() -> Self {
+        SeqRangeMap::new()
+    }
+}
+
+impl<R, V> FromIterator<(R, V)> for SeqRangeMap<V>
+where R: Into<SeqRange<usize>> {
+    fn from_iter<I>(iter: I) -> Self
+    where I: IntoIterator<Item = (R, V)> {
+        let entries = iter.into_iter().map(|(range, value)| (range.into(), value)).collect();
+        SeqRangeMap { entries }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idx;
+
+    #[test]
+    fn test_seq_range_map() {
+        let map: SeqRangeMap<_> = [(idx!(0..3), 'a'), (idx!(^3..^0), 'b'), (idx!(2..^2), 'c')].into_iter().collect();
+        assert_eq!(map.len(), 3);
+        let lookup = |index, len| map.lookup(index, len).copied().collect::<String>();
+        assert_eq!(lookup(idx!(2), 5), "abc");
+        assert_eq!(lookup(idx!(^1), 5), "b");
+        assert_eq!(lookup(idx!(^0), 5), "");
+        assert_eq!(lookup(idx!(^6), 5), "");
+        // `2..^2` does not fit a sequence of length 3
+        assert_eq!(lookup(idx!(2), 3), "ab");
+        let overlapping = |range, len| map.overlapping(range, len).map(|iter| iter.collect::<Vec<_>>());
+        assert_eq!(overlapping(idx!(3..^0), 8), Ok(vec![(5..8, &'b'), (2..6, &'c')]));
+        assert_eq!(overlapping(idx!(3..3), 8), Ok(vec![]));
+        assert!(overlapping(idx!(^9..^0), 8).is_err());
+        assert!(SeqRangeMap::<()>::default().is_empty());
+    }
+}
@@ -0,0 +1,99 @@
+//! [`nom`](::nom) combinators which consume input described by the library's structs.
+
+use ::nom::error::{ErrorKind, ParseError};
+use ::nom::{Err, IResult};
+
+use crate::SeqRange;
+
+/// Consume the input described by a range, resolving from-back bounds against the remaining input.
+///
+/// Returns the input within the range, and leaves the input after the range.
+/// The input before the range is discarded.
+///
+/// # Errors
+///
+/// Fails with [`ErrorKind::Eof`] if the range does not fit the remaining input.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::idx;
+/// # use from_back::nom::take_seq;
+/// # use nom::IResult;
+/// fn payload(input: &[u8]) -> IResult<&[u8], &[u8]> {
+///     // skip the 2 byte header, and stop before the 4 byte checksum
+///     take_seq(idx!(2..^4))(input)
+/// }
+///
+/// let frame = b"\x01\x02hello\xde\xad\xbe\xef";
+/// assert_eq!(payload(frame), Ok((&b"\xde\xad\xbe\xef"[..], &b"hello"[..])));
+/// assert!(payload(b"\x01\x02\xde").is_err());
+/// ```
+pub fn take_seq<'a, E>(range: SeqRange<usize>) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], &'a [u8], E>
+where E: ParseError<&'a [u8]> {
+    move |input: &'a [u8]| match range.checked_for_seq_len(input.len()) {
+        Ok(range) => Ok((&input[range.end..], &input[range])),
+        Err(_) => Err(Err::Error(E::from_error_kind(input, ErrorKind::Eof))),
+    }
+}
+
+/// Consume the last `n` bytes of the input.
+///
+/// Returns the trailer, and leaves the input before the trailer
+/// so that the body of a message can be parsed next.
+///
+/// # Errors
+///
+/// Fails with [`ErrorKind::Eof`] if the input is shorter than `n` bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::nom::trailer;
+/// # use nom::IResult;
+/// fn checksum(input: &[u8]) -> IResult<&[u8], &[u8]> {
+///     trailer(4)(input)
+/// }
+///
+/// let frame = b"hello\xde\xad\xbe\xef";
+/// assert_eq!(checksum(frame), Ok((&b"hello"[..], &b"\xde\xad\xbe\xef"[..])));
+/// assert!(checksum(b"\xbe\xef").is_err());
+/// ```
+pub fn trailer<'a, E>(n: usize) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], &'a [u8], E>
+where E: ParseError<&'a [u8]> {
+    move |input: &'a [u8]| match input.len().checked_sub(n) {
+        Some(split) => Ok(input.split_at(split)),
+        None => Err(Err::Error(E::from_error_kind(input, ErrorKind::Eof))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::nom::error::Error;
+
+    use super::*;
+    use crate::idx;
+
+    #[test]
+    fn test_take_seq() {
+        let input: &[u8] = b"abcdef";
+        assert_eq!(take_seq::<Error<_>>(idx!(^2..^0))(input), Ok((&b""[..], &b"ef"[..])));
+        assert_eq!(take_seq::<Error<_>>(idx!(0..^6))(input), Ok((input, &b""[..])));
+        assert_eq!(
+            take_seq::<Error<_>>(idx!(^2..3))(input),
+            Err(Err::Error(Error::new(input, ErrorKind::Eof))),
+        );
+        assert!(take_seq::<Error<_>>(idx!(0..7))(input).is_err());
+    }
+
+    #[test]
+    fn test_trailer() {
+        let input: &[u8] = b"abcdef";
+        assert_eq!(trailer::<Error<_>>(0)(input), Ok((input, &b""[..])));
+        assert_eq!(trailer::<Error<_>>(6)(input), Ok((&b""[..], input)));
+        assert_eq!(
+            trailer::<Error<_>>(7)(input),
+            Err(Err::Error(Error::new(input, ErrorKind::Eof))),
+        );
+    }
+}
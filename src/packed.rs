@@ -0,0 +1,113 @@
+//! A single-word encoding of [`SeqIndex`].
+
+use std::fmt;
+
+use crate::{SeqIndex, SeqRangeError, WithLen};
+
+/// The bit which marks a [`PackedSeqIndex`] as counting from the back.
+const FROM_BACK: usize = 1 << (usize::BITS - 1);
+
+/// A [`SeqIndex<usize>`] packed into a single [`usize`].
+///
+/// The top bit records whether the index counts from the back, so a `PackedSeqIndex`
+/// is half the size of a `SeqIndex<usize>`, at the cost of one bit of range.
+/// This matters when storing many indices, eg in an index array.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, PackedSeqIndex, SeqIndex};
+/// let packed = PackedSeqIndex::new(idx!(^2)).unwrap();
+/// assert_eq!(SeqIndex::from(packed), idx!(^2));
+/// assert_eq!(packed.for_seq_len(10), 8);
+/// assert_eq!(std::mem::size_of_val(&packed), std::mem::size_of::<usize>());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PackedSeqIndex(usize);
+
+impl PackedSeqIndex {
+    /// The largest index, from either end, which can be packed.
+    pub const MAX: usize = FROM_BACK - 1;
+
+    /// Pack an index, or return `None` if it exceeds [`PackedSeqIndex::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, PackedSeqIndex};
+    /// assert!(PackedSeqIndex::new(idx!(^PackedSeqIndex::MAX)).is_some());
+    /// assert!(PackedSeqIndex::new(idx!(usize::MAX)).is_none());
+    /// ```
+    pub const fn new(index: SeqIndex<usize>) -> Option<Self> {
+        match index {
+            SeqIndex::FromFront(idx) if idx <= Self::MAX => Some(PackedSeqIndex(idx)),
+            SeqIndex::FromBack(idx) if idx <= Self::MAX => Some(PackedSeqIndex(idx | FROM_BACK)),
+            _ => None,
+        }
+    }
+
+    /// Unpack into a [`SeqIndex`].
+    pub const fn unpack(self) -> SeqIndex<usize> {
+        if self.0 & FROM_BACK == 0 {
+            SeqIndex::FromFront(self.0)
+        } else {
+            SeqIndex::FromBack(self.0 & Self::MAX)
+        }
+    }
+
+    /// Convert to a native from-front [`usize`] for a sequence of the given `len`.
+    ///
+    /// See [`SeqIndex::for_seq_len`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the from-back value exceeds the given length.
+    #[cfg_attr(feature = "no-panic", deprecated(note = "panics on an index which does not fit"))]
+    pub fn for_seq_len(self, len: usize) -> usize {
+        self.unpack().for_seq_len(len)
+    }
+}
+
+impl From<PackedSeqIndex> for SeqIndex<usize> {
+    fn from(packed: PackedSeqIndex) -> Self {
+        packed.unpack()
+    }
+}
+
+/// Resolve to a position in `0..=len`.
+impl TryFrom<WithLen<PackedSeqIndex>> for usize {
+    type Error = SeqRangeError;
+
+    fn try_from(WithLen(packed, len): WithLen<PackedSeqIndex>) -> Result<usize, SeqRangeError> {
+        packed.unpack().checked_for_seq_len(len)
+    }
+}
+
+impl fmt::Debug for PackedSeqIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PackedSeqIndex").field(&self.unpack()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idx;
+
+    #[test]
+    fn test_round_trip() {
+        for index in [idx!(0), idx!(^0), idx!(5), idx!(^5), idx!(PackedSeqIndex::MAX), idx!(^PackedSeqIndex::MAX)] {
+            assert_eq!(PackedSeqIndex::new(index).unwrap().unpack(), index);
+        }
+        assert_eq!(PackedSeqIndex::new(idx!(^usize::MAX)), None);
+        assert_eq!(PackedSeqIndex::new(idx!(FROM_BACK)), None);
+    }
+
+    #[test]
+    fn test_resolve() {
+        let packed = PackedSeqIndex::new(idx!(^3)).unwrap();
+        assert_eq!(usize::try_from(WithLen(packed, 10)), Ok(7));
+        assert!(usize::try_from(WithLen(packed, 2)).is_err());
+        assert_eq!(format!("{packed:?}"), "PackedSeqIndex(FromBack(3))");
+    }
+}
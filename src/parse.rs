@@ -0,0 +1,236 @@
+//! Parsing and printing the library's structs as strings in [`idx!`](crate::idx) syntax.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{SeqIndex, SeqRange, SeqRangeFrom, SeqRangeInclusive};
+
+/// The error type for parsing the library's structs from strings.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ParseSeqError {
+    /// A bound was not a number, optionally prefixed with `^`.
+    InvalidIndex(String),
+    /// The input was not of the expected range form.
+    InvalidRange {
+        /// The offending input.
+        input: String,
+        /// The expected form, eg `start..end`.
+        expected: &'static str,
+    },
+}
+
+impl fmt::Display for ParseSeqError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseSeqError::InvalidIndex(input) => {
+                write!(f, "invalid index `{input}`: expected a number, optionally prefixed with `^`")
+            }
+            ParseSeqError::InvalidRange { input, expected } => {
+                write!(f, "invalid range `{input}`: expected `{expected}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseSeqError {}
+
+/// Parse an optional range bound, where an empty bound is `None`.
+fn parse_bound(bound: &str) -> Result<Option<SeqIndex<usize>>, ParseSeqError> {
+    if bound.is_empty() {
+        return Ok(None);
+    }
+    bound.parse().map(Some)
+}
+
+/// Parse `N` or `^N`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, SeqIndex};
+/// assert_eq!("^2".parse::<SeqIndex<usize>>(), Ok(idx!(^2)));
+/// assert_eq!("2".parse::<SeqIndex<usize>>(), Ok(idx!(2)));
+/// assert!("-2".parse::<SeqIndex<usize>>().is_err());
+/// ```
+impl FromStr for SeqIndex<usize> {
+    type Err = ParseSeqError;
+
+    fn from_str(s: &str) -> Result<Self, ParseSeqError> {
+        let invalid = || ParseSeqError::InvalidIndex(s.to_owned());
+        // `usize::from_str` accepts a leading `+`, which `idx!` does not.
+        let parse = |digits: &str| {
+            if digits.starts_with('+') {
+                Err(invalid())
+            } else {
+                digits.parse().map_err(|_| invalid())
+            }
+        };
+        match s.strip_prefix('^') {
+            Some(digits) => parse(digits).map(SeqIndex::FromBack),
+            None => parse(s).map(SeqIndex::FromFront),
+        }
+    }
+}
+
+/// Parse `start..end`, where either bound may be omitted.
+///
+/// A missing start is `0`, and a missing end is `^0`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, SeqRange};
+/// assert_eq!("2..^3".parse::<SeqRange<usize>>(), Ok(idx!(2..^3)));
+/// assert_eq!("^3..".parse::<SeqRange<usize>>(), Ok(idx!(^3..^0)));
+/// assert!("2..=^3".parse::<SeqRange<usize>>().is_err());
+/// ```
+impl FromStr for SeqRange<usize> {
+    type Err = ParseSeqError;
+
+    fn from_str(s: &str) -> Result<Self, ParseSeqError> {
+        let invalid = || ParseSeqError::InvalidRange { input: s.to_owned(), expected: "start..end" };
+        let (start, end) = s.split_once("..").ok_or_else(invalid)?;
+        if end.starts_with('=') {
+            return Err(invalid());
+        }
+        Ok(SeqRange {
+            start: parse_bound(start)?.unwrap_or(SeqIndex::FromFront(0)),
+            end: parse_bound(end)?.unwrap_or(SeqIndex::FromBack(0)),
+        })
+    }
+}
+
+/// Parse `start..`, where the start may be omitted.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, SeqRangeFrom};
+/// assert_eq!("^3..".parse::<SeqRangeFrom<usize>>(), Ok(idx!(^3..)));
+/// assert!("^3..^1".parse::<SeqRangeFrom<usize>>().is_err());
+/// ```
+impl FromStr for SeqRangeFrom<usize> {
+    type Err = ParseSeqError;
+
+    fn from_str(s: &str) -> Result<Self, ParseSeqError> {
+        let invalid = || ParseSeqError::InvalidRange { input: s.to_owned(), expected: "start.." };
+        let start = s.strip_suffix("..").ok_or_else(invalid)?;
+        Ok(SeqRangeFrom { start: parse_bound(start)?.unwrap_or(SeqIndex::FromFront(0)) })
+    }
+}
+
+/// Parse `start..=end`, where the start may be omitted.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, SeqRangeInclusive};
+/// assert_eq!("2..=^3".parse::<SeqRangeInclusive<usize>>(), Ok(idx!(2..=^3)));
+/// assert!("2..".parse::<SeqRangeInclusive<usize>>().is_err());
+/// ```
+impl FromStr for SeqRangeInclusive<usize> {
+    type Err = ParseSeqError;
+
+    fn from_str(s: &str) -> Result<Self, ParseSeqError> {
+        let invalid = || ParseSeqError::InvalidRange { input: s.to_owned(), expected: "start..=end" };
+        let (start, end) = s.split_once("..=").ok_or_else(invalid)?;
+        Ok(SeqRangeInclusive {
+            start: parse_bound(start)?.unwrap_or(SeqIndex::FromFront(0)),
+            end: parse_bound(end)?.ok_or_else(invalid)?,
+        })
+    }
+}
+
+/// Print as `N` or `^N`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::idx;
+/// assert_eq!(idx!(^2).to_string(), "^2");
+/// ```
+impl<Idx> fmt::Display for SeqIndex<Idx>
+where Idx: fmt::Display {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SeqIndex::FromFront(idx) => write!(f, "{idx}"),
+            SeqIndex::FromBack(idx) => write!(f, "^{idx}"),
+        }
+    }
+}
+
+/// Print as `start..end`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::idx;
+/// assert_eq!(idx!(2..^3).to_string(), "2..^3");
+/// ```
+impl<Idx> fmt::Display for SeqRange<Idx>
+where Idx: fmt::Display {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+/// Print as `start..`.
+impl<Idx> fmt::Display for SeqRangeFrom<Idx>
+where Idx: fmt::Display {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..", self.start)
+    }
+}
+
+/// Print as `start..=end`.
+impl<Idx> fmt::Display for SeqRangeInclusive<Idx>
+where Idx: fmt::Display {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..={}", self.start, self.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idx;
+
+    #[test]
+    fn test_parse_index() {
+        assert_eq!("0".parse(), Ok(idx!(0)));
+        assert_eq!("^0".parse(), Ok(idx!(^0)));
+        for bad in ["", "^", "^^1", "+1", "^+1", " 1", "1.5", "-1"] {
+            assert_eq!(bad.parse::<SeqIndex<usize>>(), Err(ParseSeqError::InvalidIndex(bad.to_owned())));
+        }
+    }
+
+    #[test]
+    fn test_parse_range() {
+        assert_eq!("..".parse(), Ok(idx!(0..^0)));
+        assert_eq!("..^1".parse(), Ok(idx!(0..^1)));
+        assert_eq!("^5..3".parse(), Ok(idx!(^5..3)));
+        assert_eq!("..".parse(), Ok(SeqRangeFrom { start: idx!(0) }));
+        assert_eq!("..=^1".parse(), Ok(idx!(0..=^1)));
+        assert_eq!(
+            "1..x".parse::<SeqRange<usize>>(),
+            Err(ParseSeqError::InvalidIndex("x".to_owned())),
+        );
+        assert_eq!(
+            "1...2".parse::<SeqRange<usize>>(),
+            Err(ParseSeqError::InvalidIndex(".2".to_owned())),
+        );
+        let err = "12".parse::<SeqRange<usize>>().unwrap_err();
+        assert_eq!(err.to_string(), "invalid range `12`: expected `start..end`");
+        assert!("1..=".parse::<SeqRangeInclusive<usize>>().is_err());
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        assert_eq!(idx!(0).to_string(), "0");
+        assert_eq!(idx!(^0..).to_string(), "^0..");
+        assert_eq!(idx!(1..=^1).to_string(), "1..=^1");
+        for s in ["3..^2", "^5..3", "0..^0"] {
+            assert_eq!(s.parse::<SeqRange<usize>>().unwrap().to_string(), s);
+        }
+    }
+}
@@ -0,0 +1,178 @@
+//! A streaming buffer which keeps only its last `N` elements.
+
+use std::hash::{Hash, Hasher};
+use std::slice;
+
+use crate::SeqIndex;
+
+/// A buffer which keeps the last `n` elements pushed to it, evicting older elements from the front.
+///
+/// The pinned elements are always contiguous, so they are available as a slice,
+/// which is `idx!(^n..)` of everything pushed so far.
+/// Evicted elements are dropped in batches, so pushing is amortized constant time,
+/// and the buffer holds at most `2 * n` elements.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, PinnedTail};
+/// let mut recent = PinnedTail::new(3);
+/// recent.extend([8, 6, 7, 5, 3]);
+/// assert_eq!(recent.tail(), [7, 5, 3]);
+/// recent.push(0);
+/// assert_eq!(recent.get(idx!(^1)), Some(&0));
+/// assert_eq!(recent.get(idx!(0)), Some(&5));
+/// ```
+#[derive(Debug, Clone)]
+pub struct PinnedTail<T> {
+    buf: Vec<T>,
+    pinned: usize,
+}
+
+impl<T> PinnedTail<T> {
+    /// Create an empty buffer which keeps the last `n` elements.
+    pub fn new(n: usize) -> Self {
+        PinnedTail { buf: Vec::new(), pinned: n }
+    }
+
+    /// The number of elements the buffer keeps.
+    pub fn pinned(&self) -> usize {
+        self.pinned
+    }
+
+    /// Whether the buffer holds all `n` of its pinned elements.
+    ///
+    /// This is false until `n` elements have been pushed.
+    pub fn is_full(&self) -> bool {
+        self.buf.len() >= self.pinned
+    }
+
+    /// Push an element to the back, evicting the oldest element if the buffer is full.
+    pub fn push(&mut self, value: T) {
+        self.buf.push(value);
+        let len = self.buf.len();
+        if len > self.pinned && len >= self.pinned.saturating_mul(2).max(1) {
+            self.buf.drain(..len - self.pinned);
+        }
+    }
+
+    /// The pinned elements, from oldest to newest.
+    pub fn tail(&self) -> &[T] {
+        &self.buf[self.buf.len().saturating_sub(self.pinned)..]
+    }
+
+    /// The pinned elements, mutably.
+    pub fn tail_mut(&mut self) -> &mut [T] {
+        let start = self.buf.len().saturating_sub(self.pinned);
+        &mut self.buf[start..]
+    }
+
+    /// Iterate over the pinned elements, from oldest to newest.
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.tail().iter()
+    }
+
+    /// The pinned element at the given index, or `None` if it is out of bounds.
+    ///
+    /// Indices are relative to the pinned elements, so `idx!(^1)` is the newest element.
+    pub fn get(&self, index: SeqIndex<usize>) -> Option<&T> {
+        let tail = self.tail();
+        tail.get(index.checked_for_seq_len(tail.len()).ok()?)
+    }
+
+    /// Unwrap the pinned elements, from oldest to newest.
+    pub fn into_vec(mut self) -> Vec<T> {
+        let start = self.buf.len().saturating_sub(self.pinned);
+        self.buf.drain(..start);
+        self.buf
+    }
+}
+
+// Implemented by hand over the pinned elements, since the buffer may also hold
+// evicted elements which have not yet been dropped.
+
+impl<T: PartialEq> PartialEq for PinnedTail<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.tail() == other.tail()
+    }
+}
+
+impl<T: Eq> Eq for PinnedTail<T> {}
+
+impl<T: Hash> Hash for PinnedTail<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.tail().hash(state);
+    }
+}
+
+impl<T> Extend<T> for PinnedTail<T> {
+    fn extend<I>(&mut self, iter: I)
+    where I: IntoIterator<Item = T> {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a PinnedTail<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idx;
+
+    #[test]
+    fn test_pinned_tail() {
+        let mut recent = PinnedTail::new(3);
+        assert!(recent.tail().is_empty());
+        recent.extend([1, 2]);
+        assert!(!recent.is_full());
+        assert_eq!(recent.get(idx!(^3)), None);
+        for value in 3..100 {
+            recent.push(value);
+            assert_eq!(recent.tail(), [value - 2, value - 1, value]);
+            assert!(recent.buf.len() <= 6);
+        }
+        recent.tail_mut()[0] = 0;
+        assert_eq!(recent.iter().copied().collect::<Vec<_>>(), [0, 98, 99]);
+        assert_eq!(recent.get(idx!(^3)), Some(&0));
+        assert_eq!(recent.get(idx!(3)), None);
+        assert_eq!(recent.into_vec(), [0, 98, 99]);
+    }
+
+    #[test]
+    fn test_pinned_tail_eq() {
+        use std::collections::hash_map::DefaultHasher;
+
+        let hash = |tail: &PinnedTail<i32>| {
+            let mut hasher = DefaultHasher::new();
+            tail.hash(&mut hasher);
+            hasher.finish()
+        };
+        let mut a = PinnedTail::new(2);
+        a.extend([1, 2, 3]);
+        let mut b = PinnedTail::new(2);
+        b.extend([2, 3]);
+        assert_ne!(a.buf, b.buf);
+        assert_eq!(a, b);
+        assert_eq!(hash(&a), hash(&b));
+        b.push(4);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_pinned_tail_empty() {
+        let mut none = PinnedTail::new(0);
+        none.extend(0..10);
+        assert!(none.is_full());
+        assert!(none.buf.is_empty());
+        assert_eq!(none.get(idx!(^0)), None);
+    }
+}
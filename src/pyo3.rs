@@ -0,0 +1,147 @@
+//! Conversions between the library's structs and Python indices and `slice` objects.
+//!
+//! Negative Python ints count from the back, as in Python itself, so `-2` becomes `idx!(^2)`.
+//! A `slice` becomes a [`SeqRange`], or a [`SeqRangeFrom`] if it has no `stop`; a missing
+//! `start` is `0` and a missing `stop` is the end of the sequence. Slices with a `step`
+//! other than `1` are rejected.
+//!
+//! Since Python cannot distinguish `-0` from `0`, a from-back `^0` converts to Python only
+//! as the `stop` of a slice, where it becomes `None`.
+
+use ::pyo3::exceptions::{PyOverflowError, PyValueError};
+use ::pyo3::prelude::*;
+use ::pyo3::types::{PyInt, PySlice};
+
+use crate::{SeqIndex, SeqRange, SeqRangeFrom};
+
+/// Convert a Python index to a signed offset, where negative values count from the back.
+fn index_to_isize(index: SeqIndex<usize>) -> PyResult<isize> {
+    let idx = match index {
+        SeqIndex::FromFront(idx) => idx.try_into().ok(),
+        SeqIndex::FromBack(0) => {
+            return Err(PyValueError::new_err("^0 cannot be represented as a Python index"));
+        }
+        SeqIndex::FromBack(idx) => 0isize.checked_sub_unsigned(idx),
+    };
+    idx.ok_or_else(|| PyOverflowError::new_err(format!("{index:?} does not fit in a Python index")))
+}
+
+/// Convert the `stop` of a range to a Python slice bound, where `^0` is `None`.
+fn stop_to_isize(index: SeqIndex<usize>) -> PyResult<Option<isize>> {
+    match index {
+        SeqIndex::FromBack(0) => Ok(None),
+        index => index_to_isize(index).map(Some),
+    }
+}
+
+/// Read the `start` and `stop` of a Python slice, rejecting any `step` other than `1`.
+fn slice_bounds(obj: Borrowed<'_, '_, PyAny>) -> PyResult<(Option<isize>, Option<isize>)> {
+    let slice = obj.cast::<PySlice>()?;
+    if !matches!(slice.getattr("step")?.extract::<Option<isize>>()?, None | Some(1)) {
+        return Err(PyValueError::new_err("slice step must be 1"));
+    }
+    Ok((slice.getattr("start")?.extract()?, slice.getattr("stop")?.extract()?))
+}
+
+impl<'py> FromPyObject<'_, 'py> for SeqIndex<usize> {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> PyResult<Self> {
+        obj.extract::<isize>().map(SeqIndex::from)
+    }
+}
+
+impl<'py> IntoPyObject<'py> for SeqIndex<usize> {
+    type Target = PyInt;
+    type Output = Bound<'py, PyInt>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Bound<'py, PyInt>> {
+        Ok(index_to_isize(self)?.into_pyobject(py)?)
+    }
+}
+
+impl<'py> FromPyObject<'_, 'py> for SeqRange<usize> {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> PyResult<Self> {
+        let (start, stop) = slice_bounds(obj)?;
+        Ok(SeqRange {
+            start: start.map_or(SeqIndex::FromFront(0), SeqIndex::from),
+            end: stop.map_or(SeqIndex::FromBack(0), SeqIndex::from),
+        })
+    }
+}
+
+impl<'py> IntoPyObject<'py> for SeqRange<usize> {
+    type Target = PySlice;
+    type Output = Bound<'py, PySlice>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Bound<'py, PySlice>> {
+        let bounds = (index_to_isize(self.start)?, stop_to_isize(self.end)?);
+        Ok(py.get_type::<PySlice>().call1(bounds)?.cast_into()?)
+    }
+}
+
+impl<'py> FromPyObject<'_, 'py> for SeqRangeFrom<usize> {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> PyResult<Self> {
+        match slice_bounds(obj)? {
+            (start, None) => Ok(SeqRangeFrom { start: start.map_or(SeqIndex::FromFront(0), SeqIndex::from) }),
+            (_, Some(_)) => Err(PyValueError::new_err("slice must not have a stop")),
+        }
+    }
+}
+
+impl<'py> IntoPyObject<'py> for SeqRangeFrom<usize> {
+    type Target = PySlice;
+    type Output = Bound<'py, PySlice>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Bound<'py, PySlice>> {
+        SeqRange::from(self).into_pyobject(py)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idx;
+
+    fn eval<'py>(py: Python<'py>, code: &str) -> Bound<'py, PyAny> {
+        let code = std::ffi::CString::new(code).unwrap();
+        py.eval(&code, None, None).unwrap()
+    }
+
+    #[test]
+    fn test_extract() {
+        Python::initialize();
+        Python::attach(|py| {
+            assert_eq!(eval(py, "-2").extract::<SeqIndex<usize>>().unwrap(), idx!(^2));
+            assert_eq!(eval(py, "slice(2, -3)").extract::<SeqRange<usize>>().unwrap(), idx!(2..^3));
+            assert_eq!(eval(py, "slice(None, 4)").extract::<SeqRange<usize>>().unwrap(), idx!(0..4));
+            assert_eq!(eval(py, "slice(-4, None)").extract::<SeqRange<usize>>().unwrap(), idx!(^4..^0));
+            assert_eq!(eval(py, "slice(-4, None, 1)").extract::<SeqRangeFrom<usize>>().unwrap(), idx!(^4..));
+            assert!(eval(py, "slice(0, 4, 2)").extract::<SeqRange<usize>>().is_err());
+            assert!(eval(py, "slice(0, 4)").extract::<SeqRangeFrom<usize>>().is_err());
+            assert!(eval(py, "3").extract::<SeqRange<usize>>().is_err());
+        });
+    }
+
+    #[test]
+    fn test_into_pyobject() {
+        Python::initialize();
+        Python::attach(|py| {
+            let list = eval(py, "list(range(10))");
+            let slice = |range: SeqRange<usize>| list.get_item(range).unwrap().extract::<Vec<usize>>().unwrap();
+            assert_eq!(slice(idx!(2..^5)), [2, 3, 4]);
+            assert_eq!(slice(idx!(^2..^0)), [8, 9]);
+            assert_eq!(list.get_item(idx!(^1)).unwrap().extract::<usize>().unwrap(), 9);
+            assert!(idx!(^0).into_pyobject(py).is_err());
+            assert!(idx!(^0..^0).into_pyobject(py).is_err());
+            assert!(idx!(usize::MAX).into_pyobject(py).is_err());
+        });
+    }
+}
@@ -0,0 +1,98 @@
+//! Generating random ranges with [`rand`](::rand).
+
+use std::ops::{Bound, RangeBounds};
+
+use ::rand::Rng;
+
+use crate::{SeqIndex, SeqRange};
+
+/// Pick a random range of a size within `size` which fits a sequence of length `len`,
+/// returning its resolved start and end.
+fn random_bounds<R>(len: usize, size: impl RangeBounds<usize>, rng: &mut R) -> Option<(usize, usize)>
+where R: Rng + ?Sized {
+    let min = match size.start_bound() {
+        Bound::Included(&min) => min,
+        Bound::Excluded(&min) => min.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+    let max = match size.end_bound() {
+        Bound::Included(&max) => max.min(len),
+        Bound::Excluded(&max) => max.checked_sub(1)?.min(len),
+        Bound::Unbounded => len,
+    };
+    if min > max {
+        return None;
+    }
+    let size = rng.random_range(min..=max);
+    let start = rng.random_range(0..=len - size);
+    Some((start, start + size))
+}
+
+impl SeqRange<usize> {
+    /// A uniformly random range which fits a sequence of length `len`,
+    /// with a size within `size` chosen uniformly first.
+    ///
+    /// Both bounds of the returned range count from the front.
+    /// Returns `None` if no size within `size` fits the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::SeqRange;
+    /// # use rand::SeedableRng;
+    /// let mut rng = rand::rngs::SmallRng::seed_from_u64(7);
+    /// let range = SeqRange::random_within(10, 2..=4, &mut rng).unwrap();
+    /// assert!((2..=4).contains(&range.for_seq_len(10).len()));
+    /// assert_eq!(SeqRange::random_within(10, 11.., &mut rng), None);
+    /// ```
+    pub fn random_within<R>(len: usize, size: impl RangeBounds<usize>, rng: &mut R) -> Option<Self>
+    where R: Rng + ?Sized {
+        let (start, end) = random_bounds(len, size, rng)?;
+        Some(SeqRange { start: SeqIndex::FromFront(start), end: SeqIndex::FromFront(end) })
+    }
+
+    /// As [`random_within`](SeqRange::random_within), but both bounds of the returned range count from the back.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{SeqIndex, SeqRange};
+    /// # use rand::SeedableRng;
+    /// let mut rng = rand::rngs::SmallRng::seed_from_u64(7);
+    /// let range = SeqRange::random_within_back(10, 3..=3, &mut rng).unwrap();
+    /// assert!(matches!(range.start, SeqIndex::FromBack(3..=10)));
+    /// ```
+    pub fn random_within_back<R>(len: usize, size: impl RangeBounds<usize>, rng: &mut R) -> Option<Self>
+    where R: Rng + ?Sized {
+        let (start, end) = random_bounds(len, size, rng)?;
+        Some(SeqRange { start: SeqIndex::FromBack(len - start), end: SeqIndex::FromBack(len - end) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::rand::rngs::SmallRng;
+    use ::rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn test_random_within() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        for len in 0..20 {
+            for _ in 0..20 {
+                let range = SeqRange::random_within(len, 1..4, &mut rng);
+                assert_eq!(range.is_none(), len == 0);
+                if let Some(range) = range {
+                    assert!((1..4).contains(&range.checked_for_seq_len(len).unwrap().len()));
+                }
+                let range = SeqRange::random_within_back(len, ..=len, &mut rng).unwrap();
+                assert!(matches!(range.end, SeqIndex::FromBack(_)));
+                assert!(range.checked_for_seq_len(len).is_ok());
+            }
+        }
+        assert_eq!(SeqRange::random_within(10, 5..5, &mut rng), None);
+        assert_eq!(SeqRange::random_within(10, 0..0, &mut rng), None);
+        assert_eq!(SeqRange::random_within(0, 0..=0, &mut rng), Some(crate::idx!(0..0)));
+    }
+}
@@ -0,0 +1,103 @@
+//! Conversions to and from the [`core::range`] types.
+//!
+//! Unlike their [`std::ops`] counterparts, these range types are [`Copy`], as are the library's.
+//! They were stabilized in Rust 1.96, so the `new-range` feature requires at least that version.
+
+use core::range::{Range, RangeFrom, RangeInclusive};
+
+use crate::{SeqIndex, SeqRange, SeqRangeError, SeqRangeFrom, SeqRangeInclusive, WithLen};
+
+/// Convert `start..end` to `idx!(start..end)`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, SeqRange};
+/// assert_eq!(SeqRange::from(core::range::Range::from(2..5)), idx!(2..5));
+/// ```
+impl<Idx> From<Range<Idx>> for SeqRange<Idx> {
+    fn from(range: Range<Idx>) -> Self {
+        SeqRange { start: SeqIndex::FromFront(range.start), end: SeqIndex::FromFront(range.end) }
+    }
+}
+
+/// Convert `start..` to `idx!(start..)`.
+impl<Idx> From<RangeFrom<Idx>> for SeqRangeFrom<Idx> {
+    fn from(range: RangeFrom<Idx>) -> Self {
+        SeqRangeFrom { start: SeqIndex::FromFront(range.start) }
+    }
+}
+
+/// Convert `start..=last` to `idx!(start..=last)`.
+impl<Idx> From<RangeInclusive<Idx>> for SeqRangeInclusive<Idx> {
+    fn from(range: RangeInclusive<Idx>) -> Self {
+        SeqRangeInclusive { start: SeqIndex::FromFront(range.start), end: SeqIndex::FromFront(range.last) }
+    }
+}
+
+/// Resolve to a [`core::range::Range`] which fits a sequence of length `len`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use core::range::Range;
+/// # use from_back::{idx, WithLen};
+/// assert_eq!(Range::try_from(WithLen(idx!(2..^2), 10)), Ok(Range::from(2..8)));
+/// ```
+impl TryFrom<WithLen<SeqRange<usize>>> for Range<usize> {
+    type Error = SeqRangeError;
+
+    fn try_from(WithLen(range, len): WithLen<SeqRange<usize>>) -> Result<Self, SeqRangeError> {
+        range.checked_for_seq_len(len).map(Range::from)
+    }
+}
+
+/// Resolve to a [`core::range::RangeFrom`] which fits a sequence of length `len`.
+impl TryFrom<WithLen<SeqRangeFrom<usize>>> for RangeFrom<usize> {
+    type Error = SeqRangeError;
+
+    fn try_from(WithLen(range, len): WithLen<SeqRangeFrom<usize>>) -> Result<Self, SeqRangeError> {
+        range.start.checked_for_seq_len(len).map(|start| RangeFrom { start })
+    }
+}
+
+/// Resolve to a [`core::range::RangeInclusive`] which fits a sequence of length `len`.
+///
+/// Returns an error for an empty range, such as `2..=1`, which `RangeInclusive` cannot
+/// represent without an end before its start. The error reports the inclusive end, as written.
+impl TryFrom<WithLen<SeqRangeInclusive<usize>>> for RangeInclusive<usize> {
+    type Error = SeqRangeError;
+
+    fn try_from(WithLen(range, len): WithLen<SeqRangeInclusive<usize>>) -> Result<Self, SeqRangeError> {
+        let resolved = range.checked_for_seq_len(len)?;
+        if resolved.is_empty() {
+            // the range is empty, so its inclusive end is one before its start, which is nonzero
+            return Err(SeqRangeError::Inverted { start: resolved.start, end: resolved.end - 1 });
+        }
+        Ok(RangeInclusive { start: resolved.start, last: resolved.end - 1 })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idx;
+
+    #[test]
+    fn test_new_range_conversions() {
+        assert_eq!(SeqRangeFrom::from(RangeFrom { start: 3 }), idx!(3..));
+        assert_eq!(SeqRangeInclusive::from(RangeInclusive { start: 1, last: 3 }), idx!(1..=3));
+        let vec: Vec<_> = (0..5).collect();
+        assert_eq!(vec[SeqRange::from(Range::from(1..3))], [1, 2]);
+
+        let resolve = |range| RangeInclusive::try_from(WithLen(range, 5));
+        assert_eq!(resolve(idx!(1..=^1)), Ok(RangeInclusive { start: 1, last: 4 }));
+        assert_eq!(resolve(idx!(^1..=^1)), Ok(RangeInclusive { start: 4, last: 4 }));
+        assert_eq!(resolve(idx!(2..=1)), Err(SeqRangeError::Inverted { start: 2, end: 1 }));
+        assert_eq!(resolve(idx!(^1..=^2)).unwrap_err().to_string(), "range starts at index 4 but ends at index 3");
+        assert!(resolve(idx!(1..=^0)).is_err());
+        assert_eq!(RangeFrom::try_from(WithLen(idx!(^2..), 5)), Ok(RangeFrom { start: 3 }));
+        assert!(RangeFrom::try_from(WithLen(idx!(^6..), 5)).is_err());
+        assert!(Range::try_from(WithLen(idx!(^1..1), 5)).is_err());
+    }
+}
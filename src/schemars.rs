@@ -0,0 +1,66 @@
+//! [`schemars::JsonSchema`](::schemars::JsonSchema) implementations, which describe the
+//! library's structs as the strings in [`idx!`](crate::idx) syntax that they are parsed from,
+//! as by [`FromStr`](std::str::FromStr) and the `serde` feature.
+
+use std::borrow::Cow;
+
+use ::schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::{SeqIndex, SeqRange, SeqRangeFrom, SeqRangeInclusive, Span};
+
+macro_rules! impl_json_schema {
+    ($($ty:ty => $name:literal, $pattern:literal, $description:literal;)*) => { $(
+        impl JsonSchema for $ty {
+            fn schema_name() -> Cow<'static, str> {
+                $name.into()
+            }
+
+            fn schema_id() -> Cow<'static, str> {
+                concat!("from_back::", $name).into()
+            }
+
+            fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                json_schema!({
+                    "type": "string",
+                    "pattern": $pattern,
+                    "description": $description,
+                })
+            }
+        }
+    )* };
+}
+
+impl_json_schema! {
+    SeqIndex<usize> => "SeqIndex", r"^\^?[0-9]+$",
+        "An index, counting from the back of the sequence if prefixed with `^`, eg `^2`.";
+    SeqRange<usize> => "SeqRange", r"^(\^?[0-9]+)?\.\.(\^?[0-9]+)?$",
+        "A range `start..end` of indices, where a missing start is `0` and a missing end is `^0`.";
+    SeqRangeFrom<usize> => "SeqRangeFrom", r"^(\^?[0-9]+)?\.\.$",
+        "A range `start..` of indices, where a missing start is `0`.";
+    SeqRangeInclusive<usize> => "SeqRangeInclusive", r"^(\^?[0-9]+)?\.\.=\^?[0-9]+$",
+        "A range `start..=end` of indices, where a missing start is `0`.";
+    Span => "Span", r"^(\^?[0-9]+)?\.\.(\^?[0-9]+)?$",
+        "A span `start..end` of source input, where a missing start is `0` and a missing end is `^0`.";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_schema() {
+        let schema = ::schemars::schema_for!(SeqRange<usize>);
+        assert_eq!(schema.get("type").unwrap(), "string");
+        assert_eq!(schema.get("pattern").unwrap(), r"^(\^?[0-9]+)?\.\.(\^?[0-9]+)?$");
+
+        #[derive(JsonSchema)]
+        #[allow(dead_code)]
+        struct Config {
+            window: SeqRange<usize>,
+            last: SeqIndex<usize>,
+        }
+        let schema = ::schemars::schema_for!(Config).as_value().to_string();
+        assert!(schema.contains(r##""$ref":"#/$defs/SeqIndex""##));
+        assert!(schema.contains(r#""pattern":"^\\^?[0-9]+$""#));
+    }
+}
@@ -0,0 +1,61 @@
+//! [`serde`](::serde) implementations, which represent the library's structs as strings
+//! in [`idx!`](crate::idx) syntax, eg `"^3"` or `"2..^1"`.
+//!
+//! Strings are printed by [`Display`](std::fmt::Display) and parsed by
+//! [`FromStr`](std::str::FromStr), so a serialized value reads as it would be written in code.
+//! A [`Span`] is represented by its range.
+
+use std::borrow::Cow;
+
+use ::serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{SeqIndex, SeqRange, SeqRangeFrom, SeqRangeInclusive, Span};
+
+macro_rules! impl_serde_str {
+    ($($ty:ty),*) => { $(
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                Cow::<'de, str>::deserialize(deserializer)?.parse().map_err(de::Error::custom)
+            }
+        }
+    )* };
+}
+
+impl_serde_str!(SeqIndex<usize>, SeqRange<usize>, SeqRangeFrom<usize>, SeqRangeInclusive<usize>);
+
+impl Serialize for Span {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.range.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Span {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        SeqRange::deserialize(deserializer).map(Span::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idx;
+
+    #[test]
+    fn test_serde_round_trip() {
+        assert_eq!(serde_json::to_string(&idx!(^3)).unwrap(), r#""^3""#);
+        assert_eq!(serde_json::to_string(&idx!(2..=^1)).unwrap(), r#""2..=^1""#);
+        assert_eq!(serde_json::to_string(&Span::from(idx!(1..^0))).unwrap(), r#""1..^0""#);
+        assert_eq!(serde_json::from_str::<SeqRange<usize>>(r#""..^2""#).unwrap(), idx!(0..^2));
+        assert_eq!(serde_json::from_str::<SeqRangeFrom<usize>>(r#""^2..""#).unwrap(), idx!(^2..));
+        assert_eq!(serde_json::from_str::<Span>(r#""^4..^1""#).unwrap(), Span::from(idx!(^4..^1)));
+        let err = serde_json::from_str::<SeqIndex<usize>>(r#""-1""#).unwrap_err();
+        assert!(err.to_string().starts_with("invalid index `-1`"));
+        assert!(serde_json::from_str::<SeqIndex<usize>>("3").is_err());
+    }
+}
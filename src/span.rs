@@ -0,0 +1,232 @@
+//! Source spans for lexers and parsers.
+
+use std::ops::Range;
+
+use crate::{SeqIndex, SeqRange};
+
+/// A span of source input, whose bounds may be anchored to the back of the input.
+///
+/// Back-anchored bounds allow spans such as "the rest of the input" (`idx!(n..^0)`)
+/// to be created before the length of the input is known, eg while streaming.
+/// Operations which compare bounds return `None` when the answer depends on the
+/// length of the input.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, Span};
+/// let keyword = Span::from(0..2);
+/// let rest = Span::from(idx!(3..^0));
+/// let source = "fn main() {}";
+///
+/// assert_eq!(keyword.text(source), Some("fn"));
+/// assert_eq!(rest.text(source), Some("main() {}"));
+/// assert_eq!(keyword.join(rest).unwrap().text(source), Some(source));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Span {
+    /// The bounds of the span.
+    pub range: SeqRange<usize>,
+}
+
+/// Whether `a <= b` for every input length which both bounds fit.
+fn known_le(a: SeqIndex<usize>, b: SeqIndex<usize>) -> bool {
+    match (a, b) {
+        (SeqIndex::FromFront(a), SeqIndex::FromFront(b)) => a <= b,
+        (SeqIndex::FromBack(a), SeqIndex::FromBack(b)) => a >= b,
+        (SeqIndex::FromFront(0), _) | (_, SeqIndex::FromBack(0)) => true,
+        _ => false,
+    }
+}
+
+/// The lesser of two bounds, if it can be determined without the input length.
+fn known_min(a: SeqIndex<usize>, b: SeqIndex<usize>) -> Option<SeqIndex<usize>> {
+    if known_le(a, b) {
+        Some(a)
+    } else if known_le(b, a) {
+        Some(b)
+    } else {
+        None
+    }
+}
+
+/// The greater of two bounds, if it can be determined without the input length.
+fn known_max(a: SeqIndex<usize>, b: SeqIndex<usize>) -> Option<SeqIndex<usize>> {
+    if known_le(a, b) {
+        Some(b)
+    } else if known_le(b, a) {
+        Some(a)
+    } else {
+        None
+    }
+}
+
+impl Span {
+    /// The smallest span covering both `self` and `other`.
+    ///
+    /// Returns `None` if that depends on the length of the input,
+    /// eg when joining `idx!(0..^2)` and `idx!(0..5)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, Span};
+    /// let a = Span::from(idx!(2..5));
+    /// assert_eq!(a.join(Span::from(idx!(4..7))), Some(Span::from(idx!(2..7))));
+    /// assert_eq!(a.join(Span::from(idx!(4..^0))), Some(Span::from(idx!(2..^0))));
+    /// assert_eq!(a.join(Span::from(idx!(4..^1))), None);
+    /// ```
+    pub fn join(self, other: Span) -> Option<Span> {
+        let start = known_min(self.range.start, other.range.start)?;
+        let end = known_max(self.range.end, other.range.end)?;
+        Some(Span { range: SeqRange { start, end } })
+    }
+
+    /// Whether the span contains the given offset into the input.
+    ///
+    /// Returns `None` if that depends on the length of the input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, Span};
+    /// let rest = Span::from(idx!(3..^0));
+    /// assert_eq!(rest.contains(2), Some(false));
+    /// assert_eq!(rest.contains(3), Some(true));
+    /// assert_eq!(Span::from(idx!(3..^1)).contains(3), None);
+    /// ```
+    pub fn contains(&self, offset: usize) -> Option<bool> {
+        let at = SeqIndex::FromFront(offset);
+        let after = SeqIndex::FromFront(offset.checked_add(1)?);
+        let starts_before = if known_le(self.range.start, at) {
+            Some(true)
+        } else if known_le(after, self.range.start) {
+            Some(false)
+        } else {
+            None
+        };
+        let ends_after = if known_le(after, self.range.end) {
+            Some(true)
+        } else if known_le(self.range.end, at) {
+            Some(false)
+        } else {
+            None
+        };
+        match (starts_before, ends_after) {
+            (Some(false), _) | (_, Some(false)) => Some(false),
+            (Some(true), Some(true)) => Some(true),
+            _ => None,
+        }
+    }
+
+    /// Extend the span by `n` in both directions.
+    ///
+    /// The span is not clamped to the input until it is resolved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, Span};
+    /// let span = Span::from(idx!(1..^3)).widen(2);
+    /// assert_eq!(span, Span::from(idx!(0..^1)));
+    /// ```
+    pub fn widen(self, n: usize) -> Span {
+        let start = match self.range.start {
+            SeqIndex::FromFront(idx) => SeqIndex::FromFront(idx.saturating_sub(n)),
+            SeqIndex::FromBack(idx) => SeqIndex::FromBack(idx.saturating_add(n)),
+        };
+        let end = match self.range.end {
+            SeqIndex::FromFront(idx) => SeqIndex::FromFront(idx.saturating_add(n)),
+            SeqIndex::FromBack(idx) => SeqIndex::FromBack(idx.saturating_sub(n)),
+        };
+        Span { range: SeqRange { start, end } }
+    }
+
+    /// Resolve to a native [`Range`] within an input of the given `len`.
+    ///
+    /// Bounds which fall outside of the input are clamped to it, and
+    /// a span whose end falls before its start resolves to an empty range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, Span};
+    /// assert_eq!(Span::from(idx!(2..^2)).resolve(10), 2..8);
+    /// assert_eq!(Span::from(idx!(^20..20)).resolve(10), 0..10);
+    /// ```
+    pub fn resolve(&self, len: usize) -> Range<usize> {
+        let clamp = |index: SeqIndex<usize>| match index {
+            SeqIndex::FromFront(idx) => idx.min(len),
+            SeqIndex::FromBack(idx) => len.saturating_sub(idx),
+        };
+        let start = clamp(self.range.start);
+        let end = clamp(self.range.end).max(start);
+        start..end
+    }
+
+    /// The source text covered by the span.
+    ///
+    /// Returns `None` if either resolved bound does not fall on a char boundary.
+    pub fn text<'a>(&self, source: &'a str) -> Option<&'a str> {
+        source.get(self.resolve(source.len()))
+    }
+
+    /// The elements of the input covered by the span, eg a slice of tokens.
+    pub fn slice<'a, T>(&self, source: &'a [T]) -> &'a [T] {
+        &source[self.resolve(source.len())]
+    }
+}
+
+impl From<SeqRange<usize>> for Span {
+    fn from(range: SeqRange<usize>) -> Self {
+        Span { range }
+    }
+}
+
+impl From<Range<usize>> for Span {
+    fn from(range: Range<usize>) -> Self {
+        Span {
+            range: SeqRange {
+                start: SeqIndex::FromFront(range.start),
+                end: SeqIndex::FromFront(range.end),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idx;
+
+    #[test]
+    fn test_join() {
+        let front = Span::from(idx!(0..^3));
+        assert_eq!(front.join(Span::from(idx!(^5..^1))), Some(Span::from(idx!(0..^1))));
+        assert_eq!(front.join(Span::from(idx!(2..4))), None);
+        let back = Span::from(idx!(^5..^3));
+        assert_eq!(back.join(Span::from(idx!(^8..^4))), Some(Span::from(idx!(^8..^3))));
+        assert_eq!(back.join(Span::from(idx!(1..2))), None);
+    }
+
+    #[test]
+    fn test_contains() {
+        let span = Span::from(2..4);
+        assert_eq!(span.contains(1), Some(false));
+        assert_eq!(span.contains(3), Some(true));
+        assert_eq!(span.contains(4), Some(false));
+        let span = Span::from(idx!(^4..^2));
+        assert_eq!(span.contains(3), None);
+        assert_eq!(Span::from(idx!(0..^0)).contains(usize::MAX), None);
+        assert_eq!(Span::from(idx!(0..^0)).contains(123), Some(true));
+    }
+
+    #[test]
+    fn test_resolve() {
+        assert_eq!(Span::from(idx!(3..^0)).widen(5).resolve(4), 0..4);
+        assert_eq!(Span::from(idx!(^1..2)).resolve(10), 9..9);
+        let tokens: Vec<_> = (0..10).collect();
+        assert_eq!(Span::from(idx!(^3..^0)).slice(&tokens), [7, 8, 9]);
+        assert_eq!(Span::from(0..2).text("€"), None);
+    }
+}
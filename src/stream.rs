@@ -0,0 +1,385 @@
+//! Extension methods for asynchronous [`Stream`]s.
+//!
+//! From-back bounds are resolved against the stream's remaining length when its
+//! [size hint](Stream::size_hint) is exact, as for streams created by
+//! [`with_seq_len`](StreamSeqExt::with_seq_len). Otherwise they are applied with a
+//! buffer which holds at most as many items as the bound counts from the back.
+//!
+//! Bounds which fall outside of the stream are clamped to it.
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::ops::Range;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+
+use crate::{SeqIndex, SeqRange};
+
+/// Extension methods for streams which accept the library's structs.
+pub trait StreamSeqExt: Stream + Sized {
+    /// Resolve to the item at the given index.
+    ///
+    /// Resolves to `None` if the index does not refer to an item.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::idx;
+    /// # use from_back::stream::StreamSeqExt;
+    /// # use futures::executor::block_on;
+    /// let words = futures::stream::iter(["the", "quick", "brown", "fox"]);
+    /// assert_eq!(block_on(words.nth_seq(idx!(^2))), Some("brown"));
+    /// ```
+    fn nth_seq(self, index: SeqIndex<usize>) -> NthSeq<Self> {
+        let range = match index {
+            SeqIndex::FromFront(idx) => idx.checked_add(1).map(|end| SeqRange { start: index, end: SeqIndex::FromFront(end) }),
+            SeqIndex::FromBack(idx) => idx.checked_sub(1).map(|end| SeqRange { start: index, end: SeqIndex::FromBack(end) }),
+        };
+        // an index which cannot refer to an item selects nothing
+        let range = range.unwrap_or(SeqRange { start: SeqIndex::FromBack(0), end: SeqIndex::FromBack(0) });
+        NthSeq { slice: self.slice_seq(range) }
+    }
+
+    /// Yield only the items within the range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::idx;
+    /// # use from_back::stream::StreamSeqExt;
+    /// # use futures::executor::block_on;
+    /// # use futures::stream::StreamExt;
+    /// let middle: Vec<_> = block_on(futures::stream::iter(0..10).slice_seq(idx!(2..^5)).collect());
+    /// assert_eq!(middle, [2, 3, 4]);
+    /// ```
+    fn slice_seq(self, range: impl Into<SeqRange<usize>>) -> SliceSeq<Self> {
+        let mut range = range.into();
+        let (lower, upper) = self.size_hint();
+        if upper == Some(lower) {
+            let start = clamp(range.start, lower);
+            let end = clamp(range.end, lower).max(start);
+            range = SeqRange { start: SeqIndex::FromFront(start), end: SeqIndex::FromFront(end) };
+        }
+        SliceSeq { stream: self, range, pos: 0, buffer: VecDeque::new(), buffered: false, done: false }
+    }
+
+    /// Declare the number of items the stream will yield, so that from-back bounds
+    /// can be resolved without buffering.
+    ///
+    /// If the stream yields a different number of items, from-back bounds will be
+    /// resolved incorrectly, but nothing worse.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::idx;
+    /// # use from_back::stream::StreamSeqExt;
+    /// # use futures::executor::block_on;
+    /// # use futures::stream::StreamExt;
+    /// let evens = futures::stream::iter(0..10).filter(|n| std::future::ready(n % 2 == 0));
+    /// assert_eq!(block_on(evens.with_seq_len(5).nth_seq(idx!(^1))), Some(8));
+    /// ```
+    fn with_seq_len(self, len: usize) -> WithSeqLen<Self> {
+        WithSeqLen { stream: self, remaining: len }
+    }
+
+    /// Label each item with the first of the given windows which contains it,
+    /// for a stream of `len` items.
+    ///
+    /// Items within none of the windows are skipped.
+    /// The windows are resolved against `len` up front, so no items are buffered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::idx;
+    /// # use from_back::stream::StreamSeqExt;
+    /// # use futures::executor::block_on;
+    /// # use futures::stream::StreamExt;
+    /// let samples = futures::stream::iter(0..10);
+    /// let windows = [("warmup", idx!(..2)), ("steady", idx!(2..^2)), ("cooldown", idx!(^2..^0))];
+    /// let labeled: Vec<_> = block_on(samples.segment_seq(10, windows).collect());
+    /// assert_eq!(labeled[..3], [("warmup", 0), ("warmup", 1), ("steady", 2)]);
+    /// assert_eq!(labeled[8..], [("cooldown", 8), ("cooldown", 9)]);
+    /// ```
+    fn segment_seq<L, R, W>(self, len: usize, windows: W) -> Segments<Self, L>
+    where
+        L: Clone,
+        R: Into<SeqRange<usize>>,
+        W: IntoIterator<Item = (L, R)>,
+    {
+        let windows: Vec<_> = windows
+            .into_iter()
+            .map(|(label, range)| {
+                let range = range.into();
+                let start = clamp(range.start, len);
+                (label, start..clamp(range.end, len).max(start))
+            })
+            .collect();
+        let end = windows.iter().map(|(_, range)| range.end).max().unwrap_or(0);
+        Segments { stream: self, windows, pos: 0, end }
+    }
+}
+
+impl<S> StreamSeqExt for S
+where S: Stream {}
+
+/// Resolve an index against a known length, clamping it to the stream.
+fn clamp(index: SeqIndex<usize>, len: usize) -> usize {
+    match index {
+        SeqIndex::FromFront(idx) => idx.min(len),
+        SeqIndex::FromBack(idx) => len.saturating_sub(idx),
+    }
+}
+
+pin_project! {
+    /// A stream over the items of another stream within a range.
+    ///
+    /// Created by [`StreamSeqExt::slice_seq`].
+    #[must_use = "streams do nothing unless polled"]
+    pub struct SliceSeq<S>
+    where S: Stream {
+        #[pin]
+        stream: S,
+        range: SeqRange<usize>,
+        pos: usize,
+        buffer: VecDeque<S::Item>,
+        buffered: bool,
+        done: bool,
+    }
+}
+
+impl<S> Stream for SliceSeq<S>
+where S: Stream {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+        let mut this = self.project();
+        let start = match this.range.start {
+            SeqIndex::FromFront(start) => start,
+            SeqIndex::FromBack(start) => {
+                // read the whole stream, keeping only the last `start` items
+                while !*this.buffered {
+                    match ready!(this.stream.as_mut().poll_next(cx)) {
+                        Some(item) => {
+                            *this.pos += 1;
+                            if this.buffer.len() == start {
+                                this.buffer.pop_front();
+                            }
+                            if start > 0 {
+                                this.buffer.push_back(item);
+                            }
+                        }
+                        None => {
+                            let first = *this.pos - this.buffer.len();
+                            let end = match this.range.end {
+                                SeqIndex::FromFront(end) => end.saturating_sub(first),
+                                SeqIndex::FromBack(end) => this.buffer.len().saturating_sub(end),
+                            };
+                            this.buffer.truncate(end);
+                            *this.buffered = true;
+                        }
+                    }
+                }
+                return Poll::Ready(this.buffer.pop_front());
+            }
+        };
+        while !*this.done {
+            let Some(item) = ready!(this.stream.as_mut().poll_next(cx)) else {
+                *this.done = true;
+                break;
+            };
+            let pos = *this.pos;
+            *this.pos += 1;
+            if pos < start {
+                continue;
+            }
+            match this.range.end {
+                SeqIndex::FromFront(end) if pos < end => return Poll::Ready(Some(item)),
+                SeqIndex::FromFront(_) => *this.done = true,
+                SeqIndex::FromBack(end) => {
+                    this.buffer.push_back(item);
+                    if this.buffer.len() > end {
+                        return Poll::Ready(this.buffer.pop_front());
+                    }
+                }
+            }
+        }
+        Poll::Ready(None)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match (self.range.start, self.range.end) {
+            _ if self.buffered => (self.buffer.len(), Some(self.buffer.len())),
+            _ if self.done => (0, Some(0)),
+            (SeqIndex::FromFront(start), SeqIndex::FromFront(end)) => {
+                let (lower, upper) = self.stream.size_hint();
+                let skip = start.saturating_sub(self.pos);
+                let wanted = end.saturating_sub(self.pos.max(start));
+                let upper = upper.map_or(wanted, |upper| upper.saturating_sub(skip).min(wanted));
+                (lower.saturating_sub(skip).min(wanted), Some(upper))
+            }
+            _ => (0, None),
+        }
+    }
+}
+
+pin_project! {
+    /// A future which resolves to the item of a stream at an index.
+    ///
+    /// Created by [`StreamSeqExt::nth_seq`].
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct NthSeq<S>
+    where S: Stream {
+        #[pin]
+        slice: SliceSeq<S>,
+    }
+}
+
+impl<S> Future for NthSeq<S>
+where S: Stream {
+    type Output = Option<S::Item>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+        self.project().slice.poll_next(cx)
+    }
+}
+
+pin_project! {
+    /// A stream with a declared length.
+    ///
+    /// Created by [`StreamSeqExt::with_seq_len`].
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct WithSeqLen<S> {
+        #[pin]
+        stream: S,
+        remaining: usize,
+    }
+}
+
+impl<S> Stream for WithSeqLen<S>
+where S: Stream {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+        let this = self.project();
+        let item = ready!(this.stream.poll_next(cx));
+        *this.remaining = if item.is_some() { this.remaining.saturating_sub(1) } else { 0 };
+        Poll::Ready(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+pin_project! {
+    /// A stream of the items of another stream, labeled by the window containing them.
+    ///
+    /// Created by [`StreamSeqExt::segment_seq`].
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Segments<S, L> {
+        #[pin]
+        stream: S,
+        windows: Vec<(L, Range<usize>)>,
+        pos: usize,
+        end: usize,
+    }
+}
+
+impl<S, L> Stream for Segments<S, L>
+where
+    S: Stream,
+    L: Clone,
+{
+    type Item = (L, S::Item);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<(L, S::Item)>> {
+        let mut this = self.project();
+        // stop without polling further once every window is complete
+        while *this.pos < *this.end {
+            let Some(item) = ready!(this.stream.as_mut().poll_next(cx)) else {
+                *this.end = 0;
+                break;
+            };
+            let pos = *this.pos;
+            *this.pos += 1;
+            if let Some((label, _)) = this.windows.iter().find(|(_, range)| range.contains(&pos)) {
+                return Poll::Ready(Some((label.clone(), item)));
+            }
+        }
+        Poll::Ready(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use futures::stream::{self, StreamExt};
+
+    use super::*;
+    use crate::idx;
+
+    /// A stream of `0..n` with no size hint.
+    fn unsized_stream(n: i32) -> impl Stream<Item = i32> {
+        stream::iter(0..n).filter(|_| std::future::ready(true))
+    }
+
+    fn slice_seq(range: impl Into<SeqRange<usize>> + Copy) -> Vec<i32> {
+        let sized: Vec<_> = block_on(stream::iter(0..10).slice_seq(range).collect());
+        let unsized_: Vec<_> = block_on(unsized_stream(10).slice_seq(range).collect());
+        assert_eq!(sized, unsized_);
+        sized
+    }
+
+    #[test]
+    fn test_slice_seq() {
+        assert_eq!(slice_seq(idx!(2..5)), [2, 3, 4]);
+        assert_eq!(slice_seq(idx!(7..20)), [7, 8, 9]);
+        assert_eq!(slice_seq(idx!(7..^1)), [7, 8]);
+        assert_eq!(slice_seq(idx!(7..^5)), [0; 0]);
+        assert_eq!(slice_seq(idx!(^3..)), [7, 8, 9]);
+        assert_eq!(slice_seq(idx!(^3..^1)), [7, 8]);
+        assert_eq!(slice_seq(idx!(^3..8)), [7]);
+        assert_eq!(slice_seq(idx!(^3..2)), [0; 0]);
+        assert_eq!(slice_seq(idx!(^0..)), [0; 0]);
+        assert_eq!(slice_seq(idx!(^20..^8)), [0, 1]);
+    }
+
+    #[test]
+    fn test_nth_seq() {
+        for index in [idx!(0), idx!(9), idx!(10), idx!(^1), idx!(^10), idx!(^11), idx!(^0)] {
+            let expected = (0..10).collect::<Vec<_>>().get(index.checked_for_seq_len(10).unwrap_or(10)).copied();
+            assert_eq!(block_on(stream::iter(0..10).nth_seq(index)), expected);
+            assert_eq!(block_on(unsized_stream(10).nth_seq(index)), expected);
+        }
+        assert_eq!(block_on(stream::iter(0..10).nth_seq(idx!(usize::MAX))), None);
+    }
+
+    #[test]
+    fn test_size_hint() {
+        let slice = stream::iter(0..10).slice_seq(idx!(2..^3));
+        assert_eq!(slice.size_hint(), (5, Some(5)));
+        let slice = unsized_stream(10).slice_seq(idx!(2..5));
+        assert_eq!(slice.size_hint(), (0, Some(3)));
+        let slice = unsized_stream(10).with_seq_len(10).slice_seq(idx!(^3..));
+        assert_eq!(slice.size_hint(), (3, Some(3)));
+        assert_eq!(block_on(slice.collect::<Vec<_>>()), [7, 8, 9]);
+    }
+
+    #[test]
+    fn test_segment_seq() {
+        let windows = [('a', idx!(0..2)), ('b', idx!(^3..^1)), ('c', idx!(1..^2)), ('d', idx!(^20..^30))];
+        let labeled: Vec<_> = block_on(stream::iter(0..6).segment_seq(6, windows).collect());
+        assert_eq!(labeled, [('a', 0), ('a', 1), ('c', 2), ('b', 3), ('b', 4)]);
+        let labeled: Vec<_> = block_on(unsized_stream(3).segment_seq(6, [((), idx!(1..^0))]).collect());
+        assert_eq!(labeled, [((), 1), ((), 2)]);
+        let labeled: Vec<_> = block_on(stream::iter(0..6).segment_seq(6, Vec::<((), SeqRange<usize>)>::new()).collect());
+        assert!(labeled.is_empty());
+    }
+}
@@ -0,0 +1,178 @@
+//! Addressing one channel of an interleaved sequence.
+
+use std::iter::{StepBy, Take};
+use std::slice;
+
+use crate::{SeqIndex, SeqRange};
+
+/// One channel of an interleaved sequence: every `stride`-th element, starting at `channel`.
+///
+/// Indices and ranges are resolved against the channel rather than the whole sequence,
+/// so `idx!(^1)` is the channel's last element, and from-back bounds count back from it.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, Strided};
+/// // interleaved stereo samples: left, right, left, right, ...
+/// let samples = [1, -1, 2, -2, 3, -3, 4, -4];
+/// let left = Strided::new(2, 0).unwrap();
+/// let right = Strided::new(2, 1).unwrap();
+/// assert_eq!(left.get(&samples, idx!(^1)), Some(&4));
+/// assert_eq!(right.iter(&samples, idx!(1..^1)).unwrap().collect::<Vec<_>>(), [&-2, &-3]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Strided {
+    stride: usize,
+    channel: usize,
+}
+
+impl Strided {
+    /// The channel starting at `channel` out of `stride` interleaved channels.
+    ///
+    /// Returns `None` unless `channel < stride`.
+    pub const fn new(stride: usize, channel: usize) -> Option<Self> {
+        if channel < stride {
+            Some(Strided { stride, channel })
+        } else {
+            None
+        }
+    }
+
+    /// The number of interleaved channels.
+    pub const fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// The position of this channel's first element.
+    pub const fn channel(&self) -> usize {
+        self.channel
+    }
+
+    /// The number of elements of this channel within a sequence of length `len`.
+    ///
+    /// A trailing partial frame counts towards the channels it reaches.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::Strided;
+    /// assert_eq!(Strided::new(3, 1).unwrap().channel_len(7), 2);
+    /// assert_eq!(Strided::new(3, 0).unwrap().channel_len(7), 3);
+    /// ```
+    pub const fn channel_len(&self, len: usize) -> usize {
+        if len <= self.channel {
+            0
+        } else {
+            (len - self.channel).div_ceil(self.stride)
+        }
+    }
+
+    /// Resolve an index into this channel to a position in a sequence of length `len`,
+    /// or `None` if it does not refer to an element.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, Strided};
+    /// let right = Strided::new(2, 1).unwrap();
+    /// assert_eq!(right.resolve(idx!(^1), 8), Some(7));
+    /// assert_eq!(right.resolve(idx!(4), 8), None);
+    /// ```
+    pub fn resolve(&self, index: SeqIndex<usize>, len: usize) -> Option<usize> {
+        let channel_len = self.channel_len(len);
+        let index = index.checked_for_seq_len(channel_len).ok().filter(|&index| index < channel_len)?;
+        Some(self.channel + index * self.stride)
+    }
+
+    /// The element of this channel at the given index, or `None` if there is none.
+    pub fn get<'a, T>(&self, slice: &'a [T], index: SeqIndex<usize>) -> Option<&'a T> {
+        slice.get(self.resolve(index, slice.len())?)
+    }
+
+    /// The element of this channel at the given index mutably, or `None` if there is none.
+    pub fn get_mut<'a, T>(&self, slice: &'a mut [T], index: SeqIndex<usize>) -> Option<&'a mut T> {
+        slice.get_mut(self.resolve(index, slice.len())?)
+    }
+
+    /// Iterate over the elements of this channel within the range,
+    /// or `None` if the range does not fit the channel.
+    pub fn iter<'a, T>(&self, slice: &'a [T], range: impl Into<SeqRange<usize>>) -> Option<Take<StepBy<slice::Iter<'a, T>>>> {
+        let (start, count) = self.flat_range(range.into(), slice.len())?;
+        Some(slice[start..].iter().step_by(self.stride).take(count))
+    }
+
+    /// Iterate mutably over the elements of this channel within the range,
+    /// or `None` if the range does not fit the channel.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, Strided};
+    /// let mut samples = [1, -1, 2, -2, 3, -3];
+    /// // silence the last two samples of the right channel
+    /// Strided::new(2, 1).unwrap().iter_mut(&mut samples, idx!(^2..)).unwrap().for_each(|s| *s = 0);
+    /// assert_eq!(samples, [1, -1, 2, 0, 3, 0]);
+    /// ```
+    pub fn iter_mut<'a, T>(
+        &self,
+        slice: &'a mut [T],
+        range: impl Into<SeqRange<usize>>,
+    ) -> Option<Take<StepBy<slice::IterMut<'a, T>>>> {
+        let (start, count) = self.flat_range(range.into(), slice.len())?;
+        Some(slice[start..].iter_mut().step_by(self.stride).take(count))
+    }
+
+    /// Resolve a range of this channel to the position of its first element and its length.
+    fn flat_range(&self, range: SeqRange<usize>, len: usize) -> Option<(usize, usize)> {
+        let range = range.checked_for_seq_len(self.channel_len(len)).ok()?;
+        // an empty range may start past the end of the slice
+        let start = (self.channel + range.start * self.stride).min(len);
+        Some((start, range.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idx;
+
+    #[test]
+    fn test_channel_len() {
+        let channels: Vec<_> = (0..3).map(|channel| Strided::new(3, channel).unwrap()).collect();
+        for len in 0..10 {
+            let total: usize = channels.iter().map(|channel| channel.channel_len(len)).sum();
+            assert_eq!(total, len);
+        }
+        assert_eq!(Strided::new(3, 3), None);
+        assert_eq!(Strided::new(0, 0), None);
+    }
+
+    #[test]
+    fn test_strided() {
+        let frames: Vec<_> = (0..10).collect();
+        let middle = Strided::new(3, 1).unwrap();
+        assert_eq!(middle.get(&frames, idx!(0)), Some(&1));
+        assert_eq!(middle.get(&frames, idx!(^1)), Some(&7));
+        assert_eq!(middle.get(&frames, idx!(^3)), Some(&1));
+        assert_eq!(middle.get(&frames, idx!(^4)), None);
+        assert_eq!(middle.get(&frames, idx!(^0)), None);
+        let collect = |range: SeqRange<usize>| middle.iter(&frames, range).map(|iter| iter.copied().collect::<Vec<_>>());
+        assert_eq!(collect(idx!(0..^0)), Some(vec![1, 4, 7]));
+        assert_eq!(collect(idx!(^2..^0)), Some(vec![4, 7]));
+        assert_eq!(collect(idx!(^0..^0)), Some(vec![]));
+        assert_eq!(collect(idx!(0..4)), None);
+        let first = Strided::new(3, 0).unwrap();
+        assert_eq!(first.iter(&frames, idx!(^1..)).unwrap().collect::<Vec<_>>(), [&9]);
+        assert_eq!(first.iter(&frames[..0], idx!(0..0)).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_strided_mut() {
+        let mut frames: Vec<_> = (0..6).collect();
+        let last = Strided::new(2, 1).unwrap();
+        *last.get_mut(&mut frames, idx!(^1)).unwrap() = 50;
+        last.iter_mut(&mut frames, idx!(..2)).unwrap().for_each(|n| *n *= 10);
+        assert_eq!(frames, [0, 10, 2, 30, 4, 50]);
+    }
+}
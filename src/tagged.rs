@@ -0,0 +1,184 @@
+//! Tagging indices and lengths with the sequence they belong to.
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::Range;
+
+use crate::{SeqIndex, SeqRange, SeqRangeError, SeqRangeFrom, SeqRangeInclusive, WithLen};
+
+/// The length of a sequence, tagged with a type which names the sequence.
+///
+/// Pairs with [`Tagged`], whose indices and ranges only resolve against lengths of the same tag,
+/// so resolving an index meant for one buffer against the length of another fails to compile.
+/// The tag is a marker type and is never constructed, so this is the size of a [`usize`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, SeqLenOf, Tagged};
+/// enum Header {}
+/// enum Payload {}
+///
+/// let header = [0u8; 4];
+/// let payload = [0u8; 16];
+/// let header_len = SeqLenOf::<Header>::new(header.len());
+/// let payload_len = SeqLenOf::<Payload>::new(payload.len());
+///
+/// let checksum = Tagged::<_, Payload>::new(idx!(^2..));
+/// assert_eq!(checksum.resolve(payload_len), Ok(14..16));
+/// ```
+///
+/// ```rust,compile_fail
+/// # use from_back::{idx, SeqLenOf, Tagged};
+/// # enum Header {}
+/// # enum Payload {}
+/// let header_len = SeqLenOf::<Header>::new(4);
+/// let checksum = Tagged::<_, Payload>::new(idx!(^2..));
+/// checksum.resolve(header_len);
+/// ```
+pub struct SeqLenOf<Tag> {
+    len: usize,
+    tag: PhantomData<fn() -> Tag>,
+}
+
+impl<Tag> SeqLenOf<Tag> {
+    /// Tag the length `len`.
+    pub const fn new(len: usize) -> Self {
+        SeqLenOf { len, tag: PhantomData }
+    }
+
+    /// The untagged length.
+    pub const fn get(self) -> usize {
+        self.len
+    }
+}
+
+/// One of the library's indices or ranges, tagged with a type which names the sequence it is for.
+///
+/// See [`SeqLenOf`].
+pub struct Tagged<S, Tag> {
+    inner: S,
+    tag: PhantomData<fn() -> Tag>,
+}
+
+impl<S, Tag> Tagged<S, Tag> {
+    /// Tag an index or range.
+    pub const fn new(inner: S) -> Self {
+        Tagged { inner, tag: PhantomData }
+    }
+
+    /// The untagged index or range.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<Tag> Tagged<SeqIndex<usize>, Tag> {
+    /// Resolve the index against a sequence of the same tag.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index falls outside the sequence.
+    pub fn resolve(self, len: SeqLenOf<Tag>) -> Result<usize, SeqRangeError> {
+        usize::try_from(WithLen(self.inner, len.len))
+    }
+}
+
+macro_rules! impl_tagged_range {
+    ( $($range:ident),* ) => { $(
+        impl<Tag> Tagged<$range<usize>, Tag> {
+            /// Resolve the range against a sequence of the same tag.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the range does not fit the sequence.
+            pub fn resolve(self, len: SeqLenOf<Tag>) -> Result<Range<usize>, SeqRangeError> {
+                Range::try_from(WithLen(self.inner, len.len))
+            }
+        }
+    )* };
+}
+
+impl_tagged_range!(SeqRange, SeqRangeFrom, SeqRangeInclusive);
+
+// Implemented by hand, since derives would require the tag to implement each trait too.
+
+impl<Tag> Clone for SeqLenOf<Tag> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Tag> Copy for SeqLenOf<Tag> {}
+
+impl<Tag> PartialEq for SeqLenOf<Tag> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len
+    }
+}
+
+impl<Tag> Eq for SeqLenOf<Tag> {}
+
+impl<Tag> Hash for SeqLenOf<Tag> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+    }
+}
+
+impl<Tag> fmt::Debug for SeqLenOf<Tag> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SeqLenOf").field(&self.len).finish()
+    }
+}
+
+impl<S: Clone, Tag> Clone for Tagged<S, Tag> {
+    fn clone(&self) -> Self {
+        Tagged::new(self.inner.clone())
+    }
+}
+
+impl<S: Copy, Tag> Copy for Tagged<S, Tag> {}
+
+impl<S: PartialEq, Tag> PartialEq for Tagged<S, Tag> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<S: Eq, Tag> Eq for Tagged<S, Tag> {}
+
+impl<S: Hash, Tag> Hash for Tagged<S, Tag> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.hash(state);
+    }
+}
+
+impl<S: fmt::Debug, Tag> fmt::Debug for Tagged<S, Tag> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Tagged").field(&self.inner).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idx;
+
+    struct Rows;
+
+    #[test]
+    fn test_tagged() {
+        let len = SeqLenOf::<Rows>::new(5);
+        assert_eq!(len.get(), 5);
+        assert_eq!(Tagged::<_, Rows>::new(idx!(^1)).resolve(len), Ok(4));
+        assert!(Tagged::<_, Rows>::new(idx!(^6)).resolve(len).is_err());
+        assert_eq!(Tagged::<_, Rows>::new(idx!(1..^1)).resolve(len), Ok(1..4));
+        assert_eq!(Tagged::<_, Rows>::new(idx!(^2..)).resolve(len), Ok(3..5));
+        assert_eq!(Tagged::<_, Rows>::new(idx!(1..=^1)).resolve(len), Ok(1..5));
+        let index = Tagged::<_, Rows>::new(idx!(2));
+        assert_eq!(index, index.clone());
+        assert_eq!(format!("{index:?} {len:?}"), "Tagged(FromFront(2)) SeqLenOf(5)");
+        assert_eq!(index.into_inner(), idx!(2));
+    }
+}
@@ -0,0 +1,140 @@
+//! Reading the end of seekable input without reading the rest of it.
+//!
+//! Each function seeks from the end of the input, and returns the data along with
+//! the byte range it was read from, counted from the front of the input.
+
+use std::io::{self, Read, Seek, SeekFrom};
+
+use crate::{SeqIndex, SeqRangeFrom};
+
+/// The size of the blocks scanned backwards while searching for line starts.
+const BLOCK_LEN: usize = 8 * 1024;
+
+/// Read the last `n` bytes of the input, or all of it if it is shorter.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::idx;
+/// # use from_back::tail::read_last_bytes;
+/// let mut file = std::io::Cursor::new(b"payload\xbe\xef");
+/// let (data, range) = read_last_bytes(&mut file, 2).unwrap();
+/// assert_eq!(data, [0xbe, 0xef]);
+/// assert_eq!(range, idx!(7..));
+/// ```
+pub fn read_last_bytes<R>(reader: &mut R, n: u64) -> io::Result<(Vec<u8>, SeqRangeFrom<u64>)>
+where R: Read + Seek {
+    let len = reader.seek(SeekFrom::End(0))?;
+    let start = len.saturating_sub(n);
+    Ok((read_from(reader, start)?, SeqRangeFrom { start: SeqIndex::FromFront(start) }))
+}
+
+/// Read the last `n` lines of the input, or all of it if it has fewer, as `tail -n` does.
+///
+/// A newline at the very end of the input ends the last line rather than starting another.
+/// The returned text keeps its line endings.
+/// Only the blocks containing the last `n` lines are read, so this is suitable for large files.
+///
+/// # Errors
+///
+/// As well as any I/O errors, returns an error of kind [`io::ErrorKind::InvalidData`]
+/// if the lines are not valid UTF-8.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::idx;
+/// # use from_back::tail::read_last_lines;
+/// let mut log = std::io::Cursor::new("one\ntwo\nthree\n");
+/// let (lines, range) = read_last_lines(&mut log, 2).unwrap();
+/// assert_eq!(lines, "two\nthree\n");
+/// assert_eq!(range, idx!(4..));
+/// ```
+pub fn read_last_lines<R>(reader: &mut R, n: usize) -> io::Result<(String, SeqRangeFrom<u64>)>
+where R: Read + Seek {
+    let len = reader.seek(SeekFrom::End(0))?;
+    let start = lines_start(reader, len, n, BLOCK_LEN)?;
+    let lines = String::from_utf8(read_from(reader, start)?)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+    Ok((lines, SeqRangeFrom { start: SeqIndex::FromFront(start) }))
+}
+
+/// Read everything from `start` to the end of the input.
+fn read_from<R>(reader: &mut R, start: u64) -> io::Result<Vec<u8>>
+where R: Read + Seek {
+    reader.seek(SeekFrom::Start(start))?;
+    let mut data = Vec::new();
+    reader.read_to_end(&mut data)?;
+    Ok(data)
+}
+
+/// Find the offset of the start of the last `n` lines, scanning backwards in blocks.
+fn lines_start<R>(reader: &mut R, len: u64, n: usize, block_len: usize) -> io::Result<u64>
+where R: Read + Seek {
+    if n == 0 {
+        return Ok(len);
+    }
+    let mut block = vec![0; block_len];
+    let mut newlines = 0;
+    // skip the last byte: a trailing newline does not start another line
+    let mut end = len.saturating_sub(1);
+    while end > 0 {
+        let start = end.saturating_sub(block_len as u64);
+        let block = &mut block[..(end - start) as usize];
+        reader.seek(SeekFrom::Start(start))?;
+        reader.read_exact(block)?;
+        for (i, _) in block.iter().enumerate().rev().filter(|&(_, &byte)| byte == b'\n') {
+            newlines += 1;
+            if newlines == n {
+                return Ok(start + i as u64 + 1);
+            }
+        }
+        end = start;
+    }
+    Ok(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn test_read_last_bytes() {
+        let mut input = Cursor::new(b"0123456789");
+        assert_eq!(read_last_bytes(&mut input, 3).unwrap().0, b"789");
+        assert_eq!(read_last_bytes(&mut input, 0).unwrap().0, b"");
+        let (data, range) = read_last_bytes(&mut input, 20).unwrap();
+        assert_eq!(data, b"0123456789");
+        assert_eq!(range.start, SeqIndex::FromFront(0));
+    }
+
+    #[test]
+    fn test_read_last_lines() {
+        let lines = |input: &str, n| read_last_lines(&mut Cursor::new(input), n).unwrap().0;
+        assert_eq!(lines("a\nb\nc\n", 1), "c\n");
+        assert_eq!(lines("a\nb\nc", 2), "b\nc");
+        assert_eq!(lines("a\nb\nc", 3), "a\nb\nc");
+        assert_eq!(lines("a\nb\nc", 4), "a\nb\nc");
+        assert_eq!(lines("a\nb\nc", 0), "");
+        assert_eq!(lines("a\n\n\n", 2), "\n\n");
+        assert_eq!(lines("\n", 1), "\n");
+        assert_eq!(lines("", 1), "");
+        let err = read_last_lines(&mut Cursor::new(b"\xff\nok"), 2).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_lines_start_blocks() {
+        let input = "alpha\nbeta\ngamma\ndelta\n";
+        for block_len in 1..8 {
+            let mut reader = Cursor::new(input);
+            let mut start = |n| lines_start(&mut reader, input.len() as u64, n, block_len).unwrap();
+            assert_eq!(start(1), 17);
+            assert_eq!(start(3), 6);
+            assert_eq!(start(4), 0);
+            assert_eq!(start(5), 0);
+        }
+    }
+}
@@ -0,0 +1,432 @@
+//! Alternative resolution modes for indexing into text.
+//!
+//! Natively, indices into a [`str`] count bytes. The [`StrIndex`] and [`StrRange`] types
+//! instead count in some [`TextUnit`], such as [`Chars`] or [`Utf16`] code units,
+//! and resolve to byte offsets once applied to a particular string.
+
+use std::marker::PhantomData;
+use std::ops::*;
+
+use crate::{SeqIndex, SeqRange, SeqRangeFrom, SeqRangeInclusive};
+
+/// A unit in which to count positions within a [`str`].
+pub trait TextUnit {
+    /// Resolve `index`, counted in this unit, to a byte offset within `s`.
+    ///
+    /// Returns `None` if the index falls outside of `s`, or does not fall on a boundary between units.
+    fn byte_offset(s: &str, index: SeqIndex<usize>) -> Option<usize>;
+}
+
+/// Count bytes, as native [`str`] indexing does.
+///
+/// Unlike native indexing, positions which are not char boundaries do not resolve.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Bytes;
+
+/// Count [`char`]s.
+///
+/// Each bound is resolved by iterating from its own anchor, so a from-back bound
+/// only decodes the chars between it and the back of the string, and the
+/// string's total char count is never computed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Chars;
+
+/// Count UTF-16 code units.
+///
+/// This is the unit used for positions by eg JavaScript and the Language Server Protocol.
+/// As with [`Chars`], each bound is resolved by iterating from its own anchor.
+/// A bound which falls between the two halves of a surrogate pair does not resolve.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Utf16;
+
+/// Count lines, as split by [`str::lines`].
+///
+/// A line's position is the byte offset of its start, and the line count's position is
+/// the end of the string, so ranges of lines include their line terminators.
+/// A from-back bound only scans the lines between it and the back of the string.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Lines;
+
+/// Count extended grapheme clusters, ie user-perceived characters.
+///
+/// As with [`Chars`], each bound is resolved by iterating from its own anchor.
+#[cfg(feature = "unicode")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Graphemes;
+
+/// Count display columns, as a terminal renders them.
+///
+/// Wide characters, such as most CJK characters and emoji, occupy two columns,
+/// and zero-width characters, such as combining marks, belong with the character before them.
+/// A bound which falls between the two columns of a wide character does not resolve.
+/// As with [`Chars`], each bound is resolved by iterating from its own anchor.
+#[cfg(feature = "width")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Columns;
+
+impl TextUnit for Bytes {
+    fn byte_offset(s: &str, index: SeqIndex<usize>) -> Option<usize> {
+        let offset = index.checked_for_seq_len(s.len()).ok()?;
+        s.is_char_boundary(offset).then_some(offset)
+    }
+}
+
+impl TextUnit for Chars {
+    fn byte_offset(s: &str, index: SeqIndex<usize>) -> Option<usize> {
+        unit_offset(s, index, |_| 1)
+    }
+}
+
+impl TextUnit for Utf16 {
+    fn byte_offset(s: &str, index: SeqIndex<usize>) -> Option<usize> {
+        unit_offset(s, index, char::len_utf16)
+    }
+}
+
+impl TextUnit for Lines {
+    fn byte_offset(s: &str, index: SeqIndex<usize>) -> Option<usize> {
+        // a trailing newline terminates the last line, rather than starting an empty one
+        let body = s.strip_suffix('\n').unwrap_or(s);
+        let mut newlines = 0;
+        match index {
+            SeqIndex::FromFront(0) => Some(0),
+            SeqIndex::FromFront(idx) => {
+                for (offset, _) in s.match_indices('\n') {
+                    newlines += 1;
+                    if newlines == idx {
+                        return Some(offset + 1);
+                    }
+                }
+                (newlines + 1 == idx && !s.is_empty() && !s.ends_with('\n')).then_some(s.len())
+            }
+            SeqIndex::FromBack(0) => Some(s.len()),
+            SeqIndex::FromBack(idx) => {
+                for (offset, _) in body.rmatch_indices('\n') {
+                    newlines += 1;
+                    if newlines == idx {
+                        return Some(offset + 1);
+                    }
+                }
+                (newlines + 1 == idx && !s.is_empty()).then_some(0)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "width")]
+impl TextUnit for Columns {
+    fn byte_offset(s: &str, index: SeqIndex<usize>) -> Option<usize> {
+        unit_offset(s, index, |c| unicode_width::UnicodeWidthChar::width(c).unwrap_or(0))
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl TextUnit for Graphemes {
+    fn byte_offset(s: &str, index: SeqIndex<usize>) -> Option<usize> {
+        use std::iter;
+
+        use unicode_segmentation::UnicodeSegmentation;
+
+        match index {
+            SeqIndex::FromFront(idx) => s
+                .grapheme_indices(true)
+                .map(|(offset, _)| offset)
+                .chain(iter::once(s.len()))
+                .nth(idx),
+            SeqIndex::FromBack(idx) => iter::once(s.len())
+                .chain(s.grapheme_indices(true).rev().map(|(offset, _)| offset))
+                .nth(idx),
+        }
+    }
+}
+
+/// The byte offset of the char boundary `index` units from its anchor, if any.
+///
+/// Chars of zero units are kept with the char before them.
+fn unit_offset(s: &str, index: SeqIndex<usize>, unit_len: fn(char) -> usize) -> Option<usize> {
+    let mut units = 0;
+    match index {
+        SeqIndex::FromFront(idx) => {
+            for (offset, c) in s.char_indices() {
+                if units >= idx && unit_len(c) > 0 {
+                    return (units == idx).then_some(offset);
+                }
+                units += unit_len(c);
+            }
+            (units == idx).then_some(s.len())
+        }
+        SeqIndex::FromBack(idx) => {
+            for (offset, c) in s.char_indices().rev() {
+                if units >= idx {
+                    break;
+                }
+                units += unit_len(c);
+                if units == idx {
+                    return Some(offset);
+                }
+            }
+            (units == idx).then_some(s.len())
+        }
+    }
+}
+
+// ===
+
+/// A [`SeqIndex`] into a [`str`] which counts in the given [`TextUnit`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, Chars, Utf16};
+/// // '𝄞' is one char, two UTF-16 code units, and four bytes
+/// let s = "𝄞 clef";
+/// assert_eq!(Chars::at(idx!(^4)).byte_offset(s), Some(5));
+/// assert_eq!(Utf16::at(idx!(2)).byte_offset(s), Some(4));
+/// assert_eq!(Utf16::at(idx!(1)).byte_offset(s), None);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct StrIndex<U> {
+    /// The index, counted in units of `U`.
+    pub index: SeqIndex<usize>,
+    unit: PhantomData<U>,
+}
+
+impl<U> StrIndex<U> {
+    /// Count the given index in units of `U`.
+    pub fn new(index: SeqIndex<usize>) -> Self {
+        StrIndex { index, unit: PhantomData }
+    }
+}
+
+impl<U> StrIndex<U>
+where U: TextUnit {
+    /// Resolve to a native byte offset within `s`.
+    ///
+    /// Returns `None` if the index does not fall on a unit boundary within `s`.
+    pub fn byte_offset(&self, s: &str) -> Option<usize> {
+        U::byte_offset(s, self.index)
+    }
+}
+
+/// One of the library's ranges over a [`str`], which counts in the given [`TextUnit`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, Chars, Utf16};
+/// let s = "¡señor!";
+/// assert_eq!(&s[Chars::range(idx!(1..^1))], "señor");
+/// assert_eq!(&s[Chars::range(idx!(^3..))], "or!");
+///
+/// // '𝄞' is two UTF-16 code units
+/// assert_eq!(&"𝄞 clef"[Utf16::range(idx!(3..))], "clef");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct StrRange<U, R = SeqRange<usize>> {
+    /// The range, counted in units of `U`.
+    pub range: R,
+    unit: PhantomData<U>,
+}
+
+impl<U, R> StrRange<U, R> {
+    /// Count the given range in units of `U`.
+    pub fn new(range: R) -> Self {
+        StrRange { range, unit: PhantomData }
+    }
+}
+
+/// The byte range between two bounds, if it fits the string.
+fn unit_range<U>(s: &str, start: SeqIndex<usize>, end: SeqIndex<usize>) -> Option<Range<usize>>
+where U: TextUnit {
+    let start = U::byte_offset(s, start)?;
+    let end = U::byte_offset(s, end)?;
+    (start <= end).then_some(start..end)
+}
+
+impl<U> StrRange<U, SeqRange<usize>>
+where U: TextUnit {
+    /// Resolve to a native byte [`Range`] within `s`, or `None` if the range does not fit.
+    pub fn byte_range(&self, s: &str) -> Option<Range<usize>> {
+        unit_range::<U>(s, self.range.start, self.range.end)
+    }
+}
+
+impl<U> StrRange<U, SeqRangeFrom<usize>>
+where U: TextUnit {
+    /// Resolve to a native byte [`Range`] within `s`, or `None` if the range does not fit.
+    pub fn byte_range(&self, s: &str) -> Option<Range<usize>> {
+        unit_range::<U>(s, self.range.start, SeqIndex::FromBack(0))
+    }
+}
+
+impl<U> StrRange<U, SeqRangeInclusive<usize>>
+where U: TextUnit {
+    /// Resolve to a native byte [`Range`] within `s`, or `None` if the range does not fit.
+    pub fn byte_range(&self, s: &str) -> Option<Range<usize>> {
+        let end = match self.range.end {
+            SeqIndex::FromFront(idx) => SeqIndex::FromFront(idx.checked_add(1)?),
+            SeqIndex::FromBack(idx) => SeqIndex::FromBack(idx.checked_sub(1)?),
+        };
+        unit_range::<U>(s, self.range.start, end)
+    }
+}
+
+macro_rules! impl_unit_constructors {
+    ($($unit:ty),*) => { $(
+        impl $unit {
+            /// Count the given index in this unit.
+            pub fn at(index: SeqIndex<usize>) -> StrIndex<Self> {
+                StrIndex::new(index)
+            }
+
+            /// Count the given range in this unit.
+            pub fn range<R>(range: R) -> StrRange<Self, R> {
+                StrRange::new(range)
+            }
+        }
+    )* };
+}
+
+impl_unit_constructors!(Bytes, Chars, Lines, Utf16);
+#[cfg(feature = "unicode")]
+impl_unit_constructors!(Graphemes);
+#[cfg(feature = "width")]
+impl_unit_constructors!(Columns);
+
+macro_rules! impl_str_range_index {
+    ($($rng:ty),*) => { $(
+        impl<U> Index<StrRange<U, $rng>> for str
+        where U: TextUnit {
+            type Output = str;
+
+            fn index(&self, rng: StrRange<U, $rng>) -> &str {
+                let range = rng.byte_range(self).unwrap_or_else(|| {
+                    panic!("range {:?} does not fit string", rng.range)
+                });
+                &self[range]
+            }
+        }
+
+        impl<U> Index<StrRange<U, $rng>> for String
+        where U: TextUnit {
+            type Output = str;
+
+            fn index(&self, rng: StrRange<U, $rng>) -> &str {
+                &self.as_str()[rng]
+            }
+        }
+    )* };
+}
+
+impl_str_range_index!(SeqRange<usize>, SeqRangeFrom<usize>, SeqRangeInclusive<usize>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idx;
+
+    #[test]
+    fn test_bytes() {
+        let s = "añb€";
+        assert_eq!(&s[Bytes::range(idx!(1..^3))], "ñb");
+        assert_eq!(Bytes::at(idx!(^3)).byte_offset(s), Some(4));
+        assert_eq!(Bytes::at(idx!(^2)).byte_offset(s), None);
+        assert_eq!(Bytes::at(idx!(8)).byte_offset(s), None);
+    }
+
+    #[test]
+    fn test_chars() {
+        let s = "añb€".to_string();
+        assert_eq!(&s[Chars::range(idx!(1..^1))], "ñb");
+        assert_eq!(&s[Chars::range(idx!(^4..^0))], s);
+        assert_eq!(&s[Chars::range(idx!(4..))], "");
+        assert_eq!(&s[Chars::range(idx!(1..=^2))], "ñb");
+        assert_eq!(&s[Chars::range(idx!(^1..=^1))], "€");
+        assert_eq!(&s[Chars::range(idx!(..=3))], s);
+    }
+
+    #[test]
+    fn test_chars_byte_range() {
+        let s = "añb€";
+        assert_eq!(Chars::range(idx!(^1..)).byte_range(s), Some(4..7));
+        assert_eq!(Chars::range(idx!(^5..)).byte_range(s), None);
+        assert_eq!(Chars::range(idx!(5..)).byte_range(s), None);
+        assert_eq!(Chars::range(idx!(^1..1)).byte_range(s), None);
+        assert_eq!(Chars::range(idx!(..=^0)).byte_range(s), None);
+        assert_eq!(Chars::range(idx!(..=4)).byte_range(s), None);
+        assert_eq!(Chars::at(idx!(^0)).byte_offset(""), Some(0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_chars_out_of_range() {
+        let _ = &"añb€"[Chars::range(idx!(^5..))];
+    }
+
+    #[test]
+    fn test_utf16() {
+        // code units: 'a' = 1, '€' = 1, '𝄞' = 2
+        let s = "a€𝄞b".to_string();
+        assert_eq!(&s[Utf16::range(idx!(1..^1))], "€𝄞");
+        assert_eq!(&s[Utf16::range(idx!(^3..))], "𝄞b");
+        assert_eq!(&s[Utf16::range(idx!(2..=^1))], "𝄞b");
+        assert_eq!(Utf16::at(idx!(2)).byte_offset(&s), Some(4));
+        assert_eq!(Utf16::at(idx!(3)).byte_offset(&s), None);
+        assert_eq!(Utf16::at(idx!(4)).byte_offset(&s), Some(8));
+        assert_eq!(Utf16::at(idx!(5)).byte_offset(&s), Some(9));
+        assert_eq!(Utf16::at(idx!(6)).byte_offset(&s), None);
+        assert_eq!(Utf16::at(idx!(^2)).byte_offset(&s), None);
+        assert_eq!(Utf16::at(idx!(^5)).byte_offset(&s), Some(0));
+        assert_eq!(Utf16::at(idx!(^6)).byte_offset(&s), None);
+    }
+
+    #[test]
+    fn test_lines() {
+        let offsets = |s: &str, index| Lines::byte_offset(s, index);
+        let s = "a\n\nbc\n";
+        let front: Vec<_> = (0..5).map(|idx| offsets(s, SeqIndex::FromFront(idx))).collect();
+        assert_eq!(front, [Some(0), Some(2), Some(3), Some(6), None]);
+        let back: Vec<_> = (0..5).map(|idx| offsets(s, SeqIndex::FromBack(idx))).collect();
+        assert_eq!(back, [Some(6), Some(3), Some(2), Some(0), None]);
+
+        let s = "a\nbc";
+        let front: Vec<_> = (0..4).map(|idx| offsets(s, SeqIndex::FromFront(idx))).collect();
+        assert_eq!(front, [Some(0), Some(2), Some(4), None]);
+        let back: Vec<_> = (0..4).map(|idx| offsets(s, SeqIndex::FromBack(idx))).collect();
+        assert_eq!(back, [Some(4), Some(2), Some(0), None]);
+
+        assert_eq!(offsets("", idx!(0)), Some(0));
+        assert_eq!(offsets("", idx!(1)), None);
+        assert_eq!(offsets("", idx!(^1)), None);
+        assert_eq!(offsets("\n", idx!(1)), Some(1));
+        assert_eq!(offsets("\n", idx!(^1)), Some(0));
+        assert_eq!(offsets("\n", idx!(2)), None);
+        assert_eq!(&"one\r\ntwo\r\n"[Lines::range(idx!(^1..))], "two\r\n");
+        assert_eq!(&"one\ntwo\nthree"[Lines::range(idx!(1..=1))], "two\n");
+    }
+
+    #[test]
+    #[cfg(feature = "width")]
+    fn test_columns() {
+        // '日' and '本' are two columns each, and U+301 is a zero-width combining accent
+        let s = "日本e\u{301}x";
+        assert_eq!(&s[Columns::range(idx!(^2..))], "e\u{301}x");
+        assert_eq!(&s[Columns::range(idx!(..^2))], "日本");
+        assert_eq!(&s[Columns::range(idx!(2..5))], "本e\u{301}");
+        assert_eq!(Columns::at(idx!(1)).byte_offset(s), None);
+        assert_eq!(Columns::at(idx!(^5)).byte_offset(s), None);
+        assert_eq!(Columns::at(idx!(^6)).byte_offset(s), Some(0));
+        assert_eq!(Columns::at(idx!(6)).byte_offset(s), Some(s.len()));
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_graphemes() {
+        // "é" as 'e' followed by a combining acute accent
+        let s = "cafe\u{301}s";
+        assert_eq!(&s[Graphemes::range(idx!(^2..^1))], "e\u{301}");
+        assert_eq!(&s[Graphemes::range(idx!(3..=3))], "e\u{301}");
+        assert_eq!(Graphemes::at(idx!(^5)).byte_offset(s), Some(0));
+        assert_eq!(Graphemes::at(idx!(^6)).byte_offset(s), None);
+    }
+}
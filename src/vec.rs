@@ -0,0 +1,266 @@
+//! Extension methods for [`Vec`].
+
+use std::ops::Range;
+
+use crate::{SeqIndex, SeqRange, SeqRangeError};
+
+/// Extension methods for [`Vec`] which accept the library's structs
+/// in place of native indices and ranges.
+pub trait VecSeqExt<T> {
+    /// Replace the given range with the elements of `replacement`,
+    /// returning a record of the change which can be undone.
+    ///
+    /// See [`Vec::splice`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the vector unchanged, if the range does not fit the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, VecSeqExt};
+    /// let mut vec = vec![8, 6, 7, 5, 3, 0, 9];
+    /// let record = vec.splice_seq(idx!(^3..^1), [1, 2, 3]).unwrap();
+    /// assert_eq!(vec, [8, 6, 7, 5, 1, 2, 3, 9]);
+    /// assert_eq!(record.removed(), [3, 0]);
+    /// assert_eq!(record.range(), 4..6);
+    ///
+    /// let redo = record.undo(&mut vec).unwrap();
+    /// assert_eq!(vec, [8, 6, 7, 5, 3, 0, 9]);
+    /// redo.undo(&mut vec).unwrap();
+    /// assert_eq!(vec, [8, 6, 7, 5, 1, 2, 3, 9]);
+    /// ```
+    fn splice_seq<I>(&mut self, range: SeqRange<usize>, replacement: I) -> Result<SpliceRecord<T>, SeqRangeError>
+    where I: IntoIterator<Item = T>;
+
+    /// Retain only the elements within the given range for which `keep` returns `true`,
+    /// shifting the elements after the range toward the front.
+    ///
+    /// Elements outside the range are kept, and `keep` is called once for each element
+    /// within the range, in order. See [`Vec::retain`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the vector unchanged, if the range does not fit the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, VecSeqExt};
+    /// // strip blank lines, keeping the header and footer
+    /// let mut lines = vec!["# header", "", "a", "", "b", ""];
+    /// lines.retain_seq(idx!(1..^1), |line| !line.is_empty()).unwrap();
+    /// assert_eq!(lines, ["# header", "a", "b", ""]);
+    /// ```
+    fn retain_seq<F>(&mut self, range: SeqRange<usize>, keep: F) -> Result<(), SeqRangeError>
+    where F: FnMut(&T) -> bool;
+
+    /// Remove consecutive repeated elements within the given range,
+    /// shifting the elements after the range toward the front.
+    ///
+    /// Elements are only compared with others within the range,
+    /// so an element at either edge is kept even if it equals its neighbour outside the range.
+    /// See [`Vec::dedup`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the vector unchanged, if the range does not fit the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, VecSeqExt};
+    /// let mut vec = vec![1, 1, 2, 2, 2, 3, 3];
+    /// vec.dedup_seq(idx!(1..^1)).unwrap();
+    /// assert_eq!(vec, [1, 1, 2, 3, 3]);
+    /// ```
+    fn dedup_seq(&mut self, range: SeqRange<usize>) -> Result<(), SeqRangeError>
+    where T: PartialEq;
+
+    /// Resize the vector to `new_len` elements, adding or removing elements at the front,
+    /// so that each element keeps its back-relative index.
+    ///
+    /// New elements are clones of `value`. See [`Vec::resize`], which resizes at the back.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, VecSeqExt};
+    /// let mut window = vec![1, 2, 3];
+    /// window.resize_front(5, 0);
+    /// assert_eq!(window, [0, 0, 1, 2, 3]);
+    /// assert_eq!(window[idx!(^1)], 3);
+    /// window.resize_front(2, 0);
+    /// assert_eq!(window, [2, 3]);
+    /// ```
+    fn resize_front(&mut self, new_len: usize, value: T)
+    where T: Clone;
+}
+
+impl<T> VecSeqExt<T> for Vec<T> {
+    fn splice_seq<I>(&mut self, range: SeqRange<usize>, replacement: I) -> Result<SpliceRecord<T>, SeqRangeError>
+    where I: IntoIterator<Item = T> {
+        let resolved = range.checked_for_seq_len(self.len())?;
+        let len = self.len();
+        let removed: Vec<T> = self.splice(resolved.clone(), replacement).collect();
+        let inserted = self.len() + removed.len() - len;
+        Ok(SpliceRecord { removed, origin: range, range: resolved, inserted })
+    }
+
+    fn retain_seq<F>(&mut self, range: SeqRange<usize>, mut keep: F) -> Result<(), SeqRangeError>
+    where F: FnMut(&T) -> bool {
+        let range = range.checked_for_seq_len(self.len())?;
+        let mut idx = 0;
+        self.retain(|elem| {
+            let kept = !range.contains(&idx) || keep(elem);
+            idx += 1;
+            kept
+        });
+        Ok(())
+    }
+
+    fn dedup_seq(&mut self, range: SeqRange<usize>) -> Result<(), SeqRangeError>
+    where T: PartialEq {
+        let range = range.checked_for_seq_len(self.len())?;
+        if range.is_empty() {
+            return Ok(());
+        }
+        // move each first occurrence down to just after the last one kept,
+        // leaving the repeats at the end of the range
+        let mut last = range.start;
+        for idx in range.start + 1..range.end {
+            if self[idx] != self[last] {
+                last += 1;
+                self.swap(last, idx);
+            }
+        }
+        self.drain(last + 1..range.end);
+        Ok(())
+    }
+
+    fn resize_front(&mut self, new_len: usize, value: T)
+    where T: Clone {
+        let len = self.len();
+        if new_len <= len {
+            self.drain(..len - new_len);
+        } else {
+            self.splice(..0, std::iter::repeat_n(value, new_len - len));
+        }
+    }
+}
+
+/// A record of a [`splice_seq`](VecSeqExt::splice_seq), holding the removed elements.
+///
+/// Undoing the splice produces another record, which undoes the undo.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SpliceRecord<T> {
+    removed: Vec<T>,
+    origin: SeqRange<usize>,
+    range: Range<usize>,
+    inserted: usize,
+}
+
+impl<T> SpliceRecord<T> {
+    /// The elements removed by the splice.
+    pub fn removed(&self) -> &[T] {
+        &self.removed
+    }
+
+    /// Unwrap the elements removed by the splice.
+    pub fn into_removed(self) -> Vec<T> {
+        self.removed
+    }
+
+    /// The [`SeqRange`] which was spliced.
+    pub fn origin(&self) -> SeqRange<usize> {
+        self.origin
+    }
+
+    /// The resolved range which was removed.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// The resolved range which the replacement elements occupy.
+    pub fn inserted_range(&self) -> Range<usize> {
+        self.range.start..self.range.start + self.inserted
+    }
+
+    /// Restore the removed elements in place of the replacement elements,
+    /// returning a record of the restoration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the vector unchanged, if the vector is too short
+    /// to contain the replacement elements. Whether the vector has otherwise changed
+    /// since the splice is not detected.
+    pub fn undo(self, vec: &mut Vec<T>) -> Result<SpliceRecord<T>, SeqRangeError> {
+        let inserted = self.inserted_range();
+        let origin = SeqRange { start: SeqIndex::FromFront(inserted.start), end: SeqIndex::FromFront(inserted.end) };
+        vec.splice_seq(origin, self.removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idx;
+
+    #[test]
+    fn test_splice_seq() {
+        let mut vec: Vec<_> = (0..5).collect();
+        let record = vec.splice_seq(idx!(1..^1), []).unwrap();
+        assert_eq!(vec, [0, 4]);
+        assert_eq!(record.origin(), idx!(1..^1));
+        assert_eq!(record.inserted_range(), 1..1);
+
+        let redo = record.undo(&mut vec).unwrap();
+        assert_eq!(vec, [0, 1, 2, 3, 4]);
+        assert!(redo.removed().is_empty());
+        assert_eq!(redo.inserted_range(), 1..4);
+
+        let record = vec.splice_seq(idx!(^0..^0), [5, 6]).unwrap();
+        assert_eq!(record.range(), 5..5);
+        vec.truncate(6);
+        assert!(record.undo(&mut vec).is_err());
+        assert_eq!(vec, [0, 1, 2, 3, 4, 5]);
+        assert!(vec.splice_seq(idx!(^7..^0), []).is_err());
+    }
+
+    #[test]
+    fn test_retain_seq() {
+        let mut vec: Vec<_> = (0..10).collect();
+        vec.retain_seq(idx!(2..^2), |n| n % 3 == 0).unwrap();
+        assert_eq!(vec, [0, 1, 3, 6, 8, 9]);
+        vec.retain_seq(idx!(^0..^0), |_| false).unwrap();
+        assert_eq!(vec.len(), 6);
+        assert!(vec.retain_seq(idx!(^7..^0), |_| false).is_err());
+        assert_eq!(vec.len(), 6);
+    }
+
+    #[test]
+    fn test_dedup_seq() {
+        let mut vec = vec![0, 0, 1, 1, 0, 0, 2, 2];
+        vec.dedup_seq(idx!(1..^1)).unwrap();
+        assert_eq!(vec, [0, 0, 1, 0, 2, 2]);
+        vec.dedup_seq(idx!(0..^0)).unwrap();
+        assert_eq!(vec, [0, 1, 0, 2]);
+        vec.dedup_seq(idx!(2..2)).unwrap();
+        assert_eq!(vec, [0, 1, 0, 2]);
+        assert!(vec.dedup_seq(idx!(^1..1)).is_err());
+    }
+
+    #[test]
+    fn test_resize_front() {
+        let mut vec = vec![1, 2];
+        vec.resize_front(2, 0);
+        assert_eq!(vec, [1, 2]);
+        vec.resize_front(4, 0);
+        assert_eq!(vec, [0, 0, 1, 2]);
+        vec.resize_front(1, 0);
+        assert_eq!(vec, [2]);
+        vec.resize_front(0, 0);
+        assert!(vec.is_empty());
+    }
+}
@@ -0,0 +1,144 @@
+//! [`wasm_bindgen`] wrappers, so JavaScript callers can construct indices and ranges
+//! and have them resolved by the same code as native callers.
+//!
+//! Resolution never panics across the FFI boundary: indices and ranges which do not fit
+//! a sequence resolve to `undefined`.
+
+use wasm_bindgen::prelude::*;
+
+use crate::{SeqIndex, SeqRange};
+
+/// An index which may count from the back of a sequence. Exported to JavaScript as `SeqIndex`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::idx;
+/// # use from_back::wasm::JsSeqIndex;
+/// let index = JsSeqIndex::back(2);
+/// assert_eq!(index.resolve(10), Some(8));
+/// assert_eq!(from_back::SeqIndex::from(index), idx!(^2));
+/// ```
+#[wasm_bindgen(js_name = SeqIndex)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JsSeqIndex {
+    /// The magnitude of the index.
+    pub index: usize,
+    /// Whether the index counts from the back of the sequence.
+    #[wasm_bindgen(js_name = fromBack)]
+    pub from_back: bool,
+}
+
+#[wasm_bindgen(js_class = SeqIndex)]
+impl JsSeqIndex {
+    /// An index counting from the front of a sequence.
+    pub fn front(index: usize) -> JsSeqIndex {
+        SeqIndex::FromFront(index).into()
+    }
+
+    /// An index counting from the back of a sequence.
+    pub fn back(index: usize) -> JsSeqIndex {
+        SeqIndex::FromBack(index).into()
+    }
+
+    /// Resolve to a position in `0..=len`, or `undefined` if it falls outside the sequence.
+    pub fn resolve(&self, len: usize) -> Option<usize> {
+        SeqIndex::from(*self).checked_for_seq_len(len).ok()
+    }
+}
+
+impl From<SeqIndex<usize>> for JsSeqIndex {
+    fn from(index: SeqIndex<usize>) -> Self {
+        match index {
+            SeqIndex::FromFront(index) => JsSeqIndex { index, from_back: false },
+            SeqIndex::FromBack(index) => JsSeqIndex { index, from_back: true },
+        }
+    }
+}
+
+impl From<JsSeqIndex> for SeqIndex<usize> {
+    fn from(index: JsSeqIndex) -> Self {
+        if index.from_back {
+            SeqIndex::FromBack(index.index)
+        } else {
+            SeqIndex::FromFront(index.index)
+        }
+    }
+}
+
+/// A range whose bounds may count from the back of a sequence. Exported to JavaScript as `SeqRange`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::wasm::{JsSeqIndex, JsSeqRange};
+/// let range = JsSeqRange::new(JsSeqIndex::front(2), JsSeqIndex::back(2));
+/// assert_eq!(range.slice(b"abcdefg"), Some(b"cde".to_vec()));
+/// assert_eq!(range.slice(b"abc"), None);
+/// ```
+#[wasm_bindgen(js_name = SeqRange)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JsSeqRange {
+    /// The lower bound of the range (inclusive).
+    pub start: JsSeqIndex,
+    /// The upper bound of the range (exclusive).
+    pub end: JsSeqIndex,
+}
+
+#[wasm_bindgen(js_class = SeqRange)]
+impl JsSeqRange {
+    /// Create a range from its bounds.
+    #[wasm_bindgen(constructor)]
+    pub fn new(start: JsSeqIndex, end: JsSeqIndex) -> JsSeqRange {
+        JsSeqRange { start, end }
+    }
+
+    /// The resolved start of the range, or `undefined` if the range does not fit the sequence.
+    #[wasm_bindgen(js_name = resolveStart)]
+    pub fn resolve_start(&self, len: usize) -> Option<usize> {
+        SeqRange::from(*self).checked_for_seq_len(len).ok().map(|range| range.start)
+    }
+
+    /// The resolved end of the range, or `undefined` if the range does not fit the sequence.
+    #[wasm_bindgen(js_name = resolveEnd)]
+    pub fn resolve_end(&self, len: usize) -> Option<usize> {
+        SeqRange::from(*self).checked_for_seq_len(len).ok().map(|range| range.end)
+    }
+
+    /// Copy the bytes within the range out of `buf`, or `undefined` if the range does not fit.
+    pub fn slice(&self, buf: &[u8]) -> Option<Vec<u8>> {
+        let range = SeqRange::from(*self).checked_for_seq_len(buf.len()).ok()?;
+        Some(buf[range].to_vec())
+    }
+}
+
+impl From<SeqRange<usize>> for JsSeqRange {
+    fn from(range: SeqRange<usize>) -> Self {
+        JsSeqRange { start: range.start.into(), end: range.end.into() }
+    }
+}
+
+impl From<JsSeqRange> for SeqRange<usize> {
+    fn from(range: JsSeqRange) -> Self {
+        SeqRange { start: range.start.into(), end: range.end.into() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idx;
+
+    #[test]
+    fn test_resolve() {
+        assert_eq!(JsSeqIndex::front(3).resolve(3), Some(3));
+        assert_eq!(JsSeqIndex::front(4).resolve(3), None);
+        assert_eq!(JsSeqIndex::back(4).resolve(3), None);
+        let range = JsSeqRange::from(idx!(^3..^1));
+        assert_eq!(range.resolve_start(10), Some(7));
+        assert_eq!(range.resolve_end(10), Some(9));
+        assert_eq!(range.resolve_end(2), None);
+        assert_eq!(SeqRange::from(range), idx!(^3..^1));
+        assert_eq!(JsSeqRange::from(idx!(4..^8)).slice(b"0123456789"), None);
+    }
+}
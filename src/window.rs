@@ -0,0 +1,159 @@
+//! A mutable view over a range of a slice, which can be moved and resized.
+
+use std::ops::{Deref, DerefMut, Range};
+
+use crate::{AnchoredRange, SeqIndex, SeqRange, SeqRangeError};
+
+/// A mutable view over a [`SeqRange`] of a slice.
+///
+/// Created by [`SliceSeqExt::window_mut`](crate::SliceSeqExt::window_mut).
+/// A `WindowMut` dereferences to the slice within its range, and keeps the
+/// whole slice borrowed so its range can be moved with [`shift`](WindowMut::shift),
+/// [`grow`](WindowMut::grow), and [`shrink`](WindowMut::shrink).
+/// Each of these adjusts the originating `SeqRange` and resolves it again,
+/// so from-back bounds stay relative to the back of the slice.
+///
+/// # Examples
+///
+/// ```rust
+/// # use from_back::{idx, SliceSeqExt};
+/// let mut vec = vec![8, 6, 7, 5, 3, 0, 9];
+/// let mut window = vec.window_mut(idx!(1..^4)).unwrap();
+/// window.fill(0);
+/// window.shift(3).unwrap();
+/// assert_eq!(window.origin(), idx!(4..^1));
+/// window.fill(1);
+/// assert!(window.grow(2).is_err());
+/// assert_eq!(vec, [8, 0, 0, 5, 1, 1, 9]);
+/// ```
+#[derive(Debug)]
+pub struct WindowMut<'a, T> {
+    parent: &'a mut [T],
+    range: AnchoredRange,
+}
+
+/// Move a bound `by` positions toward the back of the sequence, if it stays representable.
+fn move_bound(index: SeqIndex<usize>, by: isize) -> Option<SeqIndex<usize>> {
+    let distance = by.unsigned_abs();
+    match (index, by < 0) {
+        (SeqIndex::FromFront(idx), false) => idx.checked_add(distance).map(SeqIndex::FromFront),
+        (SeqIndex::FromFront(idx), true) => idx.checked_sub(distance).map(SeqIndex::FromFront),
+        (SeqIndex::FromBack(idx), false) => idx.checked_sub(distance).map(SeqIndex::FromBack),
+        (SeqIndex::FromBack(idx), true) => idx.checked_add(distance).map(SeqIndex::FromBack),
+    }
+}
+
+impl<'a, T> WindowMut<'a, T> {
+    pub(crate) fn new(parent: &'a mut [T], range: SeqRange<usize>) -> Result<Self, SeqRangeError> {
+        let range = range.anchor(parent.len())?;
+        Ok(WindowMut { parent, range })
+    }
+
+    /// The resolved range of the window within the parent slice.
+    pub fn range(&self) -> Range<usize> {
+        self.range.range()
+    }
+
+    /// The [`SeqRange`] the window's range was resolved from.
+    pub fn origin(&self) -> SeqRange<usize> {
+        self.range.origin()
+    }
+
+    /// Move bounds of the window and resolve them again, leaving the window unchanged on failure.
+    fn adjust(&mut self, start_by: isize, end_by: isize) -> Result<(), SeqRangeError> {
+        let origin = self.origin();
+        let len = self.parent.len();
+        let out_of_bounds = |index| SeqRangeError::OutOfBounds { index, len };
+        let start = move_bound(origin.start, start_by).ok_or_else(|| out_of_bounds(origin.start))?;
+        let end = move_bound(origin.end, end_by).ok_or_else(|| out_of_bounds(origin.end))?;
+        self.range = SeqRange { start, end }.anchor(len)?;
+        Ok(())
+    }
+
+    /// Move the whole window `by` elements toward the back of the parent slice,
+    /// or toward the front if `by` is negative.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the window unchanged, if the moved window does not fit the parent.
+    pub fn shift(&mut self, by: isize) -> Result<(), SeqRangeError> {
+        self.adjust(by, by)
+    }
+
+    /// Extend the end of the window `n` elements toward the back of the parent slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the window unchanged, if the grown window does not fit the parent.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use from_back::{idx, SliceSeqExt};
+    /// let mut vec = vec![8, 6, 7, 5, 3, 0, 9];
+    /// let mut window = vec.window_mut(idx!(^4..^2)).unwrap();
+    /// window.grow(1).unwrap();
+    /// assert_eq!(*window, [5, 3, 0]);
+    /// ```
+    pub fn grow(&mut self, n: usize) -> Result<(), SeqRangeError> {
+        let by = isize::try_from(n).unwrap_or(isize::MAX);
+        self.adjust(0, by)
+    }
+
+    /// Retract the end of the window `n` elements toward the front of the parent slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the window unchanged, if the window has fewer than `n` elements.
+    pub fn shrink(&mut self, n: usize) -> Result<(), SeqRangeError> {
+        let by = isize::try_from(n).map_or(isize::MIN, |n| -n);
+        self.adjust(0, by)
+    }
+
+    /// Give up the ability to move the window, keeping the borrow of the elements within it.
+    pub fn into_slice(self) -> &'a mut [T] {
+        &mut self.parent[self.range.range()]
+    }
+}
+
+impl<T> Deref for WindowMut<'_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.parent[self.range.range()]
+    }
+}
+
+impl<T> DerefMut for WindowMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.parent[self.range.range()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{idx, SliceSeqExt};
+
+    #[test]
+    fn test_window_mut() {
+        let mut vec: Vec<_> = (0..8).collect();
+        let mut window = vec.window_mut(idx!(2..^4)).unwrap();
+        assert_eq!(*window, [2, 3]);
+        assert!(window.shift(-3).is_err());
+        assert!(window.shift(5).is_err());
+        assert_eq!(window.range(), 2..4);
+        window.shift(-2).unwrap();
+        assert_eq!(*window, [0, 1]);
+        window.shrink(2).unwrap();
+        assert!(window.is_empty());
+        assert!(window.shrink(1).is_err());
+        window.grow(8).unwrap();
+        assert_eq!(window.origin(), idx!(0..^0));
+        window.shift(4).unwrap_err();
+        window.shrink(usize::MAX).unwrap_err();
+        window.grow(usize::MAX).unwrap_err();
+        window.into_slice().reverse();
+        assert_eq!(vec, [7, 6, 5, 4, 3, 2, 1, 0]);
+        assert!(vec.window_mut(idx!(^2..2)).is_err());
+    }
+}